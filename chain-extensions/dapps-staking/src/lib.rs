@@ -19,20 +19,22 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 use sp_runtime::{
     traits::{Saturating, Zero},
-    DispatchError,
+    DispatchError, PerThing, Perbill,
 };
 
-use codec::Encode;
+use codec::{Compact, CompactLen, Encode};
 use dapps_staking_chain_extension_types::{
-    DSError, DappsStakingAccountInput, DappsStakingEraInput, DappsStakingNominationInput,
-    DappsStakingValueInput,
+    DSError, DappsStakingAccountInput, DappsStakingEraInput, DappsStakingMetadataInput,
+    DappsStakingNominationInput, DappsStakingValueInput,
 };
 use frame_support::traits::{Currency, Get};
 use frame_system::RawOrigin;
 use pallet_contracts::chain_extension::{
     ChainExtension, Environment, Ext, InitState, RetVal, SysConfig,
 };
-use pallet_dapps_staking::{RewardDestination, WeightInfo};
+use pallet_dapps_staking::{
+    DAppCategory, DAppMetadata, DAppMetadataOf, RewardDestination, WeightInfo,
+};
 use sp_std::marker::PhantomData;
 
 type BalanceOf<T> = <<T as pallet_dapps_staking::Config>::Currency as Currency<
@@ -54,6 +56,9 @@ enum DappsStakingFunc {
     ClaimDapp,
     SetRewardDestination,
     NominationTransfer,
+    SetRewardDestinationSplit,
+    ReadDappMetadata,
+    SetDappMetadata,
 }
 
 impl TryFrom<u16> for DappsStakingFunc {
@@ -75,6 +80,9 @@ impl TryFrom<u16> for DappsStakingFunc {
             12 => Ok(DappsStakingFunc::ClaimDapp),
             13 => Ok(DappsStakingFunc::SetRewardDestination),
             14 => Ok(DappsStakingFunc::NominationTransfer),
+            15 => Ok(DappsStakingFunc::SetRewardDestinationSplit),
+            16 => Ok(DappsStakingFunc::ReadDappMetadata),
+            17 => Ok(DappsStakingFunc::SetDappMetadata),
             _ => Err(DispatchError::Other(
                 "DappsStakingExtension: Unimplemented func_id",
             )),
@@ -117,7 +125,8 @@ where
                 let base_weight = <T as frame_system::Config>::DbWeight::get().reads(1);
                 env.charge_weight(base_weight)?;
 
-                let unbonding_period = T::UnbondingPeriod::get();
+                let unbonding_period =
+                    pallet_dapps_staking::Pallet::<T>::staking_parameters().unbonding_period;
                 env.write(&unbonding_period.encode(), false, None)?;
             }
 
@@ -362,6 +371,113 @@ where
                     Ok(_) => Ok(RetVal::Converging(DSError::Success as u32)),
                 };
             }
+
+            DappsStakingFunc::SetRewardDestinationSplit => {
+                let restake_ratio_raw: u32 = env.read_as()?;
+
+                let base_weight =
+                    <T as pallet_dapps_staking::Config>::WeightInfo::set_reward_destination();
+                env.charge_weight(base_weight)?;
+
+                // Restake ratio is expressed in parts per billion
+                if restake_ratio_raw > Perbill::ACCURACY {
+                    let error = DSError::RewardDestinationValueOutOfBounds;
+                    return Ok(RetVal::Converging(error as u32));
+                }
+                let reward_destination =
+                    RewardDestination::Split(Perbill::from_parts(restake_ratio_raw));
+
+                let caller = env.ext().address().clone();
+                let call_result = pallet_dapps_staking::Pallet::<T>::set_reward_destination(
+                    RawOrigin::Signed(caller).into(),
+                    reward_destination,
+                );
+                return match call_result {
+                    Err(e) => {
+                        let mapped_error = DSError::try_from(e.error)?;
+                        Ok(RetVal::Converging(mapped_error as u32))
+                    }
+                    Ok(_) => Ok(RetVal::Converging(DSError::Success as u32)),
+                };
+            }
+
+            DappsStakingFunc::ReadDappMetadata => {
+                let contract_bytes: [u8; 32] = env.read_as()?;
+                let contract: <T as pallet_dapps_staking::Config>::SmartContract =
+                    contract_bytes.into();
+
+                let base_weight = <T as frame_system::Config>::DbWeight::get().reads(1);
+                env.charge_weight(base_weight)?;
+
+                let metadata = pallet_dapps_staking::DappMetadata::<T>::get(&contract)
+                    .map(|(metadata, _)| metadata);
+                env.write(&metadata.encode(), false, None)?;
+            }
+
+            DappsStakingFunc::SetDappMetadata => {
+                // Charge for the largest allowed metadata before reading the input
+                let max_field_length =
+                    <T as pallet_dapps_staking::Config>::MaxMetadataFieldLength::get();
+                let max_weight = <T as pallet_dapps_staking::Config>::WeightInfo::set_dapp_metadata(
+                    max_field_length.saturating_mul(4),
+                );
+                let charged_weight = env.charge_weight(max_weight)?;
+
+                // Input is bounded by the encoded size of the contract, four max length fields and the category
+                let max_field_size = (Compact::<u32>::compact_len(&max_field_length) as u32)
+                    .saturating_add(max_field_length);
+                let max_in_len = max_field_size.saturating_mul(4).saturating_add(33);
+                let in_len = env.in_len();
+                if in_len > max_in_len {
+                    let error = DSError::InvalidDappMetadata;
+                    return Ok(RetVal::Converging(error as u32));
+                }
+                let args: DappsStakingMetadataInput = env.read_as_unbounded(in_len)?;
+                let contract = args.contract.into();
+
+                // Text fields must fit into the bounds and category must be known
+                let metadata: DAppMetadataOf<T> = match (
+                    args.name.try_into(),
+                    args.description.try_into(),
+                    args.website.try_into(),
+                    args.logo_uri.try_into(),
+                    DAppCategory::try_from(args.category),
+                ) {
+                    (Ok(name), Ok(description), Ok(website), Ok(logo_uri), Ok(category)) => {
+                        DAppMetadata {
+                            name,
+                            description,
+                            website,
+                            logo_uri,
+                            category,
+                        }
+                    }
+                    _ => {
+                        let error = DSError::InvalidDappMetadata;
+                        return Ok(RetVal::Converging(error as u32));
+                    }
+                };
+
+                let actual_weight =
+                    <T as pallet_dapps_staking::Config>::WeightInfo::set_dapp_metadata(
+                        metadata.byte_size(),
+                    );
+                env.adjust_weight(charged_weight, actual_weight);
+
+                let caller = env.ext().address().clone();
+                let call_result = pallet_dapps_staking::Pallet::<T>::set_dapp_metadata(
+                    RawOrigin::Signed(caller).into(),
+                    contract,
+                    metadata,
+                );
+                return match call_result {
+                    Err(e) => {
+                        let mapped_error = DSError::try_from(e.error)?;
+                        Ok(RetVal::Converging(mapped_error as u32))
+                    }
+                    Ok(_) => Ok(RetVal::Converging(DSError::Success as u32)),
+                };
+            }
         }
 
         Ok(RetVal::Converging(DSError::Success as u32))
@@ -17,6 +17,8 @@
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
 #![cfg_attr(not(feature = "std"), no_std)]
+extern crate alloc;
+use alloc::vec::Vec;
 use codec::{Decode, Encode};
 use frame_support::pallet_prelude::MaxEncodedLen;
 use sp_runtime::{DispatchError, ModuleError};
@@ -80,8 +82,40 @@ pub enum DSError {
     NotActiveStaker = 25,
     /// Transfering nomination to the same contract
     NominationTransferToSameContract = 26,
-    /// Unexpected reward destination value
+    /// Unexpected reward destination value or reward destination split ratio
     RewardDestinationValueOutOfBounds = 27,
+    /// Contract has been frozen and can no longer be staked on
+    DappFrozen = 28,
+    /// Slash must forfeit some reward, slash the deposit or freeze the contract
+    NothingToSlash = 29,
+    /// Contract has been re-registered the maximum number of times
+    TooManyReregistrations = 30,
+    /// dApp has no metadata set
+    NoDappMetadata = 31,
+    /// dApp metadata field is too long or category value is unexpected
+    InvalidDappMetadata = 32,
+    /// Staker has no operator set
+    NoOperator = 33,
+    /// Caller isn't the operator of the staker
+    NotOperator = 34,
+    /// Number of nomination transfers is zero or exceeds the limit
+    InvalidNumberOfNominationTransfers = 35,
+    /// Staker can't be its own operator
+    OperatorIsStaker = 36,
+    /// Contract stake history is full
+    ContractStakeHistoryFull = 37,
+    /// Number of eras to slash exceeds the limit
+    InvalidNumberOfSlashEras = 38,
+    /// There are no unlocking chunks which could be rebonded
+    NothingToRebond = 39,
+    /// Staker reward for the era has expired and can no longer be claimed
+    RewardExpired = 40,
+    /// Lock duration isn't one of the allowed options
+    InvalidStakeLockDuration = 41,
+    /// Too many stake locks for (staker, contract) pairing
+    TooManyStakeLocks = 42,
+    /// Value can't be unstaked since it's locked on the contract
+    StakeLocked = 43,
     /// Unknown error
     UnknownError = 99,
 }
@@ -123,6 +157,23 @@ impl TryFrom<DispatchError> for DSError {
             Some("NominationTransferToSameContract") => {
                 Ok(DSError::NominationTransferToSameContract)
             }
+            Some("DappFrozen") => Ok(DSError::DappFrozen),
+            Some("NothingToSlash") => Ok(DSError::NothingToSlash),
+            Some("TooManyReregistrations") => Ok(DSError::TooManyReregistrations),
+            Some("NoDappMetadata") => Ok(DSError::NoDappMetadata),
+            Some("NoOperator") => Ok(DSError::NoOperator),
+            Some("NotOperator") => Ok(DSError::NotOperator),
+            Some("InvalidNumberOfNominationTransfers") => {
+                Ok(DSError::InvalidNumberOfNominationTransfers)
+            }
+            Some("OperatorIsStaker") => Ok(DSError::OperatorIsStaker),
+            Some("ContractStakeHistoryFull") => Ok(DSError::ContractStakeHistoryFull),
+            Some("InvalidNumberOfSlashEras") => Ok(DSError::InvalidNumberOfSlashEras),
+            Some("NothingToRebond") => Ok(DSError::NothingToRebond),
+            Some("RewardExpired") => Ok(DSError::RewardExpired),
+            Some("InvalidStakeLockDuration") => Ok(DSError::InvalidStakeLockDuration),
+            Some("TooManyStakeLocks") => Ok(DSError::TooManyStakeLocks),
+            Some("StakeLocked") => Ok(DSError::StakeLocked),
             _ => Ok(DSError::UnknownError),
         };
     }
@@ -152,3 +203,13 @@ pub struct DappsStakingNominationInput<Balance> {
     pub target_contract: [u8; 32],
     pub value: Balance,
 }
+
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct DappsStakingMetadataInput {
+    pub contract: [u8; 32],
+    pub name: Vec<u8>,
+    pub description: Vec<u8>,
+    pub website: Vec<u8>,
+    pub logo_uri: Vec<u8>,
+    pub category: u8,
+}
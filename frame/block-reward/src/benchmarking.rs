@@ -22,21 +22,92 @@ use super::*;
 
 use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
 use frame_system::{Pallet as System, RawOrigin};
+use sp_std::vec::Vec;
 
 /// Assert that the last event equals the provided one.
 fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
     System::<T>::assert_last_event(generic_event.into());
 }
 
+/// Consistent configuration with the max number of distinct beneficiaries, which is the worst case.
+fn max_beneficiaries_config() -> RewardDistributionConfig {
+    let percent = Perbill::from_rational(1, MAX_BENEFICIARIES + 1);
+    let beneficiaries: Vec<_> = [
+        BeneficiaryId::Treasury,
+        BeneficiaryId::Collators,
+        BeneficiaryId::DappsStakers,
+        BeneficiaryId::Dapps,
+    ]
+    .into_iter()
+    .chain((0..=u8::MAX).map(|index| BeneficiaryId::Custom([index; 8])))
+    .take(MAX_BENEFICIARIES as usize)
+    .map(|beneficiary| (beneficiary, percent))
+    .collect();
+
+    RewardDistributionConfig {
+        beneficiaries: BoundedVec::truncate_from(beneficiaries),
+        // Remainder, so all percentages sum up to one whole
+        adjustable_percent: Perbill::one()
+            .saturating_sub(Perbill::from_parts(percent.deconstruct() * MAX_BENEFICIARIES)),
+        ideal_dapps_staking_tvl: Perbill::from_percent(50),
+    }
+}
+
 benchmarks! {
 
     set_configuration {
-        let reward_config = RewardDistributionConfig::default();
+        let reward_config = max_beneficiaries_config();
         assert!(reward_config.is_consistent());
+        assert_eq!(reward_config.beneficiaries.len() as u32, MAX_BENEFICIARIES);
     }: _(RawOrigin::Root, reward_config.clone())
     verify {
         assert_last_event::<T>(Event::<T>::DistributionConfigurationChanged(reward_config).into());
     }
+
+    set_inflation_mode {
+        let inflation_mode = InflationMode::PerBlock {
+            annual_inflation: Perbill::from_percent(10),
+            blocks_per_year: 2_629_800,
+        };
+    }: _(RawOrigin::Root, inflation_mode)
+    verify {
+        assert_last_event::<T>(Event::<T>::InflationModeChanged(inflation_mode).into());
+    }
+
+    schedule_configuration {
+        let reward_config = max_beneficiaries_config();
+        assert!(reward_config.is_consistent());
+        let activation = Activation::NextEra;
+    }: _(RawOrigin::Root, reward_config.clone(), activation)
+    verify {
+        assert_last_event::<T>(Event::<T>::DistributionConfigurationScheduled(reward_config, activation).into());
+    }
+
+    cancel_scheduled_configuration {
+        Pallet::<T>::schedule_configuration(RawOrigin::Root.into(), RewardDistributionConfig::default(), Activation::NextEra)?;
+    }: _(RawOrigin::Root)
+    verify {
+        assert_last_event::<T>(Event::<T>::ScheduledDistributionConfigurationCancelled.into());
+    }
+
+    on_timestamp_set {
+        Pallet::<T>::set_inflation_mode(
+            RawOrigin::Root.into(),
+            InflationMode::PerElapsedTime { annual_inflation: Perbill::from_percent(10) },
+        )?;
+        LastTimestamp::<T>::put(1_000_000);
+
+        let block_number = System::<T>::block_number() + 1u32.into();
+        Pallet::<T>::schedule_configuration(RawOrigin::Root.into(), max_beneficiaries_config(), Activation::AtBlock(block_number))?;
+        System::<T>::set_block_number(block_number);
+    }: {
+        <Pallet<T> as OnTimestampSet<u64>>::on_timestamp_set(1_012_000);
+    }
+    verify {
+        assert!(ScheduledConfigStorage::<T>::get().is_none());
+        assert_eq!(LastTimestamp::<T>::get(), 1_012_000);
+        assert_eq!(NextRecalculation::<T>::get(), block_number + T::InflationRecalculationPeriod::get());
+    }
 }
 
 #[cfg(test)]
@@ -29,17 +29,49 @@
 //!
 //! Major on-chain factors which can influence reward distribution are total issuance and total value locked by dapps staking.
 //!
+//! ## Beneficiaries
+//!
+//! Reward distribution configuration contains a bounded list of beneficiaries, each identified by a [`BeneficiaryId`],
+//! together with the percentage of the block reward they receive. Besides the well-known beneficiaries,
+//! runtime can define its own using `BeneficiaryId::Custom`, e.g. for a parachain lease fund or an ecosystem grant pot.
+//!
+//! Part of the block reward defined by `adjustable_percent` is split between dapps stakers and treasury,
+//! depending on how close the total value locked by dapps staking is to `ideal_dapps_staking_tvl`.
+//! Treasury receives everything which isn't distributed to other beneficiaries.
+//!
+//! Chains upgrading from the configuration with fixed beneficiaries should run [`migrations::BeneficiaryListMigration`].
+//!
 //! ## Interface
 //!
 //! ### Dispatchable Function
 //!
 //! - `set_configuration` - used to change reward distribution configuration parameters
+//! - `set_inflation_mode` - used to switch between fixed and dynamic block reward issuance
+//! - `schedule_configuration` - used to schedule a reward distribution configuration change for a future block or dapps staking era
+//! - `cancel_scheduled_configuration` - used to cancel the scheduled reward distribution configuration change
 //!
 //! ### Other
 //!
 //! - `on_timestamp_set` - This pallet implements the `OnTimestampSet` trait to handle block production.
 //!                        Note: We assume that it's impossible to set timestamp two times in a block.
 //!
+//! ## Inflation
+//!
+//! Amount issued for each block depends on the [`InflationMode`]:
+//! - `Fixed` - constant `RewardAmount` is issued for each block, regardless of block production speed.
+//! - `PerBlock` - annual issuance is spread evenly over the expected number of blocks per year.
+//! - `PerElapsedTime` - annual issuance is issued proportionally to the time elapsed since the previous block.
+//!
+//! For dynamic modes, annual issuance is derived from the annual inflation rate and current total issuance.
+//! It is recalculated every `InflationRecalculationPeriod` blocks.
+//!
+//! ## Scheduled Configuration
+//!
+//! Reward distribution configuration change can be scheduled for a future block, or for the start of the next dapps staking era.
+//! This way the rewards accumulated by dapps staking throughout an era are all distributed using the same configuration.
+//! Scheduled change is applied before the block reward is distributed.
+//! Only one change can be scheduled at a time, scheduling a new one replaces the existing one.
+//!
 //! ## Usage
 //!
 //! 1. Pallet should be set as a handler of `OnTimestampSet`.
@@ -57,20 +89,21 @@
 //! pub struct BeneficiaryPayout();
 //! impl BeneficiaryPayout<NegativeImbalanceOf<T>> for BeneficiaryPayout {
 //!
-//!     fn treasury(reward: NegativeImbalanceOf<T>) {
-//!         Balances::resolve_creating(&TREASURY_POT.into_account(), reward);
-//!     }
-//!
-//!     fn collators(reward: NegativeImbalanceOf<T>) {
-//!         Balances::resolve_creating(&COLLATOR_POT.into_account(), reward);
-//!      }
-//!
-//!     fn dapps_staking(stakers: NegativeImbalanceOf<T>, dapps: NegativeImbalanceOf<T>) {
-//!         DappsStaking::rewards(stakers, dapps);
+//!     fn payout(beneficiary: BeneficiaryId, reward: NegativeImbalanceOf<T>) {
+//!         match beneficiary {
+//!             BeneficiaryId::Treasury => Balances::resolve_creating(&TREASURY_POT.into_account(), reward),
+//!             BeneficiaryId::Collators => Balances::resolve_creating(&COLLATOR_POT.into_account(), reward),
+//!             BeneficiaryId::DappsStakers => DappsStaking::rewards(reward, Default::default()),
+//!             BeneficiaryId::Dapps => DappsStaking::rewards(Default::default(), reward),
+//!             BeneficiaryId::Custom(id) => Balances::resolve_creating(&PalletId(id).into_account(), reward),
+//!         }
 //!     }
 //! }
 //! ```
-//! 4. Set `RewardAmount` to desired block reward value in native currency.
+//! 4. `DappsStakingEraProvider` should provide the current dapps staking era, e.g. `DappsStaking::current_era()`.
+//!    Dapps staking era must be advanced before `on_timestamp_set` is called in a block, which is the case when it's done in `on_initialize`.
+//! 5. Set `RewardAmount` to desired block reward value in native currency.
+//! 6. Set `InflationRecalculationPeriod` to the number of blocks after which dynamic block reward is recalculated.
 //!
 
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -84,13 +117,14 @@ use frame_support::{
 };
 use frame_system::{ensure_root, pallet_prelude::*};
 use sp_runtime::{
-    traits::{CheckedAdd, Zero},
+    traits::{CheckedAdd, Saturating, UniqueSaturatedFrom, UniqueSaturatedInto, Zero},
     Perbill,
 };
 use sp_std::vec;
 
 #[cfg(any(feature = "runtime-benchmarks"))]
 pub mod benchmarking;
+pub mod migrations;
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
@@ -104,7 +138,11 @@ pub mod pallet {
 
     use super::*;
 
+    /// The current storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(PhantomData<T>);
 
     /// The balance type of this pallet.
@@ -124,13 +162,21 @@ pub mod pallet {
         /// Provides information about how much value is locked by dapps staking
         type DappsStakingTvlProvider: Get<BalanceOf<Self>>;
 
+        /// Provides the current dapps staking era
+        type DappsStakingEraProvider: Get<u32>;
+
         /// Used to payout rewards
         type BeneficiaryPayout: BeneficiaryPayout<NegativeImbalanceOf<Self>>;
 
         /// The amount of issuance for each block.
+        /// Used in `InflationMode::Fixed`.
         #[pallet::constant]
         type RewardAmount: Get<BalanceOf<Self>>;
 
+        /// Number of blocks after which the annual issuance of the dynamic inflation modes is recalculated.
+        #[pallet::constant]
+        type InflationRecalculationPeriod: Get<BlockNumberFor<Self>>;
+
         /// The overarching event type.
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
@@ -143,17 +189,58 @@ pub mod pallet {
     pub type RewardDistributionConfigStorage<T: Config> =
         StorageValue<_, RewardDistributionConfig, ValueQuery>;
 
+    /// Reward distribution configuration change which is scheduled to be applied in the future.
+    #[pallet::storage]
+    #[pallet::getter(fn scheduled_config)]
+    pub type ScheduledConfigStorage<T: Config> =
+        StorageValue<_, ScheduledConfig<BlockNumberFor<T>>>;
+
+    /// Mode used to determine the amount issued for each block.
+    #[pallet::storage]
+    #[pallet::getter(fn inflation_mode)]
+    pub type InflationModeStorage<T: Config> = StorageValue<_, InflationMode, ValueQuery>;
+
+    /// Amount issued over a year in dynamic inflation modes, calculated at the start of the recalculation cycle.
+    #[pallet::storage]
+    #[pallet::getter(fn annual_issuance)]
+    pub type AnnualIssuance<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    /// Block at which the annual issuance is recalculated next.
+    #[pallet::storage]
+    #[pallet::getter(fn next_recalculation)]
+    pub type NextRecalculation<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+    /// Timestamp of the previous block, in milliseconds.
+    /// Only tracked in `InflationMode::PerElapsedTime`.
+    #[pallet::storage]
+    #[pallet::getter(fn last_timestamp)]
+    pub type LastTimestamp<T: Config> = StorageValue<_, u64, ValueQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(crate) fn deposit_event)]
     pub enum Event<T: Config> {
-        /// Distribution configuration has been updated.
+        /// Distribution configuration has been updated, either directly or by applying the scheduled change.
         DistributionConfigurationChanged(RewardDistributionConfig),
+        /// Distribution configuration change has been scheduled.
+        DistributionConfigurationScheduled(RewardDistributionConfig, Activation<BlockNumberFor<T>>),
+        /// Scheduled distribution configuration change has been cancelled.
+        ScheduledDistributionConfigurationCancelled,
+        /// Inflation mode has been changed, it applies from the next block.
+        InflationModeChanged(InflationMode),
+        /// Annual issuance of the dynamic inflation mode has been recalculated.
+        AnnualIssuanceRecalculated(BalanceOf<T>),
     }
 
     #[pallet::error]
     pub enum Error<T> {
-        /// Sum of all rations must be one whole (100%)
+        /// Sum of all rations must be one whole (100%), and each beneficiary can only be listed once
         InvalidDistributionConfiguration,
+        /// Expected number of blocks per year must be greater than zero.
+        InvalidInflationMode,
+        /// Activation block must be in the future.
+        InvalidActivation,
+        /// There is no scheduled distribution configuration change.
+        NoScheduledConfiguration,
     }
 
     #[pallet::genesis_config]
@@ -170,6 +257,14 @@ pub mod pallet {
         }
     }
 
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_now: BlockNumberFor<T>) -> Weight {
+            // Block reward is issued in `on_timestamp_set`, which cannot register its own weight
+            T::WeightInfo::on_timestamp_set()
+        }
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Sets the reward distribution configuration parameters which will be used from next block reward distribution.
@@ -201,16 +296,178 @@ pub mod pallet {
 
             Ok(().into())
         }
+
+        /// Sets the inflation mode which will be used from the next block reward issuance.
+        ///
+        /// In case of a dynamic inflation mode, annual issuance is recalculated in the next block,
+        /// and then every `InflationRecalculationPeriod` blocks.
+        ///
+        /// - `inflation_mode` - inflation mode
+        ///
+        /// Emits `InflationModeChanged` with inflation mode embeded into event itself.
+        ///
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::set_inflation_mode())]
+        pub fn set_inflation_mode(
+            origin: OriginFor<T>,
+            inflation_mode: InflationMode,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+
+            ensure!(inflation_mode.is_valid(), Error::<T>::InvalidInflationMode);
+            InflationModeStorage::<T>::put(inflation_mode);
+            NextRecalculation::<T>::kill();
+            // Timestamp isn't tracked in other modes, so it could be outdated
+            LastTimestamp::<T>::kill();
+
+            Self::deposit_event(Event::<T>::InflationModeChanged(inflation_mode));
+
+            Ok(().into())
+        }
+
+        /// Schedules the reward distribution configuration change, replacing the already scheduled one if it exists.
+        ///
+        /// Same rules as for `set_configuration` apply.
+        ///
+        /// - `reward_distro_params` - reward distribution params
+        /// - `activation` - future block, or the start of the next dapps staking era, from which the params are used
+        ///
+        /// Emits `DistributionConfigurationScheduled` now, and `DistributionConfigurationChanged` once the change is applied.
+        ///
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::schedule_configuration())]
+        pub fn schedule_configuration(
+            origin: OriginFor<T>,
+            reward_distro_params: RewardDistributionConfig,
+            activation: Activation<BlockNumberFor<T>>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+
+            ensure!(
+                reward_distro_params.is_consistent(),
+                Error::<T>::InvalidDistributionConfiguration
+            );
+            if let Activation::AtBlock(block) = activation {
+                ensure!(
+                    block > frame_system::Pallet::<T>::block_number(),
+                    Error::<T>::InvalidActivation
+                );
+            }
+
+            ScheduledConfigStorage::<T>::put(ScheduledConfig {
+                config: reward_distro_params.clone(),
+                activation,
+                scheduled_era: T::DappsStakingEraProvider::get(),
+            });
+
+            Self::deposit_event(Event::<T>::DistributionConfigurationScheduled(
+                reward_distro_params,
+                activation,
+            ));
+
+            Ok(().into())
+        }
+
+        /// Cancels the scheduled reward distribution configuration change.
+        ///
+        /// Emits `ScheduledDistributionConfigurationCancelled`.
+        ///
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::cancel_scheduled_configuration())]
+        pub fn cancel_scheduled_configuration(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+
+            ensure!(
+                ScheduledConfigStorage::<T>::exists(),
+                Error::<T>::NoScheduledConfiguration
+            );
+            ScheduledConfigStorage::<T>::kill();
+
+            Self::deposit_event(Event::<T>::ScheduledDistributionConfigurationCancelled);
+
+            Ok(().into())
+        }
     }
 
-    impl<Moment, T: Config> OnTimestampSet<Moment> for Pallet<T> {
-        fn on_timestamp_set(_moment: Moment) {
-            let inflation = T::Currency::issue(T::RewardAmount::get());
+    impl<Moment: UniqueSaturatedInto<u64>, T: Config> OnTimestampSet<Moment> for Pallet<T> {
+        fn on_timestamp_set(moment: Moment) {
+            Self::apply_scheduled_config();
+
+            let inflation = T::Currency::issue(Self::block_reward(moment.unique_saturated_into()));
             Self::distribute_rewards(inflation);
         }
     }
 
     impl<T: Config> Pallet<T> {
+        /// Applies the scheduled reward distribution configuration change, if it's due.
+        fn apply_scheduled_config() {
+            let scheduled_config = match Self::scheduled_config() {
+                Some(scheduled_config) => scheduled_config,
+                None => return,
+            };
+
+            let is_due = match scheduled_config.activation {
+                Activation::AtBlock(block) => frame_system::Pallet::<T>::block_number() >= block,
+                Activation::NextEra => {
+                    T::DappsStakingEraProvider::get() > scheduled_config.scheduled_era
+                }
+            };
+
+            if is_due {
+                ScheduledConfigStorage::<T>::kill();
+                RewardDistributionConfigStorage::<T>::put(scheduled_config.config.clone());
+
+                Self::deposit_event(Event::<T>::DistributionConfigurationChanged(
+                    scheduled_config.config,
+                ));
+            }
+        }
+
+        /// Calculates the amount to issue for the current block.
+        ///
+        /// # Arguments
+        /// * `now` - timestamp of the current block, in milliseconds
+        ///
+        fn block_reward(now: u64) -> BalanceOf<T> {
+            match Self::inflation_mode() {
+                InflationMode::Fixed => T::RewardAmount::get(),
+                InflationMode::PerBlock {
+                    annual_inflation,
+                    blocks_per_year,
+                } => Self::recalculated_annual_issuance(annual_inflation) / blocks_per_year.into(),
+                InflationMode::PerElapsedTime { annual_inflation } => {
+                    let annual_issuance = Self::recalculated_annual_issuance(annual_inflation);
+                    // Previous timestamp is `0` if unknown
+                    let previous =
+                        LastTimestamp::<T>::mutate(|last| sp_std::mem::replace(last, now));
+                    let elapsed = if previous.is_zero() {
+                        Zero::zero()
+                    } else {
+                        now.saturating_sub(previous)
+                    };
+                    // Can only saturate for annual issuance far beyond any realistic value
+                    annual_issuance.saturating_mul(BalanceOf::<T>::unique_saturated_from(elapsed))
+                        / BalanceOf::<T>::unique_saturated_from(MILLISECS_PER_YEAR)
+                }
+            }
+        }
+
+        /// Returns the annual issuance, recalculating it first in case a new recalculation cycle has started.
+        fn recalculated_annual_issuance(annual_inflation: Perbill) -> BalanceOf<T> {
+            let now = frame_system::Pallet::<T>::block_number();
+            if now < Self::next_recalculation() {
+                return Self::annual_issuance();
+            }
+
+            let annual_issuance = annual_inflation * T::Currency::total_issuance();
+            AnnualIssuance::<T>::put(annual_issuance);
+            NextRecalculation::<T>::put(now.saturating_add(T::InflationRecalculationPeriod::get()));
+
+            Self::deposit_event(Event::<T>::AnnualIssuanceRecalculated(annual_issuance));
+
+            annual_issuance
+        }
+
         /// Distribute reward between beneficiaries.
         ///
         /// # Arguments
@@ -219,32 +476,43 @@ pub mod pallet {
         fn distribute_rewards(block_reward: NegativeImbalanceOf<T>) {
             let distro_params = Self::reward_config();
 
-            // Pre-calculate balance which will be deposited for each beneficiary
-            let base_staker_balance = distro_params.base_staker_percent * block_reward.peek();
-            let dapps_balance = distro_params.dapps_percent * block_reward.peek();
-            let collator_balance = distro_params.collators_percent * block_reward.peek();
-
             // This is part that's distributed between stakers and treasury
             let adjustable_balance = distro_params.adjustable_percent * block_reward.peek();
 
-            // Calculate total staker and treasury reward balance
-            let adjustable_staker_part = if distro_params.ideal_dapps_staking_tvl.is_zero() {
+            // Calculate staker part of the adjustable reward, the rest goes to treasury
+            let mut adjustable_staker_part = if distro_params.ideal_dapps_staking_tvl.is_zero() {
                 adjustable_balance
             } else {
                 Self::tvl_percentage() / distro_params.ideal_dapps_staking_tvl * adjustable_balance
             };
 
-            let total_staker_balance = base_staker_balance + adjustable_staker_part;
+            // Payout beneficiaries, treasury receives the remainder
+            let total_balance = block_reward.peek();
+            let mut remainder = block_reward;
+            for (beneficiary, percent) in distro_params.beneficiaries.iter() {
+                if *beneficiary == BeneficiaryId::Treasury {
+                    continue;
+                }
+
+                let mut balance = *percent * total_balance;
+                if *beneficiary == BeneficiaryId::DappsStakers {
+                    balance =
+                        balance.saturating_add(sp_std::mem::take(&mut adjustable_staker_part));
+                }
+
+                let (imbalance, new_remainder) = remainder.split(balance);
+                remainder = new_remainder;
+                T::BeneficiaryPayout::payout(*beneficiary, imbalance);
+            }
 
-            // Prepare imbalances
-            let (dapps_imbalance, remainder) = block_reward.split(dapps_balance);
-            let (stakers_imbalance, remainder) = remainder.split(total_staker_balance);
-            let (collator_imbalance, treasury_imbalance) = remainder.split(collator_balance);
+            // Stakers are entitled to the adjustable part even if they aren't listed as a beneficiary
+            if !adjustable_staker_part.is_zero() {
+                let (imbalance, new_remainder) = remainder.split(adjustable_staker_part);
+                remainder = new_remainder;
+                T::BeneficiaryPayout::payout(BeneficiaryId::DappsStakers, imbalance);
+            }
 
-            // Payout beneficiaries
-            T::BeneficiaryPayout::treasury(treasury_imbalance);
-            T::BeneficiaryPayout::collators(collator_imbalance);
-            T::BeneficiaryPayout::dapps_staking(stakers_imbalance, dapps_imbalance);
+            T::BeneficiaryPayout::payout(BeneficiaryId::Treasury, remainder);
         }
 
         /// Provides TVL as percentage of total issuance
@@ -260,6 +528,91 @@ pub mod pallet {
     }
 }
 
+/// Number of milliseconds in a year, used to calculate the time-based block reward.
+/// Average year length of 365.25 days is assumed.
+pub const MILLISECS_PER_YEAR: u64 = 31_557_600_000;
+
+/// Mode used to determine the amount issued for each block.
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub enum InflationMode {
+    /// Constant `RewardAmount` is issued for each block.
+    Fixed,
+    /// Annual issuance is spread evenly over `blocks_per_year` blocks.
+    PerBlock {
+        /// Annual issuance as part of the total issuance
+        annual_inflation: Perbill,
+        /// Expected number of blocks produced in a year
+        blocks_per_year: u32,
+    },
+    /// Annual issuance is issued proportionally to the time elapsed since the previous block.
+    PerElapsedTime {
+        /// Annual issuance as part of the total issuance
+        annual_inflation: Perbill,
+    },
+}
+
+impl Default for InflationMode {
+    fn default() -> Self {
+        InflationMode::Fixed
+    }
+}
+
+impl InflationMode {
+    /// `true` if inflation mode parameters can be used to calculate the block reward, `false` otherwise.
+    pub fn is_valid(&self) -> bool {
+        match self {
+            InflationMode::PerBlock {
+                blocks_per_year, ..
+            } => !blocks_per_year.is_zero(),
+            _ => true,
+        }
+    }
+}
+
+/// Point from which the scheduled reward distribution configuration is used.
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub enum Activation<BlockNumber> {
+    /// Configuration is used from the specified block.
+    AtBlock(BlockNumber),
+    /// Configuration is used from the first block of the next dapps staking era.
+    NextEra,
+}
+
+/// Reward distribution configuration change, scheduled to be applied in the future.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ScheduledConfig<BlockNumber> {
+    /// Configuration which will be applied
+    pub config: RewardDistributionConfig,
+    /// Point from which the configuration is used
+    pub activation: Activation<BlockNumber>,
+    /// Dapps staking era in which the change was scheduled
+    pub scheduled_era: u32,
+}
+
+/// Maximum number of beneficiaries in the reward distribution configuration.
+pub const MAX_BENEFICIARIES: u32 = 16;
+
+/// Identifies the beneficiary of the block reward.
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub enum BeneficiaryId {
+    /// Treasury, also receives the undistributed remainder of the block reward
+    Treasury,
+    /// Collators
+    Collators,
+    /// Dapps staking stakers, also receive their part of the adjustable reward
+    DappsStakers,
+    /// Dapps registered in dapps staking
+    Dapps,
+    /// Beneficiary defined by the runtime, e.g. a parachain lease fund or an ecosystem grant pot
+    Custom([u8; 8]),
+}
+
+/// Bounded list of beneficiaries, together with the percentage of the block reward they receive.
+pub type BeneficiaryList = BoundedVec<(BeneficiaryId, Perbill), ConstU32<MAX_BENEFICIARIES>>;
+
 /// List of configuration parameters used to calculate reward distribution portions for all the beneficiaries.
 ///
 /// Note that if `ideal_dapps_staking_tvl` is set to `Zero`, entire `adjustable_percent` goes to the stakers.
@@ -267,18 +620,8 @@ pub mod pallet {
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub struct RewardDistributionConfig {
-    /// Base percentage of reward that goes to treasury
-    #[codec(compact)]
-    pub base_treasury_percent: Perbill,
-    /// Base percentage of reward that goes to stakers
-    #[codec(compact)]
-    pub base_staker_percent: Perbill,
-    /// Percentage of rewards that goes to dApps
-    #[codec(compact)]
-    pub dapps_percent: Perbill,
-    /// Percentage of reward that goes to collators
-    #[codec(compact)]
-    pub collators_percent: Perbill,
+    /// Percentage of reward that goes to each of the beneficiaries
+    pub beneficiaries: BeneficiaryList,
     /// Adjustable reward percentage that either goes to treasury or to stakers
     #[codec(compact)]
     pub adjustable_percent: Perbill,
@@ -292,10 +635,12 @@ impl Default for RewardDistributionConfig {
     /// Should be overriden by desired params.
     fn default() -> Self {
         RewardDistributionConfig {
-            base_treasury_percent: Perbill::from_percent(40),
-            base_staker_percent: Perbill::from_percent(25),
-            dapps_percent: Perbill::from_percent(25),
-            collators_percent: Perbill::from_percent(10),
+            beneficiaries: BoundedVec::truncate_from(vec![
+                (BeneficiaryId::Treasury, Perbill::from_percent(40)),
+                (BeneficiaryId::DappsStakers, Perbill::from_percent(25)),
+                (BeneficiaryId::Dapps, Perbill::from_percent(25)),
+                (BeneficiaryId::Collators, Perbill::from_percent(10)),
+            ]),
             adjustable_percent: Zero::zero(),
             ideal_dapps_staking_tvl: Zero::zero(),
         }
@@ -303,24 +648,18 @@ impl Default for RewardDistributionConfig {
 }
 
 impl RewardDistributionConfig {
-    /// `true` if sum of all percentages is `one whole`, `false` otherwise.
+    /// `true` if sum of all percentages is `one whole` and no beneficiary is listed twice, `false` otherwise.
     pub fn is_consistent(&self) -> bool {
-        // TODO: perhaps this can be writen in a more cleaner way?
-        // experimental-only `try_reduce` could be used but it's not available
-        // https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.try_reduce
-
-        let variables = vec![
-            &self.base_treasury_percent,
-            &self.base_staker_percent,
-            &self.dapps_percent,
-            &self.collators_percent,
-            &self.adjustable_percent,
-        ];
-
-        let mut accumulator = Perbill::zero();
-        for config_param in variables {
-            let result = accumulator.checked_add(config_param);
-            if let Some(mid_result) = result {
+        let mut accumulator = self.adjustable_percent;
+        for (index, (beneficiary, percent)) in self.beneficiaries.iter().enumerate() {
+            if self.beneficiaries[..index]
+                .iter()
+                .any(|(other, _)| other == beneficiary)
+            {
+                return false;
+            }
+
+            if let Some(mid_result) = accumulator.checked_add(percent) {
                 accumulator = mid_result;
             } else {
                 return false;
@@ -329,22 +668,24 @@ impl RewardDistributionConfig {
 
         Perbill::one() == accumulator
     }
+
+    /// Base percentage of reward that goes to the beneficiary, `Zero` if it isn't listed.
+    pub fn percent_of(&self, beneficiary: BeneficiaryId) -> Perbill {
+        self.beneficiaries
+            .iter()
+            .find(|(id, _)| *id == beneficiary)
+            .map_or(Zero::zero(), |(_, percent)| *percent)
+    }
 }
 
 /// Defines functions used to payout the beneficiaries of block rewards
 pub trait BeneficiaryPayout<Imbalance> {
-    /// Payout reward to the treasury
-    fn treasury(reward: Imbalance);
-
-    /// Payout reward to the collators
-    fn collators(reward: Imbalance);
-
-    /// Payout reward to dapps staking
+    /// Payout reward to the beneficiary
     ///
     /// # Arguments
     ///
-    /// * `stakers` - reward that goes towards staker reward pot
-    /// * `dapps`   - reward that goes towards dapps reward pot
+    /// * `beneficiary` - identifies the beneficiary of the reward
+    /// * `reward`      - reward that goes towards the beneficiary
     ///
-    fn dapps_staking(stakers: Imbalance, dapps: Imbalance);
+    fn payout(beneficiary: BeneficiaryId, reward: Imbalance);
 }
@@ -0,0 +1,115 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Block reward storage migrations.
+
+use super::*;
+use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// Layout of `RewardDistributionConfig` prior to the introduction of the beneficiary list.
+#[derive(Encode, Decode)]
+pub(crate) struct OldRewardDistributionConfig {
+    #[codec(compact)]
+    pub(crate) base_treasury_percent: Perbill,
+    #[codec(compact)]
+    pub(crate) base_staker_percent: Perbill,
+    #[codec(compact)]
+    pub(crate) dapps_percent: Perbill,
+    #[codec(compact)]
+    pub(crate) collators_percent: Perbill,
+    #[codec(compact)]
+    pub(crate) adjustable_percent: Perbill,
+    #[codec(compact)]
+    pub(crate) ideal_dapps_staking_tvl: Perbill,
+}
+
+impl From<OldRewardDistributionConfig> for RewardDistributionConfig {
+    fn from(old: OldRewardDistributionConfig) -> Self {
+        RewardDistributionConfig {
+            beneficiaries: BoundedVec::truncate_from(vec![
+                (BeneficiaryId::Treasury, old.base_treasury_percent),
+                (BeneficiaryId::DappsStakers, old.base_staker_percent),
+                (BeneficiaryId::Dapps, old.dapps_percent),
+                (BeneficiaryId::Collators, old.collators_percent),
+            ]),
+            adjustable_percent: old.adjustable_percent,
+            ideal_dapps_staking_tvl: old.ideal_dapps_staking_tvl,
+        }
+    }
+}
+
+/// Migrates `RewardDistributionConfigStorage` to the beneficiary list layout.
+///
+/// Each of the old fixed beneficiary percentages becomes an entry in the list,
+/// adjustable part of the reward keeps its semantics.
+///
+/// `ScheduledConfigStorage` was never deployed with the old layout, so it doesn't need to be migrated.
+pub struct BeneficiaryListMigration<T: Config>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for BeneficiaryListMigration<T> {
+    fn on_runtime_upgrade() -> Weight {
+        if Pallet::<T>::on_chain_storage_version() != 0 {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let _ = RewardDistributionConfigStorage::<T>::translate::<OldRewardDistributionConfig, _>(
+            |old| old.map(Into::into),
+        );
+
+        StorageVersion::new(1).put::<Pallet<T>>();
+
+        T::DbWeight::get().reads_writes(2, 2)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+        let old_config = frame_support::storage::unhashed::get::<OldRewardDistributionConfig>(
+            &RewardDistributionConfigStorage::<T>::hashed_key(),
+        );
+
+        Ok(old_config.encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+        let old_config: Option<OldRewardDistributionConfig> =
+            Decode::decode(&mut state.as_ref())
+                .map_err(|_| "Failed to decode pre-upgrade state.")?;
+
+        ensure!(
+            Pallet::<T>::on_chain_storage_version() == 1,
+            "Storage version must be 1 at the end of migration."
+        );
+        if let Some(old_config) = old_config {
+            ensure!(
+                RewardDistributionConfigStorage::<T>::get()
+                    == RewardDistributionConfig::from(old_config),
+                "Reward distribution config must be preserved."
+            );
+        }
+        ensure!(
+            RewardDistributionConfigStorage::<T>::get().is_consistent(),
+            "Reward distribution config must be consistent."
+        );
+
+        Ok(())
+    }
+}
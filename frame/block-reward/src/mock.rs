@@ -16,7 +16,7 @@
 // You should have received a copy of the GNU General Public License
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
-use crate::{self as pallet_block_reward, NegativeImbalanceOf};
+use crate::{self as pallet_block_reward, BeneficiaryId, NegativeImbalanceOf};
 
 use frame_support::{
     construct_runtime, parameter_types, sp_io::TestExternalities, traits::Currency, traits::Get,
@@ -140,32 +140,38 @@ pub struct BeneficiaryPayout();
 impl pallet_block_reward::BeneficiaryPayout<NegativeImbalanceOf<TestRuntime>>
     for BeneficiaryPayout
 {
-    fn treasury(reward: NegativeImbalanceOf<TestRuntime>) {
-        Balances::resolve_creating(&TREASURY_POT.into_account_truncating(), reward);
-    }
-
-    fn collators(reward: NegativeImbalanceOf<TestRuntime>) {
-        Balances::resolve_creating(&COLLATOR_POT.into_account_truncating(), reward);
+    fn payout(beneficiary: BeneficiaryId, reward: NegativeImbalanceOf<TestRuntime>) {
+        let pot = match beneficiary {
+            BeneficiaryId::Treasury => TREASURY_POT,
+            BeneficiaryId::Collators => COLLATOR_POT,
+            BeneficiaryId::DappsStakers => STAKERS_POT,
+            BeneficiaryId::Dapps => DAPPS_POT,
+            BeneficiaryId::Custom(id) => PalletId(id),
+        };
+        Balances::resolve_creating(&pot.into_account_truncating(), reward);
     }
+}
 
-    fn dapps_staking(
-        stakers: NegativeImbalanceOf<TestRuntime>,
-        dapps: NegativeImbalanceOf<TestRuntime>,
-    ) {
-        Balances::resolve_creating(&STAKERS_POT.into_account_truncating(), stakers);
-        Balances::resolve_creating(&DAPPS_POT.into_account_truncating(), dapps);
-    }
+parameter_types! {
+    // Dapps staking era, can be changed by the tests
+    pub static DappsStakingEra: u32 = 1;
 }
 
+// Number of blocks after which dynamic block reward is recalculated.
+pub(crate) const RECALCULATION_PERIOD: BlockNumber = 10;
+
 parameter_types! {
     pub const RewardAmount: Balance = BLOCK_REWARD;
+    pub const InflationRecalculationPeriod: BlockNumber = RECALCULATION_PERIOD;
 }
 
 impl pallet_block_reward::Config for TestRuntime {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
     type RewardAmount = RewardAmount;
+    type InflationRecalculationPeriod = InflationRecalculationPeriod;
     type DappsStakingTvlProvider = TvlProvider;
+    type DappsStakingEraProvider = DappsStakingEra;
     type BeneficiaryPayout = BeneficiaryPayout;
     type WeightInfo = ();
 }
@@ -16,8 +16,17 @@
 // You should have received a copy of the GNU General Public License
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
-use super::{pallet::Error, Event, *};
-use frame_support::{assert_noop, assert_ok, traits::OnTimestampSet};
+use super::{
+    migrations::{BeneficiaryListMigration, OldRewardDistributionConfig},
+    pallet::Error,
+    Event, *,
+};
+use frame_support::{
+    assert_noop, assert_ok,
+    storage::unhashed,
+    traits::{Hooks, OnRuntimeUpgrade, OnTimestampSet},
+    PalletId,
+};
 use mock::*;
 use sp_runtime::{
     traits::{AccountIdConversion, BadOrigin, Zero},
@@ -34,10 +43,12 @@ fn default_reward_distribution_config_is_consitent() {
 fn reward_distribution_config_is_consistent() {
     // 1
     let reward_config = RewardDistributionConfig {
-        base_treasury_percent: Perbill::from_percent(100),
-        base_staker_percent: Zero::zero(),
-        dapps_percent: Zero::zero(),
-        collators_percent: Zero::zero(),
+        beneficiaries: beneficiaries(vec![
+            (BeneficiaryId::Treasury, Perbill::from_percent(100)),
+            (BeneficiaryId::DappsStakers, Zero::zero()),
+            (BeneficiaryId::Dapps, Zero::zero()),
+            (BeneficiaryId::Collators, Zero::zero()),
+        ]),
         adjustable_percent: Zero::zero(),
         ideal_dapps_staking_tvl: Zero::zero(),
     };
@@ -45,10 +56,12 @@ fn reward_distribution_config_is_consistent() {
 
     // 2
     let reward_config = RewardDistributionConfig {
-        base_treasury_percent: Zero::zero(),
-        base_staker_percent: Perbill::from_percent(100),
-        dapps_percent: Zero::zero(),
-        collators_percent: Zero::zero(),
+        beneficiaries: beneficiaries(vec![
+            (BeneficiaryId::Treasury, Zero::zero()),
+            (BeneficiaryId::DappsStakers, Perbill::from_percent(100)),
+            (BeneficiaryId::Dapps, Zero::zero()),
+            (BeneficiaryId::Collators, Zero::zero()),
+        ]),
         adjustable_percent: Zero::zero(),
         ideal_dapps_staking_tvl: Zero::zero(),
     };
@@ -56,10 +69,12 @@ fn reward_distribution_config_is_consistent() {
 
     // 3
     let reward_config = RewardDistributionConfig {
-        base_treasury_percent: Zero::zero(),
-        base_staker_percent: Zero::zero(),
-        dapps_percent: Zero::zero(),
-        collators_percent: Zero::zero(),
+        beneficiaries: beneficiaries(vec![
+            (BeneficiaryId::Treasury, Zero::zero()),
+            (BeneficiaryId::DappsStakers, Zero::zero()),
+            (BeneficiaryId::Dapps, Zero::zero()),
+            (BeneficiaryId::Collators, Zero::zero()),
+        ]),
         adjustable_percent: Perbill::from_percent(100),
         ideal_dapps_staking_tvl: Perbill::from_percent(13),
     };
@@ -68,21 +83,42 @@ fn reward_distribution_config_is_consistent() {
     // 4
     // 100%
     let reward_config = RewardDistributionConfig {
-        base_treasury_percent: Perbill::from_percent(3),
-        base_staker_percent: Perbill::from_percent(14),
-        dapps_percent: Perbill::from_percent(18),
-        collators_percent: Perbill::from_percent(31),
+        beneficiaries: beneficiaries(vec![
+            (BeneficiaryId::Treasury, Perbill::from_percent(3)),
+            (BeneficiaryId::DappsStakers, Perbill::from_percent(14)),
+            (BeneficiaryId::Dapps, Perbill::from_percent(18)),
+            (BeneficiaryId::Collators, Perbill::from_percent(31)),
+        ]),
         adjustable_percent: Perbill::from_percent(34),
         ideal_dapps_staking_tvl: Zero::zero(),
     };
     assert!(reward_config.is_consistent());
+
+    // 5
+    // custom beneficiaries, no stakers entry
+    let reward_config = RewardDistributionConfig {
+        beneficiaries: beneficiaries(vec![
+            (BeneficiaryId::Treasury, Perbill::from_percent(20)),
+            (
+                BeneficiaryId::Custom(*b"leasefnd"),
+                Perbill::from_percent(30),
+            ),
+            (
+                BeneficiaryId::Custom(*b"ecogrant"),
+                Perbill::from_percent(10),
+            ),
+        ]),
+        adjustable_percent: Perbill::from_percent(40),
+        ideal_dapps_staking_tvl: Perbill::from_percent(50),
+    };
+    assert!(reward_config.is_consistent());
 }
 
 #[test]
 fn reward_distribution_config_not_consistent() {
     // 1
     let reward_config = RewardDistributionConfig {
-        base_treasury_percent: Perbill::from_percent(100),
+        beneficiaries: beneficiaries(vec![(BeneficiaryId::Treasury, Perbill::from_percent(90))]),
         ..Default::default()
     };
     assert!(!reward_config.is_consistent());
@@ -97,10 +133,12 @@ fn reward_distribution_config_not_consistent() {
     // 3
     // 99%
     let reward_config = RewardDistributionConfig {
-        base_treasury_percent: Perbill::from_percent(10),
-        base_staker_percent: Perbill::from_percent(20),
-        dapps_percent: Perbill::from_percent(20),
-        collators_percent: Perbill::from_percent(30),
+        beneficiaries: beneficiaries(vec![
+            (BeneficiaryId::Treasury, Perbill::from_percent(10)),
+            (BeneficiaryId::DappsStakers, Perbill::from_percent(20)),
+            (BeneficiaryId::Dapps, Perbill::from_percent(20)),
+            (BeneficiaryId::Collators, Perbill::from_percent(30)),
+        ]),
         adjustable_percent: Perbill::from_percent(19),
         ideal_dapps_staking_tvl: Zero::zero(),
     };
@@ -109,14 +147,36 @@ fn reward_distribution_config_not_consistent() {
     // 4
     // 101%
     let reward_config = RewardDistributionConfig {
-        base_treasury_percent: Perbill::from_percent(10),
-        base_staker_percent: Perbill::from_percent(20),
-        dapps_percent: Perbill::from_percent(20),
-        collators_percent: Perbill::from_percent(31),
+        beneficiaries: beneficiaries(vec![
+            (BeneficiaryId::Treasury, Perbill::from_percent(10)),
+            (BeneficiaryId::DappsStakers, Perbill::from_percent(20)),
+            (BeneficiaryId::Dapps, Perbill::from_percent(20)),
+            (BeneficiaryId::Collators, Perbill::from_percent(31)),
+        ]),
         adjustable_percent: Perbill::from_percent(20),
         ideal_dapps_staking_tvl: Zero::zero(),
     };
     assert!(!reward_config.is_consistent());
+
+    // 5
+    // 100%, but with duplicate beneficiary
+    let reward_config = RewardDistributionConfig {
+        beneficiaries: beneficiaries(vec![
+            (BeneficiaryId::Treasury, Perbill::from_percent(10)),
+            (BeneficiaryId::Dapps, Perbill::from_percent(20)),
+            (
+                BeneficiaryId::Custom(*b"leasefnd"),
+                Perbill::from_percent(30),
+            ),
+            (
+                BeneficiaryId::Custom(*b"leasefnd"),
+                Perbill::from_percent(40),
+            ),
+        ]),
+        adjustable_percent: Zero::zero(),
+        ideal_dapps_staking_tvl: Zero::zero(),
+    };
+    assert!(!reward_config.is_consistent());
 }
 
 #[test]
@@ -130,7 +190,10 @@ pub fn set_configuration_fails() {
 
         // 2
         let reward_config = RewardDistributionConfig {
-            base_treasury_percent: Perbill::from_percent(100),
+            beneficiaries: beneficiaries(vec![(
+                BeneficiaryId::Treasury,
+                Perbill::from_percent(90),
+            )]),
             ..Default::default()
         };
         assert!(!reward_config.is_consistent());
@@ -146,10 +209,12 @@ pub fn set_configuration_is_ok() {
     ExternalityBuilder::build().execute_with(|| {
         // custom config so it differs from the default one
         let reward_config = RewardDistributionConfig {
-            base_treasury_percent: Perbill::from_percent(3),
-            base_staker_percent: Perbill::from_percent(14),
-            dapps_percent: Perbill::from_percent(18),
-            collators_percent: Perbill::from_percent(31),
+            beneficiaries: beneficiaries(vec![
+                (BeneficiaryId::Treasury, Perbill::from_percent(3)),
+                (BeneficiaryId::DappsStakers, Perbill::from_percent(14)),
+                (BeneficiaryId::Dapps, Perbill::from_percent(18)),
+                (BeneficiaryId::Collators, Perbill::from_percent(31)),
+            ]),
             adjustable_percent: Perbill::from_percent(34),
             ideal_dapps_staking_tvl: Perbill::from_percent(87),
         };
@@ -198,10 +263,12 @@ pub fn reward_distribution_as_expected() {
 
         // Prepare a custom config (easily discernable percentages for visual verification)
         let reward_config = RewardDistributionConfig {
-            base_treasury_percent: Perbill::from_percent(10),
-            base_staker_percent: Perbill::from_percent(20),
-            dapps_percent: Perbill::from_percent(25),
-            collators_percent: Perbill::from_percent(5),
+            beneficiaries: beneficiaries(vec![
+                (BeneficiaryId::Treasury, Perbill::from_percent(10)),
+                (BeneficiaryId::DappsStakers, Perbill::from_percent(20)),
+                (BeneficiaryId::Dapps, Perbill::from_percent(25)),
+                (BeneficiaryId::Collators, Perbill::from_percent(5)),
+            ]),
             adjustable_percent: Perbill::from_percent(40),
             ideal_dapps_staking_tvl: Perbill::from_percent(50),
         };
@@ -231,10 +298,12 @@ pub fn reward_distribution_as_expected() {
 pub fn reward_distribution_no_adjustable_part() {
     ExternalityBuilder::build().execute_with(|| {
         let reward_config = RewardDistributionConfig {
-            base_treasury_percent: Perbill::from_percent(10),
-            base_staker_percent: Perbill::from_percent(45),
-            dapps_percent: Perbill::from_percent(40),
-            collators_percent: Perbill::from_percent(5),
+            beneficiaries: beneficiaries(vec![
+                (BeneficiaryId::Treasury, Perbill::from_percent(10)),
+                (BeneficiaryId::DappsStakers, Perbill::from_percent(45)),
+                (BeneficiaryId::Dapps, Perbill::from_percent(40)),
+                (BeneficiaryId::Collators, Perbill::from_percent(5)),
+            ]),
             adjustable_percent: Perbill::zero(),
             ideal_dapps_staking_tvl: Perbill::from_percent(50), // this is irrelevant
         };
@@ -265,10 +334,12 @@ pub fn reward_distribution_no_adjustable_part() {
 pub fn reward_distribution_all_zero_except_one() {
     ExternalityBuilder::build().execute_with(|| {
         let reward_config = RewardDistributionConfig {
-            base_treasury_percent: Perbill::zero(),
-            base_staker_percent: Perbill::zero(),
-            dapps_percent: Perbill::zero(),
-            collators_percent: Perbill::zero(),
+            beneficiaries: beneficiaries(vec![
+                (BeneficiaryId::Treasury, Perbill::zero()),
+                (BeneficiaryId::DappsStakers, Perbill::zero()),
+                (BeneficiaryId::Dapps, Perbill::zero()),
+                (BeneficiaryId::Collators, Perbill::zero()),
+            ]),
             adjustable_percent: Perbill::one(),
             ideal_dapps_staking_tvl: Perbill::from_percent(50), // this is irrelevant
         };
@@ -290,6 +361,476 @@ pub fn reward_distribution_all_zero_except_one() {
     })
 }
 
+#[test]
+pub fn reward_distribution_custom_beneficiaries() {
+    ExternalityBuilder::build().execute_with(|| {
+        let lease_fund = BeneficiaryId::Custom(*b"leasefnd");
+        let grant_pot = BeneficiaryId::Custom(*b"ecogrant");
+        let reward_config = RewardDistributionConfig {
+            beneficiaries: beneficiaries(vec![
+                (lease_fund, Perbill::from_percent(15)),
+                (BeneficiaryId::Dapps, Perbill::from_percent(25)),
+                (grant_pot, Perbill::from_percent(5)),
+                (BeneficiaryId::Treasury, Perbill::from_percent(15)),
+            ]),
+            adjustable_percent: Perbill::from_percent(40),
+            ideal_dapps_staking_tvl: Perbill::from_percent(50),
+        };
+        assert!(reward_config.is_consistent());
+        assert_ok!(BlockReward::set_configuration(
+            RuntimeOrigin::root(),
+            reward_config.clone()
+        ));
+        adjust_tvl_percentage(Perbill::from_percent(30));
+
+        let custom_balance = |id: [u8; 8]| {
+            <TestRuntime as Config>::Currency::free_balance(&PalletId(id).into_account_truncating())
+        };
+
+        for _block in 1..=10 {
+            let init_balance_state = FreeBalanceSnapshot::new();
+            let init_lease_fund = custom_balance(*b"leasefnd");
+            let init_grant_pot = custom_balance(*b"ecogrant");
+            // Stakers keep receiving the adjustable part even though they aren't listed
+            let rewards = Rewards::calculate(&reward_config);
+            assert!(!rewards.adjustable_staker_reward.is_zero());
+
+            BlockReward::on_timestamp_set(0);
+
+            let final_balance_state = FreeBalanceSnapshot::new();
+            init_balance_state.assert_distribution(&final_balance_state, &rewards);
+            assert_eq!(
+                custom_balance(*b"leasefnd"),
+                init_lease_fund + reward_config.percent_of(lease_fund) * BLOCK_REWARD
+            );
+            assert_eq!(
+                custom_balance(*b"ecogrant"),
+                init_grant_pot + reward_config.percent_of(grant_pot) * BLOCK_REWARD
+            );
+        }
+    })
+}
+
+#[test]
+pub fn beneficiary_list_migration_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        StorageVersion::new(0).put::<BlockReward>();
+        let old_config = OldRewardDistributionConfig {
+            base_treasury_percent: Perbill::from_percent(10),
+            base_staker_percent: Perbill::from_percent(20),
+            dapps_percent: Perbill::from_percent(25),
+            collators_percent: Perbill::from_percent(5),
+            adjustable_percent: Perbill::from_percent(40),
+            ideal_dapps_staking_tvl: Perbill::from_percent(50),
+        };
+        unhashed::put(
+            &RewardDistributionConfigStorage::<TestRuntime>::hashed_key(),
+            &old_config,
+        );
+
+        BeneficiaryListMigration::<TestRuntime>::on_runtime_upgrade();
+
+        assert_eq!(BlockReward::on_chain_storage_version(), 1);
+        let reward_config = BlockReward::reward_config();
+        assert!(reward_config.is_consistent());
+        assert_eq!(
+            reward_config,
+            RewardDistributionConfig {
+                beneficiaries: beneficiaries(vec![
+                    (BeneficiaryId::Treasury, Perbill::from_percent(10)),
+                    (BeneficiaryId::DappsStakers, Perbill::from_percent(20)),
+                    (BeneficiaryId::Dapps, Perbill::from_percent(25)),
+                    (BeneficiaryId::Collators, Perbill::from_percent(5)),
+                ]),
+                adjustable_percent: Perbill::from_percent(40),
+                ideal_dapps_staking_tvl: Perbill::from_percent(50),
+            }
+        );
+
+        // Migration is only executed once
+        assert_ok!(BlockReward::set_configuration(
+            RuntimeOrigin::root(),
+            Default::default()
+        ));
+        BeneficiaryListMigration::<TestRuntime>::on_runtime_upgrade();
+        assert_eq!(
+            BlockReward::reward_config(),
+            RewardDistributionConfig::default()
+        );
+    })
+}
+
+#[test]
+pub fn set_inflation_mode_fails() {
+    ExternalityBuilder::build().execute_with(|| {
+        // 1
+        assert_noop!(
+            BlockReward::set_inflation_mode(RuntimeOrigin::signed(1), InflationMode::Fixed),
+            BadOrigin
+        );
+
+        // 2
+        let inflation_mode = InflationMode::PerBlock {
+            annual_inflation: Perbill::from_percent(10),
+            blocks_per_year: 0,
+        };
+        assert!(!inflation_mode.is_valid());
+        assert_noop!(
+            BlockReward::set_inflation_mode(RuntimeOrigin::root(), inflation_mode),
+            Error::<TestRuntime>::InvalidInflationMode,
+        );
+    })
+}
+
+#[test]
+pub fn set_inflation_mode_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_eq!(BlockReward::inflation_mode(), InflationMode::Fixed);
+
+        let inflation_mode = InflationMode::PerElapsedTime {
+            annual_inflation: Perbill::from_percent(7),
+        };
+        NextRecalculation::<TestRuntime>::put(100);
+        assert_ok!(BlockReward::set_inflation_mode(
+            RuntimeOrigin::root(),
+            inflation_mode
+        ));
+        System::assert_last_event(mock::RuntimeEvent::BlockReward(
+            Event::InflationModeChanged(inflation_mode),
+        ));
+
+        // Annual issuance is recalculated in the next block
+        assert_eq!(BlockReward::inflation_mode(), inflation_mode);
+        assert!(BlockReward::next_recalculation().is_zero());
+    })
+}
+
+#[test]
+pub fn per_block_inflation_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        // Ensure issuance is large enough to produce a meaningful block reward
+        <TestRuntime as Config>::Currency::resolve_creating(
+            &1,
+            <TestRuntime as Config>::Currency::issue(TVL),
+        );
+
+        let annual_inflation = Perbill::from_percent(10);
+        let blocks_per_year = 1_000;
+        assert_ok!(BlockReward::set_inflation_mode(
+            RuntimeOrigin::root(),
+            InflationMode::PerBlock {
+                annual_inflation,
+                blocks_per_year,
+            }
+        ));
+
+        for _cycle in 0..3 {
+            // Annual issuance is derived from the total issuance at the start of the cycle
+            let annual_issuance =
+                annual_inflation * <TestRuntime as Config>::Currency::total_issuance();
+            let expected_reward = annual_issuance / blocks_per_year as Balance;
+
+            for block in 0..RECALCULATION_PERIOD {
+                let init_issuance = <TestRuntime as Config>::Currency::total_issuance();
+                BlockReward::on_timestamp_set(0);
+                assert_eq!(
+                    <TestRuntime as Config>::Currency::total_issuance(),
+                    init_issuance + expected_reward
+                );
+
+                if block.is_zero() {
+                    System::assert_has_event(mock::RuntimeEvent::BlockReward(
+                        Event::AnnualIssuanceRecalculated(annual_issuance),
+                    ));
+                }
+                System::set_block_number(System::block_number() + 1);
+            }
+        }
+
+        // Switching back to the fixed mode uses the constant reward again
+        assert_ok!(BlockReward::set_inflation_mode(
+            RuntimeOrigin::root(),
+            InflationMode::Fixed
+        ));
+        let init_issuance = <TestRuntime as Config>::Currency::total_issuance();
+        BlockReward::on_timestamp_set(0);
+        assert_eq!(
+            <TestRuntime as Config>::Currency::total_issuance(),
+            init_issuance + BLOCK_REWARD
+        );
+    })
+}
+
+#[test]
+pub fn per_elapsed_time_inflation_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        // Ensure issuance is large enough to produce a meaningful block reward
+        <TestRuntime as Config>::Currency::resolve_creating(
+            &1,
+            <TestRuntime as Config>::Currency::issue(TVL),
+        );
+
+        let annual_inflation = Perbill::from_percent(10);
+        assert_ok!(BlockReward::set_inflation_mode(
+            RuntimeOrigin::root(),
+            InflationMode::PerElapsedTime { annual_inflation }
+        ));
+        let annual_issuance =
+            annual_inflation * <TestRuntime as Config>::Currency::total_issuance();
+
+        // Nothing is issued without the previous block timestamp
+        let init_issuance = <TestRuntime as Config>::Currency::total_issuance();
+        let mut timestamp: u64 = 1_000_000;
+        BlockReward::on_timestamp_set(timestamp);
+        assert_eq!(
+            <TestRuntime as Config>::Currency::total_issuance(),
+            init_issuance
+        );
+
+        // Reward is proportional to the time elapsed since the previous block
+        for elapsed in [12_000, 24_000, 6_000] {
+            System::set_block_number(System::block_number() + 1);
+            timestamp += elapsed;
+
+            let init_issuance = <TestRuntime as Config>::Currency::total_issuance();
+            BlockReward::on_timestamp_set(timestamp);
+            assert_eq!(
+                <TestRuntime as Config>::Currency::total_issuance(),
+                init_issuance
+                    + annual_issuance * elapsed as Balance / MILLISECS_PER_YEAR as Balance
+            );
+        }
+
+        // Timestamp is only tracked in the time-based mode
+        assert_ok!(BlockReward::set_inflation_mode(
+            RuntimeOrigin::root(),
+            InflationMode::Fixed
+        ));
+        assert_eq!(BlockReward::last_timestamp(), 0);
+        BlockReward::on_timestamp_set(timestamp + 12_000);
+        assert_eq!(BlockReward::last_timestamp(), 0);
+    })
+}
+
+#[test]
+pub fn block_reward_weight_is_registered() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_eq!(
+            BlockReward::on_initialize(System::block_number()),
+            <TestRuntime as Config>::WeightInfo::on_timestamp_set()
+        );
+    })
+}
+
+#[test]
+pub fn schedule_configuration_fails() {
+    ExternalityBuilder::build().execute_with(|| {
+        // 1
+        assert_noop!(
+            BlockReward::schedule_configuration(
+                RuntimeOrigin::signed(1),
+                Default::default(),
+                Activation::NextEra
+            ),
+            BadOrigin
+        );
+
+        // 2
+        let reward_config = RewardDistributionConfig {
+            beneficiaries: beneficiaries(vec![(
+                BeneficiaryId::Treasury,
+                Perbill::from_percent(90),
+            )]),
+            ..Default::default()
+        };
+        assert!(!reward_config.is_consistent());
+        assert_noop!(
+            BlockReward::schedule_configuration(
+                RuntimeOrigin::root(),
+                reward_config,
+                Activation::NextEra
+            ),
+            Error::<TestRuntime>::InvalidDistributionConfiguration,
+        );
+
+        // 3
+        let current_block = System::block_number();
+        assert_noop!(
+            BlockReward::schedule_configuration(
+                RuntimeOrigin::root(),
+                Default::default(),
+                Activation::AtBlock(current_block)
+            ),
+            Error::<TestRuntime>::InvalidActivation,
+        );
+
+        // 4
+        assert_noop!(
+            BlockReward::cancel_scheduled_configuration(RuntimeOrigin::signed(1)),
+            BadOrigin
+        );
+        assert_noop!(
+            BlockReward::cancel_scheduled_configuration(RuntimeOrigin::root()),
+            Error::<TestRuntime>::NoScheduledConfiguration,
+        );
+    })
+}
+
+#[test]
+pub fn schedule_and_cancel_configuration_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        DappsStakingEra::set(3);
+        let reward_config = RewardDistributionConfig {
+            beneficiaries: beneficiaries(vec![
+                (BeneficiaryId::Treasury, Perbill::from_percent(100)),
+                (BeneficiaryId::DappsStakers, Zero::zero()),
+                (BeneficiaryId::Dapps, Zero::zero()),
+                (BeneficiaryId::Collators, Zero::zero()),
+            ]),
+            adjustable_percent: Zero::zero(),
+            ideal_dapps_staking_tvl: Zero::zero(),
+        };
+
+        // Schedule the change and verify it's queryable
+        let activation = Activation::AtBlock(System::block_number() + 5);
+        assert_ok!(BlockReward::schedule_configuration(
+            RuntimeOrigin::root(),
+            reward_config.clone(),
+            activation
+        ));
+        System::assert_last_event(mock::RuntimeEvent::BlockReward(
+            Event::DistributionConfigurationScheduled(reward_config.clone(), activation),
+        ));
+        assert_eq!(
+            BlockReward::scheduled_config(),
+            Some(ScheduledConfig {
+                config: reward_config.clone(),
+                activation,
+                scheduled_era: 3,
+            })
+        );
+
+        // Scheduling again replaces the existing change
+        assert_ok!(BlockReward::schedule_configuration(
+            RuntimeOrigin::root(),
+            reward_config.clone(),
+            Activation::NextEra
+        ));
+        assert_eq!(
+            BlockReward::scheduled_config().map(|scheduled| scheduled.activation),
+            Some(Activation::NextEra)
+        );
+
+        // Cancel the change
+        assert_ok!(BlockReward::cancel_scheduled_configuration(
+            RuntimeOrigin::root()
+        ));
+        System::assert_last_event(mock::RuntimeEvent::BlockReward(
+            Event::ScheduledDistributionConfigurationCancelled,
+        ));
+        assert!(BlockReward::scheduled_config().is_none());
+        assert_eq!(
+            RewardDistributionConfigStorage::<TestRuntime>::get(),
+            RewardDistributionConfig::default()
+        );
+    })
+}
+
+#[test]
+pub fn scheduled_configuration_at_block_is_applied() {
+    ExternalityBuilder::build().execute_with(|| {
+        let reward_config = RewardDistributionConfig {
+            beneficiaries: beneficiaries(vec![
+                (BeneficiaryId::Treasury, Perbill::from_percent(100)),
+                (BeneficiaryId::DappsStakers, Zero::zero()),
+                (BeneficiaryId::Dapps, Zero::zero()),
+                (BeneficiaryId::Collators, Zero::zero()),
+            ]),
+            adjustable_percent: Zero::zero(),
+            ideal_dapps_staking_tvl: Zero::zero(),
+        };
+        let activation_block = System::block_number() + 2;
+        assert_ok!(BlockReward::schedule_configuration(
+            RuntimeOrigin::root(),
+            reward_config.clone(),
+            Activation::AtBlock(activation_block)
+        ));
+
+        // Before the activation block, the old config is still used
+        for _ in System::block_number()..activation_block {
+            BlockReward::on_timestamp_set(0);
+            assert_eq!(
+                RewardDistributionConfigStorage::<TestRuntime>::get(),
+                RewardDistributionConfig::default()
+            );
+            System::set_block_number(System::block_number() + 1);
+        }
+
+        // Config is applied before the reward is distributed in the activation block
+        let init_balance_snapshot = FreeBalanceSnapshot::new();
+        BlockReward::on_timestamp_set(0);
+        System::assert_has_event(mock::RuntimeEvent::BlockReward(
+            Event::DistributionConfigurationChanged(reward_config.clone()),
+        ));
+        assert_eq!(
+            RewardDistributionConfigStorage::<TestRuntime>::get(),
+            reward_config
+        );
+        assert!(BlockReward::scheduled_config().is_none());
+
+        let final_balance_snapshot = FreeBalanceSnapshot::new();
+        assert_eq!(
+            final_balance_snapshot.treasury - init_balance_snapshot.treasury,
+            BLOCK_REWARD
+        );
+    })
+}
+
+#[test]
+pub fn scheduled_configuration_at_next_era_is_applied() {
+    ExternalityBuilder::build().execute_with(|| {
+        DappsStakingEra::set(7);
+        let reward_config = RewardDistributionConfig {
+            beneficiaries: beneficiaries(vec![
+                (BeneficiaryId::Treasury, Perbill::from_percent(100)),
+                (BeneficiaryId::DappsStakers, Zero::zero()),
+                (BeneficiaryId::Dapps, Zero::zero()),
+                (BeneficiaryId::Collators, Zero::zero()),
+            ]),
+            adjustable_percent: Zero::zero(),
+            ideal_dapps_staking_tvl: Zero::zero(),
+        };
+        assert_ok!(BlockReward::schedule_configuration(
+            RuntimeOrigin::root(),
+            reward_config.clone(),
+            Activation::NextEra
+        ));
+
+        // Config isn't applied while the era remains the same
+        for _ in 0..3 {
+            System::set_block_number(System::block_number() + 1);
+            BlockReward::on_timestamp_set(0);
+            assert_eq!(
+                RewardDistributionConfigStorage::<TestRuntime>::get(),
+                RewardDistributionConfig::default()
+            );
+        }
+
+        // Config is applied once the next era starts
+        DappsStakingEra::set(8);
+        System::set_block_number(System::block_number() + 1);
+        BlockReward::on_timestamp_set(0);
+        System::assert_has_event(mock::RuntimeEvent::BlockReward(
+            Event::DistributionConfigurationChanged(reward_config.clone()),
+        ));
+        assert_eq!(
+            RewardDistributionConfigStorage::<TestRuntime>::get(),
+            reward_config
+        );
+        assert!(BlockReward::scheduled_config().is_none());
+    })
+}
+
 /// Represents free balance snapshot at a specific point in time
 #[derive(PartialEq, Eq, Clone, RuntimeDebug)]
 struct FreeBalanceSnapshot {
@@ -369,10 +910,11 @@ impl Rewards {
     ///
     fn calculate(reward_config: &RewardDistributionConfig) -> Self {
         // Calculate `tvl-independent` portions
-        let base_treasury_reward = reward_config.base_treasury_percent * BLOCK_REWARD;
-        let base_staker_reward = reward_config.base_staker_percent * BLOCK_REWARD;
-        let dapps_reward = reward_config.dapps_percent * BLOCK_REWARD;
-        let collators_reward = reward_config.collators_percent * BLOCK_REWARD;
+        let base_treasury_reward = reward_config.percent_of(BeneficiaryId::Treasury) * BLOCK_REWARD;
+        let base_staker_reward =
+            reward_config.percent_of(BeneficiaryId::DappsStakers) * BLOCK_REWARD;
+        let dapps_reward = reward_config.percent_of(BeneficiaryId::Dapps) * BLOCK_REWARD;
+        let collators_reward = reward_config.percent_of(BeneficiaryId::Collators) * BLOCK_REWARD;
         let adjustable_reward = reward_config.adjustable_percent * BLOCK_REWARD;
 
         // Calculate `tvl-dependent` portions
@@ -405,6 +947,11 @@ impl Rewards {
     }
 }
 
+/// Creates a bounded beneficiary list from the provided entries
+fn beneficiaries(entries: Vec<(BeneficiaryId, Perbill)>) -> BeneficiaryList {
+    BoundedVec::try_from(entries).expect("Number of test beneficiaries is within bounds.")
+}
+
 /// Adjusts total_issuance  in order to try-and-match the requested TVL percentage
 fn adjust_tvl_percentage(desired_tvl_percentage: Perbill) {
     // Calculate the required total issuance
@@ -22,6 +22,8 @@
 //! DATE: 2022-12-29, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! HOSTNAME: `shiden-collator-02-ovh`, CPU: `Intel(R) Xeon(R) E-2136 CPU @ 3.30GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("astar-dev"), DB CACHE: 1024
+//!
+//! NOTE: weight implementations also contain hand-written placeholder weights, in the sections marked as NOT AUTOGENERATED.
 
 // Executed Command:
 // ./astar-collator
@@ -56,6 +58,10 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for pallet-reward-distribution.
 pub trait WeightInfo {
     fn set_configuration() -> Weight;
+    fn set_inflation_mode() -> Weight;
+    fn schedule_configuration() -> Weight;
+    fn cancel_scheduled_configuration() -> Weight;
+    fn on_timestamp_set() -> Weight;
 }
 
 /// Weights for pallet-reward-distribution using the Substrate node and recommended hardware.
@@ -66,6 +72,50 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_ref_time(13_636_000 as u64)
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+
+	// ------------------------------------------------------------------------------------------------
+	// NOT AUTOGENERATED: placeholder weights.
+	//
+	// Weights below cover calls and hooks which were added or changed after the benchmark run described
+	// in the header. Ref times are estimated from comparable benchmarked calls and storage accesses are
+	// counted by hand from the implementations.
+	// TODO: re-run `pallet_block_reward` benchmarks and replace this section with the generated output.
+	// ------------------------------------------------------------------------------------------------
+	// Storage: BlockReward InflationModeStorage (r:0 w:1)
+	// Storage: BlockReward NextRecalculation (r:0 w:1)
+	// Storage: BlockReward LastTimestamp (r:0 w:1)
+	fn set_inflation_mode() -> Weight {
+		Weight::from_ref_time(15_104_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: DappsStaking CurrentEra (r:1 w:0)
+	// Storage: BlockReward ScheduledConfigStorage (r:0 w:1)
+	fn schedule_configuration() -> Weight {
+		Weight::from_ref_time(16_041_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: BlockReward ScheduledConfigStorage (r:1 w:1)
+	fn cancel_scheduled_configuration() -> Weight {
+		Weight::from_ref_time(14_572_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: BlockReward ScheduledConfigStorage (r:1 w:1)
+	// Storage: DappsStaking CurrentEra (r:1 w:0)
+	// Storage: BlockReward RewardDistributionConfigStorage (r:1 w:1)
+	// Storage: BlockReward InflationModeStorage (r:1 w:0)
+	// Storage: BlockReward NextRecalculation (r:1 w:1)
+	// Storage: Balances TotalIssuance (r:1 w:1)
+	// Storage: BlockReward AnnualIssuance (r:0 w:1)
+	// Storage: BlockReward LastTimestamp (r:1 w:1)
+	// Storage: DappsStaking GeneralEraInfo (r:1 w:0)
+	// Storage: System Account (r:17 w:17)
+	fn on_timestamp_set() -> Weight {
+		Weight::from_ref_time(97_415_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(25 as u64))
+			.saturating_add(T::DbWeight::get().writes(23 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -75,4 +125,48 @@ impl WeightInfo for () {
 		Weight::from_ref_time(13_636_000 as u64)
 			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
+
+	// ------------------------------------------------------------------------------------------------
+	// NOT AUTOGENERATED: placeholder weights.
+	//
+	// Weights below cover calls and hooks which were added or changed after the benchmark run described
+	// in the header. Ref times are estimated from comparable benchmarked calls and storage accesses are
+	// counted by hand from the implementations.
+	// TODO: re-run `pallet_block_reward` benchmarks and replace this section with the generated output.
+	// ------------------------------------------------------------------------------------------------
+	// Storage: BlockReward InflationModeStorage (r:0 w:1)
+	// Storage: BlockReward NextRecalculation (r:0 w:1)
+	// Storage: BlockReward LastTimestamp (r:0 w:1)
+	fn set_inflation_mode() -> Weight {
+		Weight::from_ref_time(15_104_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: DappsStaking CurrentEra (r:1 w:0)
+	// Storage: BlockReward ScheduledConfigStorage (r:0 w:1)
+	fn schedule_configuration() -> Weight {
+		Weight::from_ref_time(16_041_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: BlockReward ScheduledConfigStorage (r:1 w:1)
+	fn cancel_scheduled_configuration() -> Weight {
+		Weight::from_ref_time(14_572_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: BlockReward ScheduledConfigStorage (r:1 w:1)
+	// Storage: DappsStaking CurrentEra (r:1 w:0)
+	// Storage: BlockReward RewardDistributionConfigStorage (r:1 w:1)
+	// Storage: BlockReward InflationModeStorage (r:1 w:0)
+	// Storage: BlockReward NextRecalculation (r:1 w:1)
+	// Storage: Balances TotalIssuance (r:1 w:1)
+	// Storage: BlockReward AnnualIssuance (r:0 w:1)
+	// Storage: BlockReward LastTimestamp (r:1 w:1)
+	// Storage: DappsStaking GeneralEraInfo (r:1 w:0)
+	// Storage: System Account (r:17 w:17)
+	fn on_timestamp_set() -> Weight {
+		Weight::from_ref_time(97_415_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(25 as u64))
+			.saturating_add(RocksDbWeight::get().writes(23 as u64))
+	}
 }
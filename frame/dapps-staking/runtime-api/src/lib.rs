@@ -0,0 +1,45 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: PolyForm-Noncommercial-1.0.0
+
+// You should have received a copy of the PolyForm-Noncommercial license with this crate.
+// If not, see <https://polyformproject.org/licenses/noncommercial/1.0.0//>.
+
+//! Runtime API definition for the dapps staking pallet.
+//!
+//! Rewards are calculated by the pallet itself, the same way as when they are claimed,
+//! so clients don't need to replicate the reward calculation.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// These clippy lints are disabled because the macro-generated code triggers them.
+#![allow(clippy::unnecessary_mut_passed)]
+#![allow(clippy::too_many_arguments)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+/// Counter for the number of eras that have passed.
+pub type EraIndex = u32;
+
+sp_api::decl_runtime_apis! {
+    /// API for querying claimable rewards and unbonding state of dapps staking.
+    pub trait DappsStakingApi<AccountId, SmartContract, Balance>
+    where
+        AccountId: Codec,
+        SmartContract: Codec,
+        Balance: Codec,
+    {
+        /// Returns `(era, reward)` pairs for all eras in which `staker` can claim a reward for staking on `contract_id`.
+        /// Pairs are sorted by era, starting with the oldest one.
+        fn staker_claimable_rewards(staker: AccountId, contract_id: SmartContract) -> Vec<(EraIndex, Balance)>;
+
+        /// Returns `(era, reward)` pairs for all eras in which developer of `contract_id` can claim the dApp reward.
+        /// Pairs are sorted by era, starting with the oldest one.
+        fn dapp_claimable_rewards(contract_id: SmartContract) -> Vec<(EraIndex, Balance)>;
+
+        /// Returns `(unlock era, amount)` pairs of the `staker` unlocking chunks.
+        /// Pairs are sorted by unlock era, starting with the earliest one.
+        fn unlocking_chunks(staker: AccountId) -> Vec<(EraIndex, Balance)>;
+    }
+}
@@ -31,7 +31,19 @@ fn initialize<T: Config>() {
     let _ = RegisteredDapps::<T>::clear(u32::MAX, None);
     let _ = GeneralEraInfo::<T>::clear(u32::MAX, None);
     let _ = ContractEraStake::<T>::clear(u32::MAX, None);
+    let _ = ContractStakeHistory::<T>::clear(u32::MAX, None);
     let _ = GeneralStakerInfo::<T>::clear(u32::MAX, None);
+    let _ = DappCommission::<T>::clear(u32::MAX, None);
+    let _ = DappCommissionHistory::<T>::clear(u32::MAX, None);
+    let _ = StakeLocks::<T>::clear(u32::MAX, None);
+    let _ = BoostExpiries::<T>::clear(u32::MAX, None);
+    let _ = FrozenDapps::<T>::clear(u32::MAX, None);
+    let _ = SlashedDeposits::<T>::clear(u32::MAX, None);
+    let _ = UnregisteredPeriods::<T>::clear(u32::MAX, None);
+    let _ = DappMetadata::<T>::clear(u32::MAX, None);
+    let _ = Operators::<T>::clear(u32::MAX, None);
+    StakingParameters::<T>::kill();
+    PendingStakingParameters::<T>::kill();
     CurrentEra::<T>::kill();
     BlockRewardAccumulator::<T>::kill();
 
@@ -85,15 +97,64 @@ fn register_contract<T: Config>(
     let developer: T::AccountId = account("developer", index.into(), SEED);
     let smart_contract = smart_contract::<T>(index);
     T::Currency::make_free_balance_be(&developer, BalanceOf::<T>::max_value());
-    DappsStaking::<T>::register(
-        RawOrigin::Root.into(),
-        developer.clone(),
+    DappsStaking::<T>::self_register(
+        RawOrigin::Signed(developer.clone()).into(),
         smart_contract.clone(),
     )?;
 
     Ok((developer, smart_contract))
 }
 
+/// Used to set a non-zero commission for the given contract.
+///
+/// Era is advanced so the commission applies to the current era.
+fn prepare_dapp_commission<T: Config>(
+    developer: T::AccountId,
+    contract_id: &T::SmartContract,
+) -> Result<(), &'static str> {
+    DappsStaking::<T>::set_dapp_commission(
+        RawOrigin::Signed(developer).into(),
+        contract_id.clone(),
+        Perbill::from_percent(50),
+    )?;
+    advance_to_era::<T>(DappsStaking::<T>::current_era() + 1);
+
+    Ok(())
+}
+
+/// Used to create dApp metadata of the given size, filling the text fields one after another.
+fn dapp_metadata<T: Config>(byte_size: u32) -> DAppMetadataOf<T> {
+    let max_len = T::MaxMetadataFieldLength::get();
+    let mut remaining = byte_size;
+    let mut field = || {
+        let len = remaining.min(max_len);
+        remaining -= len;
+        BoundedVec::try_from(vec![b'x'; len as usize]).expect("Length is within bounds.")
+    };
+
+    DAppMetadata {
+        name: field(),
+        description: field(),
+        website: field(),
+        logo_uri: field(),
+        category: DAppCategory::DeFi,
+    }
+}
+
+/// Used to set metadata of the maximum size for the given contract.
+fn prepare_dapp_metadata<T: Config>(
+    developer: T::AccountId,
+    contract_id: &T::SmartContract,
+) -> Result<(), &'static str> {
+    DappsStaking::<T>::set_dapp_metadata(
+        RawOrigin::Signed(developer).into(),
+        contract_id.clone(),
+        dapp_metadata::<T>(4 * T::MaxMetadataFieldLength::get()),
+    )?;
+
+    Ok(())
+}
+
 /// Used to bond_and_stake the given contract with the specified amount of stakers.
 /// Method will create new staker accounts using the provided seed.
 ///
@@ -103,7 +164,7 @@ fn prepare_bond_and_stake<T: Config>(
     contract_id: &T::SmartContract,
     seed: u32,
 ) -> Result<Vec<T::AccountId>, &'static str> {
-    let stake_balance = T::MinimumStakingAmount::get(); // maybe make this an argument?
+    let stake_balance = DappsStaking::<T>::staking_parameters().minimum_staking_amount; // maybe make this an argument?
     let mut stakers = Vec::new();
 
     for id in 0..number_of_stakers {
@@ -128,7 +189,8 @@ benchmarks! {
         let developer_id = whitelisted_caller();
         let contract_id = T::SmartContract::default();
         T::Currency::make_free_balance_be(&developer_id, BalanceOf::<T>::max_value());
-    }: _(RawOrigin::Root, developer_id.clone(), contract_id.clone())
+        let origin = T::RegisterOrigin::successful_origin();
+    }: _<T::RuntimeOrigin>(origin, developer_id.clone(), contract_id.clone())
     verify {
         assert_last_event::<T>(Event::<T>::NewContract(developer_id, contract_id).into());
     }
@@ -137,8 +199,9 @@ benchmarks! {
         initialize::<T>();
         let (developer_id, contract_id) = register_contract::<T>(1)?;
         prepare_bond_and_stake::<T>(2, &contract_id, SEED)?;
-
-    }: _(RawOrigin::Root, contract_id.clone())
+        prepare_dapp_metadata::<T>(developer_id.clone(), &contract_id)?;
+        let origin = T::UnregisterOrigin::successful_origin();
+    }: _<T::RuntimeOrigin>(origin, contract_id.clone())
     verify {
         assert_last_event::<T>(Event::<T>::ContractRemoved(developer_id, contract_id).into());
     }
@@ -151,7 +214,7 @@ benchmarks! {
         let stake_amount = BalanceOf::<T>::max_value() / 2u32.into();
 
         DappsStaking::<T>::bond_and_stake(RawOrigin::Signed(staker.clone()).into(), contract_id.clone(), stake_amount)?;
-        DappsStaking::<T>::unregister(RawOrigin::Root.into(), contract_id.clone())?;
+        DappsStaking::<T>::self_unregister(RawOrigin::Signed(developer).into(), contract_id.clone())?;
     }: _(RawOrigin::Signed(staker.clone()), contract_id.clone())
     verify {
         let staker_info = DappsStaking::<T>::staker_info(&staker, &contract_id);
@@ -162,7 +225,7 @@ benchmarks! {
         initialize::<T>();
 
         let (_, contract_id) = register_contract::<T>(1)?;
-        prepare_bond_and_stake::<T>(T::MaxNumberOfStakersPerContract::get() - 1, &contract_id, SEED)?;
+        prepare_bond_and_stake::<T>(DappsStaking::<T>::staking_parameters().max_number_of_stakers_per_contract - 1, &contract_id, SEED)?;
 
         let staker = whitelisted_caller();
         let _ = T::Currency::make_free_balance_be(&staker, BalanceOf::<T>::max_value());
@@ -177,7 +240,7 @@ benchmarks! {
         initialize::<T>();
 
         let (_, contract_id) = register_contract::<T>(1)?;
-        prepare_bond_and_stake::<T>(T::MaxNumberOfStakersPerContract::get() - 1, &contract_id, SEED)?;
+        prepare_bond_and_stake::<T>(DappsStaking::<T>::staking_parameters().max_number_of_stakers_per_contract - 1, &contract_id, SEED)?;
 
         let staker = whitelisted_caller();
         let _ = T::Currency::make_free_balance_be(&staker, BalanceOf::<T>::max_value());
@@ -194,7 +257,7 @@ benchmarks! {
         initialize::<T>();
 
         let (_, contract_id) = register_contract::<T>(1)?;
-        prepare_bond_and_stake::<T>(T::MaxNumberOfStakersPerContract::get() - 1, &contract_id, SEED)?;
+        prepare_bond_and_stake::<T>(DappsStaking::<T>::staking_parameters().max_number_of_stakers_per_contract - 1, &contract_id, SEED)?;
 
         let staker = whitelisted_caller();
         let _ = T::Currency::make_free_balance_be(&staker, BalanceOf::<T>::max_value());
@@ -205,7 +268,7 @@ benchmarks! {
         DappsStaking::<T>::unbond_and_unstake(RawOrigin::Signed(staker.clone()).into(), contract_id, unstake_amount)?;
 
         let current_era = DappsStaking::<T>::current_era();
-        advance_to_era::<T>(current_era + 1 + T::UnbondingPeriod::get());
+        advance_to_era::<T>(current_era + 1 + DappsStaking::<T>::staking_parameters().unbonding_period);
 
     }: _(RawOrigin::Signed(staker.clone()))
     verify {
@@ -220,14 +283,44 @@ benchmarks! {
 
         let staker = prepare_bond_and_stake::<T>(1, &origin_contract_id, SEED)?[0].clone();
 
-    }: _(RawOrigin::Signed(staker.clone()), origin_contract_id.clone(), T::MinimumStakingAmount::get(), target_contract_id.clone())
+    }: _(RawOrigin::Signed(staker.clone()), origin_contract_id.clone(), DappsStaking::<T>::staking_parameters().minimum_staking_amount, target_contract_id.clone())
     verify {
-        assert_last_event::<T>(Event::<T>::NominationTransfer(staker, origin_contract_id, T::MinimumStakingAmount::get(), target_contract_id).into());
+        assert_last_event::<T>(Event::<T>::NominationTransfer(staker, origin_contract_id, DappsStaking::<T>::staking_parameters().minimum_staking_amount, target_contract_id).into());
+    }
+
+    batch_nomination_transfer {
+        let n in 1 .. T::MaxBatchNominationTransfers::get();
+
+        initialize::<T>();
+
+        let (_, origin_contract_id) = register_contract::<T>(1)?;
+        let minimum_staking_amount = DappsStaking::<T>::staking_parameters().minimum_staking_amount;
+
+        let staker: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&staker, BalanceOf::<T>::max_value());
+        DappsStaking::<T>::bond_and_stake(
+            RawOrigin::Signed(staker.clone()).into(),
+            origin_contract_id.clone(),
+            minimum_staking_amount * (n + 1).into(),
+        )?;
+
+        let mut transfers = Vec::new();
+        for index in 0..n {
+            let (_, target_contract_id) = register_contract::<T>((index + 2) as u8)?;
+            transfers.push((origin_contract_id.clone(), target_contract_id, minimum_staking_amount));
+        }
+        let (_, last_target_contract_id, _) = transfers[n as usize - 1].clone();
+        let transfers = BoundedVec::try_from(transfers).expect("Number of transfers is within bounds.");
+
+    }: _(RawOrigin::Signed(staker.clone()), transfers)
+    verify {
+        assert_last_event::<T>(Event::<T>::NominationTransfer(staker, origin_contract_id, minimum_staking_amount, last_target_contract_id).into());
     }
 
     claim_staker_with_restake {
         initialize::<T>();
-        let (_, contract_id) = register_contract::<T>(1)?;
+        let (developer, contract_id) = register_contract::<T>(1)?;
+        prepare_dapp_commission::<T>(developer, &contract_id)?;
 
         let number_of_stakers = 3;
         let claim_era = DappsStaking::<T>::current_era();
@@ -246,7 +339,8 @@ benchmarks! {
 
     claim_staker_without_restake {
         initialize::<T>();
-        let (_, contract_id) = register_contract::<T>(1)?;
+        let (developer, contract_id) = register_contract::<T>(1)?;
+        prepare_dapp_commission::<T>(developer, &contract_id)?;
 
         let number_of_stakers = 3;
         let claim_era = DappsStaking::<T>::current_era();
@@ -266,6 +360,7 @@ benchmarks! {
     claim_dapp {
         initialize::<T>();
         let (developer, contract_id) = register_contract::<T>(1)?;
+        prepare_dapp_commission::<T>(developer.clone(), &contract_id)?;
 
         let number_of_stakers = 3;
         let claim_era = DappsStaking::<T>::current_era();
@@ -298,6 +393,231 @@ benchmarks! {
         assert_last_event::<T>(Event::<T>::RewardDestination(staker, option).into());
     }
 
+    claim_staker_batch {
+        let n in 1 .. T::MaxBatchClaimEras::get();
+
+        initialize::<T>();
+        let (developer, contract_id) = register_contract::<T>(1)?;
+        prepare_dapp_commission::<T>(developer, &contract_id)?;
+
+        let number_of_stakers = 3;
+        let claim_era = DappsStaking::<T>::current_era();
+        let stakers = prepare_bond_and_stake::<T>(number_of_stakers, &contract_id, SEED)?;
+        let staker = stakers[0].clone();
+
+        DappsStaking::<T>::set_reward_destination(RawOrigin::Signed(staker.clone()).into(), RewardDestination::StakeBalance)?;
+        advance_to_era::<T>(claim_era + n);
+
+    }: _(RawOrigin::Signed(staker.clone()), contract_id.clone(), n)
+    verify {
+        let mut staker_info = DappsStaking::<T>::staker_info(&staker, &contract_id);
+        let (era, _) = staker_info.claim();
+        assert!(era >= claim_era + n);
+    }
+
+    propose_ownership_transfer {
+        initialize::<T>();
+        let (developer, contract_id) = register_contract::<T>(1)?;
+        let new_developer: T::AccountId = account("new_developer", 1, SEED);
+
+    }: _(RawOrigin::Signed(developer.clone()), contract_id.clone(), new_developer.clone())
+    verify {
+        assert_last_event::<T>(Event::<T>::OwnershipTransferProposed(developer, contract_id, new_developer).into());
+    }
+
+    accept_ownership_transfer {
+        initialize::<T>();
+        let (developer, contract_id) = register_contract::<T>(1)?;
+        let new_developer: T::AccountId = account("new_developer", 1, SEED);
+        T::Currency::make_free_balance_be(&new_developer, BalanceOf::<T>::max_value() / 2u32.into());
+        prepare_dapp_metadata::<T>(developer.clone(), &contract_id)?;
+
+        DappsStaking::<T>::propose_ownership_transfer(RawOrigin::Signed(developer.clone()).into(), contract_id.clone(), new_developer.clone())?;
+    }: _(RawOrigin::Signed(new_developer.clone()), contract_id.clone())
+    verify {
+        assert_last_event::<T>(Event::<T>::OwnershipTransferred(developer, contract_id, new_developer).into());
+    }
+
+    rebond {
+        initialize::<T>();
+
+        let (_, contract_id) = register_contract::<T>(1)?;
+        prepare_bond_and_stake::<T>(DappsStaking::<T>::staking_parameters().max_number_of_stakers_per_contract - 1, &contract_id, SEED)?;
+
+        let staker = whitelisted_caller();
+        let _ = T::Currency::make_free_balance_be(&staker, BalanceOf::<T>::max_value());
+        let amount = BalanceOf::<T>::max_value() / 2u32.into();
+        DappsStaking::<T>::bond_and_stake(RawOrigin::Signed(staker.clone()).into(), contract_id.clone(), amount)?;
+
+        // Each unbonding era creates a new unlocking chunk, all of them need to be rebonded.
+        // Enough space must remain in staker info for the rebond era.
+        let number_of_chunks = T::MaxUnlockingChunks::get().min(T::MaxEraStakeValues::get().saturating_sub(2));
+        let unbond_amount = DappsStaking::<T>::staking_parameters().minimum_staking_amount;
+        for _ in 0..number_of_chunks {
+            DappsStaking::<T>::unbond_and_unstake(RawOrigin::Signed(staker.clone()).into(), contract_id.clone(), unbond_amount)?;
+            advance_to_era::<T>(DappsStaking::<T>::current_era() + 1u32);
+        }
+        let rebond_amount = unbond_amount * number_of_chunks.into();
+
+    }: _(RawOrigin::Signed(staker.clone()), contract_id.clone(), rebond_amount)
+    verify {
+        assert_last_event::<T>(Event::<T>::Rebonded(staker.clone(), contract_id, rebond_amount).into());
+        assert!(DappsStaking::<T>::ledger(&staker).unbonding_info.is_empty());
+    }
+
+    set_staking_parameters {
+        let mut parameters = DappsStaking::<T>::staking_parameters();
+        parameters.unbonding_period += 1;
+
+    }: _(RawOrigin::Root, parameters.clone())
+    verify {
+        assert_last_event::<T>(Event::<T>::StakingParametersScheduled(parameters).into());
+    }
+
+    set_dapp_commission {
+        initialize::<T>();
+        let (developer, contract_id) = register_contract::<T>(1)?;
+
+        // Previous commission is moved to history
+        prepare_dapp_commission::<T>(developer.clone(), &contract_id)?;
+        let commission = Perbill::from_percent(20);
+        let since_era = DappsStaking::<T>::current_era() + 1;
+
+    }: _(RawOrigin::Signed(developer.clone()), contract_id.clone(), commission)
+    verify {
+        assert_last_event::<T>(Event::<T>::DappCommissionChanged(developer, contract_id, commission, since_era).into());
+    }
+
+    instant_unbond_and_unstake {
+        initialize::<T>();
+
+        let (_, contract_id) = register_contract::<T>(1)?;
+        prepare_bond_and_stake::<T>(DappsStaking::<T>::staking_parameters().max_number_of_stakers_per_contract - 1, &contract_id, SEED)?;
+
+        let staker = whitelisted_caller();
+        let _ = T::Currency::make_free_balance_be(&staker, BalanceOf::<T>::max_value());
+        let amount = BalanceOf::<T>::max_value() / 2u32.into();
+        let penalty = DappsStaking::<T>::staking_parameters().instant_unbond_penalty * amount;
+
+        DappsStaking::<T>::bond_and_stake(RawOrigin::Signed(staker.clone()).into(), contract_id.clone(), amount)?;
+
+    }: _(RawOrigin::Signed(staker.clone()), contract_id.clone(), amount)
+    verify {
+        assert_last_event::<T>(Event::<T>::InstantUnbondAndUnstake(staker, contract_id, amount, penalty).into());
+    }
+
+    instant_withdraw_unbonded {
+        initialize::<T>();
+
+        let (_, contract_id) = register_contract::<T>(1)?;
+        prepare_bond_and_stake::<T>(DappsStaking::<T>::staking_parameters().max_number_of_stakers_per_contract - 1, &contract_id, SEED)?;
+
+        let staker = whitelisted_caller();
+        let _ = T::Currency::make_free_balance_be(&staker, BalanceOf::<T>::max_value());
+        let stake_amount = BalanceOf::<T>::max_value() / 2u32.into();
+        let unstake_amount = stake_amount / 2u32.into();
+        let penalty = DappsStaking::<T>::staking_parameters().instant_unbond_penalty * unstake_amount;
+
+        DappsStaking::<T>::bond_and_stake(RawOrigin::Signed(staker.clone()).into(), contract_id.clone(), stake_amount)?;
+        DappsStaking::<T>::unbond_and_unstake(RawOrigin::Signed(staker.clone()).into(), contract_id, unstake_amount)?;
+
+    }: _(RawOrigin::Signed(staker.clone()))
+    verify {
+        assert_last_event::<T>(Event::<T>::InstantWithdrawn(staker, unstake_amount, penalty).into());
+    }
+
+    bond_and_stake_with_lock {
+        initialize::<T>();
+
+        let (_, contract_id) = register_contract::<T>(1)?;
+        prepare_bond_and_stake::<T>(DappsStaking::<T>::staking_parameters().max_number_of_stakers_per_contract - 1, &contract_id, SEED)?;
+
+        let staker = whitelisted_caller();
+        let _ = T::Currency::make_free_balance_be(&staker, BalanceOf::<T>::max_value());
+        let amount = BalanceOf::<T>::max_value() / 2u32.into();
+        let (lock_eras, _) = T::StakeLockOptions::get().into_iter().max_by_key(|(eras, _)| *eras).ok_or("No stake lock options configured.")?;
+        let unlock_era = DappsStaking::<T>::current_era() + lock_eras;
+
+    }: _(RawOrigin::Signed(staker.clone()), contract_id.clone(), amount, lock_eras)
+    verify {
+        assert_last_event::<T>(Event::<T>::BondAndStakeWithLock(staker, contract_id, amount, unlock_era).into());
+    }
+
+    slash_dapp {
+        let n in 0 .. T::MaxSlashEras::get();
+
+        initialize::<T>();
+        let (developer, contract_id) = register_contract::<T>(1)?;
+        prepare_dapp_commission::<T>(developer, &contract_id)?;
+
+        let number_of_stakers = 3;
+        let from_era = DappsStaking::<T>::current_era();
+        prepare_bond_and_stake::<T>(number_of_stakers, &contract_id, SEED)?;
+        advance_to_era::<T>(from_era + n);
+
+        let origin = T::SlashOrigin::successful_origin();
+    }: _<T::RuntimeOrigin>(origin, contract_id.clone(), from_era, n, Perbill::from_percent(50), true, true)
+    verify {
+        assert_last_event::<T>(Event::<T>::DappFrozen(contract_id.clone()).into());
+        assert!(DappsStaking::<T>::frozen_dapps(&contract_id).is_some());
+    }
+
+    reregister {
+        initialize::<T>();
+        let (developer_id, contract_id) = register_contract::<T>(1)?;
+        prepare_bond_and_stake::<T>(2, &contract_id, SEED)?;
+        DappsStaking::<T>::self_unregister(RawOrigin::Signed(developer_id.clone()).into(), contract_id.clone())?;
+        advance_to_era::<T>(DappsStaking::<T>::current_era() + 1u32);
+        // Worst case, stake history has to be repaired
+        ContractStakeHistory::<T>::remove(&contract_id);
+
+        let origin = T::RegisterOrigin::successful_origin();
+    }: _<T::RuntimeOrigin>(origin, contract_id.clone())
+    verify {
+        assert_last_event::<T>(Event::<T>::ContractReregistered(developer_id, contract_id).into());
+    }
+
+    set_dapp_metadata {
+        let n in 0 .. 4 * T::MaxMetadataFieldLength::get();
+
+        initialize::<T>();
+        let (developer, contract_id) = register_contract::<T>(1)?;
+        let metadata = dapp_metadata::<T>(n);
+    }: _(RawOrigin::Signed(developer.clone()), contract_id.clone(), metadata.clone())
+    verify {
+        assert_eq!(DappsStaking::<T>::dapp_metadata(&contract_id).map(|(metadata, _)| metadata), Some(metadata));
+    }
+
+    clear_dapp_metadata {
+        initialize::<T>();
+        let (developer, contract_id) = register_contract::<T>(1)?;
+        prepare_dapp_metadata::<T>(developer, &contract_id)?;
+
+        let origin = T::MetadataOrigin::successful_origin();
+    }: _<T::RuntimeOrigin>(origin, contract_id.clone())
+    verify {
+        assert_last_event::<T>(Event::<T>::DappMetadataCleared(contract_id).into());
+    }
+
+    set_operator {
+        initialize::<T>();
+        let staker: T::AccountId = whitelisted_caller();
+        let operator: T::AccountId = account("operator", 1, SEED);
+    }: _(RawOrigin::Signed(staker.clone()), operator.clone())
+    verify {
+        assert_last_event::<T>(Event::<T>::OperatorSet(staker, operator).into());
+    }
+
+    remove_operator {
+        initialize::<T>();
+        let staker: T::AccountId = whitelisted_caller();
+        let operator: T::AccountId = account("operator", 1, SEED);
+        DappsStaking::<T>::set_operator(RawOrigin::Signed(staker.clone()).into(), operator.clone())?;
+    }: _(RawOrigin::Signed(staker.clone()))
+    verify {
+        assert_last_event::<T>(Event::<T>::OperatorRemoved(staker, operator).into());
+    }
+
 }
 
 #[cfg(test)]
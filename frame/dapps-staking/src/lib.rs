@@ -19,7 +19,9 @@
 //! Stakers are also rewarded, based on the total amount they've locked (invariant of the dapp they staked on).
 //!
 //! Rewards are accumulated throughout an **era** and when **era** finishes, both stakers and developers can claim their rewards for that era.
-//! This is a continous process. Rewards can be claimed even for eras which are older than the last one (no limit at the moment).
+//! This is a continous process. Rewards can be claimed even for eras which are older than the last one.
+//! Staker rewards can be claimed for the last `StakerClaimWindow` eras, older staker rewards expire and are swept
+//! to the `ExpiredStakerRewards` handler. There is no limit for the dapp rewards at the moment.
 //!
 //! Reward claiming isn't automated since the whole process is done **on-chain** and is fully decentralized.
 //! Both stakers and developers are responsible for claiming their own rewards.
@@ -31,18 +33,40 @@
 //!
 //! - `register` - used to register a new contract for dapps staking
 //! - `unregister` - used to unregister contract from dapps staking, making it ineligible for receiveing future rewards
+//! - `self_register` - used by a developer to register own contract for dapps staking
+//! - `self_unregister` - used by a developer to unregister own contract from dapps staking
+//! - `propose_ownership_transfer` - used by a developer to propose transferring contract ownership to another account
+//! - `accept_ownership_transfer` - used by the proposed account to accept contract ownership transfer
 //! - `withdraw_from_unregistered` - used by stakers to withdraw their stake from an unregistered contract (no unbonding period)
+//! - `reregister` - used to register a previously unregistered contract again
 //! - `bond_and_stake` - basic call for nominating a dapp and locking stakers tokens into dapps staking
+//! - `bond_and_stake_with_lock` - same as `bond_and_stake` but the stake is locked on the dapp for some eras, in exchange for a reward boost
 //! - `unbond_and_unstake` - removes nomination from the contract, starting the unbonding process for the unstaked funds
 //! - `withdraw_unbonded` - withdraws all funds that have completed the unbonding period
+//! - `instant_unbond_and_unstake` - unstakes funds from the contract and unlocks them immediately, for a penalty
+//! - `instant_withdraw_unbonded` - withdraws all funds undergoing the unbonding process immediately, for a penalty
+//! - `rebond` - moves funds from the unlocking chunks back into stake on a contract, without waiting for the unbonding period
 //! - `nomination_transfer` - transfer nomination from one contract to another contract (avoids unbonding period)
+//! - `batch_nomination_transfer` - multiple nomination transfers between contracts in a single, atomic call
 //! - `claim_staker` - claims staker reward for a single era
+//! - `claim_staker_batch` - claims staker rewards for multiple eras in a single call
+//! - `claim_staker_for` - claims staker rewards on behalf of another account, callable by anyone
 //! - `claim_dapp` - claims dapp rewards for the specified era
 //! - `force_new_era` - forces new era on the start of the next block
 //! - `maintenance_mode` - enables or disables pallet maintenance mode
 //! - `set_reward_destination` - sets reward destination for the staker rewards
 //! - `set_contract_stake_info` - root-only call to set storage value (used for fixing corrupted data)
 //! - `burn_stale_reward` - root-only call to burn unclaimed, stale rewards from unregistered contracts
+//! - `migrate` - continues the multi-block storage migration, callable by anyone
+//! - `set_dapp_commission` - used by a developer to share part of the dapp reward with the contract stakers
+//! - `set_staking_parameters` - root-only call to change staking parameters, applied from the next era
+//! - `slash_dapp` - used by governance to forfeit dapp rewards, slash the register deposit or freeze staking on a misbehaving dapp
+//! - `set_dapp_metadata` - used by a developer to publish dapp metadata (name, description, website, logo, category), for a deposit
+//! - `clear_dapp_metadata` - used by governance to remove abusive dapp metadata
+//! - `set_operator` - used by a staker to grant an operator account the right to manage its stake, without withdrawing it
+//! - `remove_operator` - used by a staker to revoke the operator role
+//! - `operator_nomination_transfer` - same as `nomination_transfer`, called by the operator on behalf of the staker
+//! - `operator_set_reward_destination` - same as `set_reward_destination`, called by the operator on behalf of the staker
 //!
 //! User is encouraged to refer to specific function implementations for more comprehensive documentation.
 //!
@@ -53,19 +77,30 @@
 //! - `ensure_pallet_enabled` - checks whether pallet is in maintenance mode or not and returns appropriate `Result`
 //! - `rewards` - used to deposit staker and dapps rewards into dApps staking reward pool
 //! - `tvl` - total value locked in dApps staking (might differ from total staked value)
+//! - `contract_stake_info` - returns contract staking info for the specified era, derived from the last era in which it changed
+//! - `staker_claimable_rewards` - returns claimable staker rewards per era, used by the runtime API
+//! - `dapp_claimable_rewards` - returns claimable dApp rewards per era, used by the runtime API
+//! - `dapp_commission_at` - returns dApp commission which applies to the specified era
+//! - `unlocking_chunks` - returns unlock eras and amounts of staker's unlocking chunks, used by the runtime API
+//! - `on_idle` - part of `Hooks` trait, prunes era data older than `EraHistoryDepth` once rewards can no longer be claimed for it
+//! - `do_try_state` - checks storage invariants, called from the `try_state` hook of `Hooks` trait
 //!
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::{Decode, Encode, HasCompact, MaxEncodedLen};
-use frame_support::traits::Currency;
+use frame_support::{
+    traits::{Currency, Get},
+    BoundedVec, CloneNoBound, DefaultNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
 use frame_system::{self as system};
 use scale_info::TypeInfo;
 use sp_runtime::{
     traits::{AtLeast32BitUnsigned, Zero},
-    RuntimeDebug,
+    Perbill, RuntimeDebug,
 };
-use sp_std::{ops::Add, prelude::*};
+use sp_std::{fmt::Debug, ops::Add, prelude::*};
 
+pub mod migrations;
 pub mod pallet;
 pub mod weights;
 
@@ -89,24 +124,6 @@ pub type BalanceOf<T> =
 /// Counter for the number of eras that have passed.
 pub type EraIndex = u32;
 
-// This represents the max assumed vector length that any storage item should have.
-// In particular, this relates to `UnbondingInfo` and `StakerInfo`.
-// In structs which are bound in size, `MaxEncodedLen` can just be derived but that's not the case for standard `vec`.
-// To fix this 100% correctly, we'd need to do one of the following:
-//
-// - Use `BoundedVec` instead of `Vec` and do storage migration
-// - Introduce a new type `S: Get<u32>` into the aforementioned structs and use it to inject max allowed size,
-//   thus allowing us to correctly calculate max encoded len
-//
-// The issue with first approach is that it requires storage migration which we want to avoid
-// unless it's really necessary. The issue with second approach is that it makes code much more
-// difficult to work with since all of it will be ridden with injections of the `S` type.
-//
-// Since dApps staking has been stable for long time and there are plans to redesign & refactor it,
-// doing neither of the above makes sense, timewise. So we use an assumption that vec length
-// won't go over the following constant.
-const MAX_ASSUMED_VEC_LEN: u32 = 10;
-
 /// DApp State descriptor
 #[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 enum DAppState {
@@ -140,6 +157,107 @@ impl<AccountId> DAppInfo<AccountId> {
     }
 }
 
+/// Part of the developer reward which is shared with the contract stakers.
+#[derive(
+    Copy, Clone, PartialEq, Eq, Encode, Decode, Default, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub struct DAppCommission {
+    /// Fraction of the developer reward distributed to the stakers, pro-rata to their stake
+    pub commission: Perbill,
+    /// Era from which the commission applies
+    #[codec(compact)]
+    pub since_era: EraIndex,
+}
+
+/// Category under which the dApp is listed.
+#[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum DAppCategory {
+    /// dApp doesn't fit into any of the other categories
+    Others,
+    /// Decentralized finance
+    DeFi,
+    /// Non-fungible tokens & marketplaces
+    NFT,
+    /// Games & metaverse
+    Gaming,
+    /// Developer tooling & infrastructure
+    Tooling,
+    /// Utility dApps (bridges, wallets, DAOs, ...)
+    Utility,
+}
+
+impl Default for DAppCategory {
+    fn default() -> Self {
+        DAppCategory::Others
+    }
+}
+
+impl TryFrom<u8> for DAppCategory {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(DAppCategory::Others),
+            1 => Ok(DAppCategory::DeFi),
+            2 => Ok(DAppCategory::NFT),
+            3 => Ok(DAppCategory::Gaming),
+            4 => Ok(DAppCategory::Tooling),
+            5 => Ok(DAppCategory::Utility),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Descriptive, developer-provided information about the dApp, used by explorers & staking portals.
+///
+/// Each of the text fields is limited to `MaxLen` bytes.
+#[derive(
+    Encode,
+    Decode,
+    CloneNoBound,
+    DefaultNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    RuntimeDebugNoBound,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(MaxLen))]
+#[codec(mel_bound(MaxLen: Get<u32>))]
+pub struct DAppMetadata<MaxLen: Get<u32>> {
+    /// Name of the dApp
+    pub name: BoundedVec<u8, MaxLen>,
+    /// Short description of the dApp
+    pub description: BoundedVec<u8, MaxLen>,
+    /// dApp website URL
+    pub website: BoundedVec<u8, MaxLen>,
+    /// URI of the dApp logo
+    pub logo_uri: BoundedVec<u8, MaxLen>,
+    /// Category under which the dApp is listed
+    pub category: DAppCategory,
+}
+
+impl<MaxLen: Get<u32>> DAppMetadata<MaxLen> {
+    /// Total number of bytes used by the text fields, used to calculate the metadata deposit.
+    ///
+    /// ```
+    /// # use pallet_dapps_staking::DAppMetadata;
+    /// # use frame_support::traits::ConstU32;
+    /// let metadata = DAppMetadata::<ConstU32<16>> {
+    ///     name: b"Astar".to_vec().try_into().unwrap(),
+    ///     website: b"astar.network".to_vec().try_into().unwrap(),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(metadata.byte_size(), 18);
+    /// ```
+    pub fn byte_size(&self) -> u32 {
+        [&self.name, &self.description, &self.website, &self.logo_uri]
+            .iter()
+            .map(|field| field.len() as u32)
+            .sum()
+    }
+}
+
 /// Mode of era-forcing.
 #[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
@@ -180,6 +298,108 @@ pub struct EraInfo<Balance: HasCompact + MaxEncodedLen> {
     /// Total locked amount in an era
     #[codec(compact)]
     pub locked: Balance,
+    /// Total additional reward weight of the time-locked stakes in an era
+    #[codec(compact)]
+    pub boost: Balance,
+}
+
+/// Staking parameters which can be changed on-chain, without a runtime upgrade.
+/// Values from the pallet config are used until parameters are changed for the first time.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct StakingParametersInfo<BlockNumber, Balance> {
+    /// Number of blocks per era.
+    pub block_per_era: BlockNumber,
+    /// Minimum amount user must have staked on contract.
+    pub minimum_staking_amount: Balance,
+    /// Number of eras that need to pass until unstaked value can be withdrawn.
+    pub unbonding_period: EraIndex,
+    /// Maximum number of unique stakers per contract.
+    pub max_number_of_stakers_per_contract: u32,
+    /// Number of eras that need to pass until dApp rewards for the unregistered contracts can be burned.
+    pub unregistered_dapp_reward_retention: u32,
+    /// Part of the value which is taken as a penalty when it's unbonded instantly.
+    pub instant_unbond_penalty: Perbill,
+}
+
+impl<BlockNumber: Zero, Balance: Zero> StakingParametersInfo<BlockNumber, Balance> {
+    /// `true` if parameters can be used by the pallet, `false` otherwise.
+    ///
+    /// Eras must have at least one block, and both staking amount and number of stakers per contract must be non-zero.
+    pub fn is_valid(&self) -> bool {
+        !self.block_per_era.is_zero()
+            && !self.minimum_staking_amount.is_zero()
+            && self.max_number_of_stakers_per_contract > 0
+    }
+}
+
+/// Stage of the era history pruning pass.
+///
+/// Pass is executed in `on_idle`, possibly over many blocks, so each stage keeps track of its progress.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum EraPruningStage<AccountId, SmartContract> {
+    /// Waiting for the next era to start a new pass.
+    Idle,
+    /// Looking for the oldest era in which stakers still have unclaimed rewards.
+    ScanStakers {
+        /// Last processed staker & contract pair.
+        last_key: Option<(AccountId, SmartContract)>,
+        /// Oldest era which mustn't be pruned, found so far.
+        oldest_unprunable_era: EraIndex,
+    },
+    /// Looking for the oldest era in which dApps still have unclaimed rewards.
+    ScanDapps {
+        /// Last processed contract.
+        last_key: Option<SmartContract>,
+        /// Oldest era which mustn't be pruned, found so far.
+        oldest_unprunable_era: EraIndex,
+    },
+    /// Removing `GeneralEraInfo` entries, which makes claiming rewards for pruned eras impossible.
+    PruneEras {
+        /// Next era for which era info is removed.
+        next_era: EraIndex,
+        /// Era data older than this era is pruned.
+        prune_before: EraIndex,
+        /// Number of removed era info entries.
+        pruned_era_info: u32,
+    },
+    /// Removing `ContractEraStake` entries.
+    PruneContracts {
+        /// Last processed contract.
+        last_key: Option<SmartContract>,
+        /// Era data older than this era is pruned.
+        prune_before: EraIndex,
+        /// Number of removed era info entries.
+        pruned_era_info: u32,
+        /// Number of removed contract stake entries.
+        pruned_contract_stakes: u32,
+    },
+}
+
+impl<AccountId, SmartContract> Default for EraPruningStage<AccountId, SmartContract> {
+    fn default() -> Self {
+        EraPruningStage::Idle
+    }
+}
+
+/// Progress of the era history pruning.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct EraPruningInfo<AccountId, SmartContract> {
+    /// Era data older than this era has been pruned.
+    pub pruned_before: EraIndex,
+    /// Era in which the latest pass was started.
+    pub pass_era: EraIndex,
+    /// Stage of the current pass.
+    pub stage: EraPruningStage<AccountId, SmartContract>,
+}
+
+impl<AccountId, SmartContract> Default for EraPruningInfo<AccountId, SmartContract> {
+    fn default() -> Self {
+        Self {
+            pruned_before: 0,
+            pass_era: 0,
+            stage: EraPruningStage::Idle,
+        }
+    }
 }
 
 /// Used to split total EraPayout among contracts.
@@ -195,6 +415,9 @@ pub struct ContractStakeInfo<Balance: HasCompact + MaxEncodedLen> {
     number_of_stakers: u32,
     /// Indicates whether rewards were claimed for this era or not
     contract_reward_claimed: bool,
+    /// Total additional reward weight of the time-locked stakes on the contract
+    #[codec(compact)]
+    pub boost: Balance,
 }
 
 /// Storage value representing the current Dapps staking pallet storage version.
@@ -205,6 +428,9 @@ pub enum Version {
     V2_0_0,
     V3_0_0,
     V4_0_0,
+    V5_0_0,
+    V6_0_0,
+    V7_0_0,
 }
 
 impl Default for Version {
@@ -259,25 +485,39 @@ impl<Balance: AtLeast32BitUnsigned + Copy + MaxEncodedLen> EraStake<Balance> {
 ///
 /// **NOTE:** It is important to understand that staker **DID NOT** claim any rewards during this period.
 ///
-#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo)]
-pub struct StakerInfo<Balance: AtLeast32BitUnsigned + Copy + MaxEncodedLen> {
-    // Size of this list would be limited by a configurable constant
-    stakes: Vec<EraStake<Balance>>,
+#[derive(
+    Encode,
+    Decode,
+    CloneNoBound,
+    DefaultNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    RuntimeDebugNoBound,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(MaxLen))]
+#[codec(mel_bound(Balance: MaxEncodedLen, MaxLen: Get<u32>))]
+pub struct StakerInfo<
+    Balance: AtLeast32BitUnsigned + Copy + Debug + MaxEncodedLen,
+    MaxLen: Get<u32>,
+> {
+    // Size of this list is limited by `MaxLen`, which is the `MaxEraStakeValues` config constant
+    stakes: BoundedVec<EraStake<Balance>, MaxLen>,
 }
 
-impl<Balance: AtLeast32BitUnsigned + Copy + MaxEncodedLen> MaxEncodedLen for StakerInfo<Balance> {
-    // This is just an assumption, will be calculated properly in the future. See the comment for `MAX_ASSUMED_VEC_LEN`.
-    fn max_encoded_len() -> usize {
-        codec::Compact(MAX_ASSUMED_VEC_LEN)
-            .encoded_size()
-            .saturating_add(
-                (MAX_ASSUMED_VEC_LEN as usize)
-                    .saturating_mul(EraStake::<Balance>::max_encoded_len()),
-            )
-    }
+/// Errors which can occur when modifying `StakerInfo`.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum StakerInfoError {
+    /// Specified era is older than the latest era in the staking info.
+    UnexpectedEra,
+    /// There is no more space for a new `EraStake` entry.
+    TooManyEraStakeValues,
 }
 
-impl<Balance: AtLeast32BitUnsigned + Copy + MaxEncodedLen> StakerInfo<Balance> {
+impl<Balance: AtLeast32BitUnsigned + Copy + Debug + MaxEncodedLen, MaxLen: Get<u32>>
+    StakerInfo<Balance, MaxLen>
+{
     /// `true` if no active stakes and unclaimed eras exist, `false` otherwise
     fn is_empty(&self) -> bool {
         self.stakes.is_empty()
@@ -288,6 +528,11 @@ impl<Balance: AtLeast32BitUnsigned + Copy + MaxEncodedLen> StakerInfo<Balance> {
         self.stakes.len() as u32
     }
 
+    /// Oldest era for which reward hasn't been claimed yet, if any.
+    fn oldest_unclaimed_era(&self) -> Option<EraIndex> {
+        self.stakes.first().map(|era_stake| era_stake.era)
+    }
+
     /// Stakes some value in the specified era.
     ///
     /// User should ensure that given era is either equal or greater than the
@@ -301,10 +546,10 @@ impl<Balance: AtLeast32BitUnsigned + Copy + MaxEncodedLen> StakerInfo<Balance> {
     /// * `stake(7, 100)` will result in `[<5, 1000>, <7, 1400>]`
     /// * `stake(9, 200)` will result in `[<5, 1000>, <7, 1400>, <9, 1600>]`
     ///
-    fn stake(&mut self, current_era: EraIndex, value: Balance) -> Result<(), &str> {
-        if let Some(era_stake) = self.stakes.last_mut() {
+    fn stake(&mut self, current_era: EraIndex, value: Balance) -> Result<(), StakerInfoError> {
+        if let Some(era_stake) = self.stakes.iter_mut().last() {
             if era_stake.era > current_era {
-                return Err("Unexpected era");
+                return Err(StakerInfoError::UnexpectedEra);
             }
 
             let new_stake_value = era_stake.staked.saturating_add(value);
@@ -313,10 +558,13 @@ impl<Balance: AtLeast32BitUnsigned + Copy + MaxEncodedLen> StakerInfo<Balance> {
                 *era_stake = EraStake::new(new_stake_value, current_era)
             } else {
                 self.stakes
-                    .push(EraStake::new(new_stake_value, current_era))
+                    .try_push(EraStake::new(new_stake_value, current_era))
+                    .map_err(|_| StakerInfoError::TooManyEraStakeValues)?
             }
         } else {
-            self.stakes.push(EraStake::new(value, current_era));
+            self.stakes
+                .try_push(EraStake::new(value, current_era))
+                .map_err(|_| StakerInfoError::TooManyEraStakeValues)?
         }
 
         Ok(())
@@ -341,10 +589,10 @@ impl<Balance: AtLeast32BitUnsigned + Copy + MaxEncodedLen> StakerInfo<Balance> {
     ///
     /// Note that if no unclaimed eras remain, vector will be cleared.
     ///
-    fn unstake(&mut self, current_era: EraIndex, value: Balance) -> Result<(), &str> {
-        if let Some(era_stake) = self.stakes.last_mut() {
+    fn unstake(&mut self, current_era: EraIndex, value: Balance) -> Result<(), StakerInfoError> {
+        if let Some(era_stake) = self.stakes.iter_mut().last() {
             if era_stake.era > current_era {
-                return Err("Unexpected era");
+                return Err(StakerInfoError::UnexpectedEra);
             }
 
             let new_stake_value = era_stake.staked.saturating_sub(value);
@@ -352,7 +600,8 @@ impl<Balance: AtLeast32BitUnsigned + Copy + MaxEncodedLen> StakerInfo<Balance> {
                 *era_stake = EraStake::new(new_stake_value, current_era)
             } else {
                 self.stakes
-                    .push(EraStake::new(new_stake_value, current_era))
+                    .try_push(EraStake::new(new_stake_value, current_era))
+                    .map_err(|_| StakerInfoError::TooManyEraStakeValues)?
             }
 
             // Removes unstaked values if they're no longer valid for comprehension
@@ -414,6 +663,30 @@ impl<Balance: AtLeast32BitUnsigned + Copy + MaxEncodedLen> StakerInfo<Balance> {
         }
     }
 
+    /// Skips all unclaimed eras before `era`, as if their rewards were claimed.
+    ///
+    /// # Example
+    ///
+    /// `stakes: [<5, 1000>, <7, 1300>, <8, 0>, <15, 3000>]`
+    /// * `skip_to(6)` will result in `[<6, 1000>, <7, 1300>, <8, 0>, <15, 3000>]`
+    /// * `skip_to(10)` will result in `[<15, 3000>]`
+    /// * `skip_to(20)` will result in `[<20, 3000>]`
+    ///
+    fn skip_to(&mut self, era: EraIndex) {
+        while self.stakes.len() > 1 && self.stakes[1].era <= era {
+            self.stakes.remove(0);
+        }
+
+        if let Some(era_stake) = self.stakes.get_mut(0) {
+            era_stake.era = era_stake.era.max(era);
+        }
+
+        // Removes unstaked values if they're no longer valid for comprehension
+        if !self.stakes.is_empty() && self.stakes[0].staked.is_zero() {
+            self.stakes.remove(0);
+        }
+    }
+
     /// Latest staked value.
     /// E.g. if staker is fully unstaked, this will return `Zero`.
     /// Otherwise returns a non-zero balance.
@@ -422,6 +695,33 @@ impl<Balance: AtLeast32BitUnsigned + Copy + MaxEncodedLen> StakerInfo<Balance> {
     }
 }
 
+/// Part of the stake which can't be unstaked from the contract until `unlock_era`.
+/// In return, staker's reward weight is increased by `boost` for each era in which the stake is locked.
+#[derive(
+    Clone, Copy, PartialEq, Eq, Encode, Decode, Default, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub struct StakeLock<Balance: HasCompact + MaxEncodedLen> {
+    /// Locked amount
+    #[codec(compact)]
+    pub amount: Balance,
+    /// Additional reward weight of the locked amount
+    #[codec(compact)]
+    pub boost: Balance,
+    /// First era in which the stake is locked
+    #[codec(compact)]
+    pub start_era: EraIndex,
+    /// Era in which the stake becomes unlocked and is no longer boosted
+    #[codec(compact)]
+    pub unlock_era: EraIndex,
+}
+
+impl<Balance: HasCompact + MaxEncodedLen> StakeLock<Balance> {
+    /// `true` if stake is locked (and boosted) in the specified era, `false` otherwise.
+    pub fn is_locked_in(&self, era: EraIndex) -> bool {
+        self.start_era <= era && era < self.unlock_era
+    }
+}
+
 /// Represents an balance amount undergoing the unbonding process.
 /// Since unbonding takes time, it's important to keep track of when and how much was unbonded.
 #[derive(
@@ -448,29 +748,30 @@ where
 
 /// Contains unlocking chunks.
 /// This is a convenience struct that provides various utility methods to help with unbonding handling.
-#[derive(Clone, PartialEq, Encode, Decode, Default, RuntimeDebug, TypeInfo)]
-pub struct UnbondingInfo<Balance: AtLeast32BitUnsigned + Default + Copy + MaxEncodedLen> {
+#[derive(
+    CloneNoBound,
+    PartialEqNoBound,
+    Encode,
+    Decode,
+    DefaultNoBound,
+    RuntimeDebugNoBound,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(MaxLen))]
+#[codec(mel_bound(Balance: MaxEncodedLen, MaxLen: Get<u32>))]
+pub struct UnbondingInfo<
+    Balance: AtLeast32BitUnsigned + Default + Copy + Debug + MaxEncodedLen,
+    MaxLen: Get<u32>,
+> {
     // Vector of unlocking chunks. Sorted in ascending order in respect to unlock_era.
-    unlocking_chunks: Vec<UnlockingChunk<Balance>>,
+    unlocking_chunks: BoundedVec<UnlockingChunk<Balance>, MaxLen>,
 }
 
-impl<Balance: AtLeast32BitUnsigned + Default + Copy + MaxEncodedLen> MaxEncodedLen
-    for UnbondingInfo<Balance>
-{
-    // This is just an assumption, will be calculated properly in the future. See the comment for `MAX_ASSUMED_VEC_LEN`.
-    fn max_encoded_len() -> usize {
-        codec::Compact(MAX_ASSUMED_VEC_LEN)
-            .encoded_size()
-            .saturating_add(
-                (MAX_ASSUMED_VEC_LEN as usize)
-                    .saturating_mul(UnlockingChunk::<Balance>::max_encoded_len()),
-            )
-    }
-}
-
-impl<Balance> UnbondingInfo<Balance>
+impl<Balance, MaxLen> UnbondingInfo<Balance, MaxLen>
 where
-    Balance: AtLeast32BitUnsigned + Default + Copy + MaxEncodedLen,
+    Balance: AtLeast32BitUnsigned + Default + Copy + Debug + MaxEncodedLen,
+    MaxLen: Get<u32>,
 {
     /// Returns total number of unlocking chunks.
     fn len(&self) -> u32 {
@@ -492,17 +793,47 @@ where
     }
 
     /// Adds a new unlocking chunk to the vector, preserving the unlock_era based ordering.
-    fn add(&mut self, chunk: UnlockingChunk<Balance>) {
+    ///
+    /// Returns an error if the chunk cannot be merged with an existing one and there is no more space for it.
+    fn add(&mut self, chunk: UnlockingChunk<Balance>) -> Result<(), UnlockingChunk<Balance>> {
         // It is possible that the unbonding period changes so we need to account for that
         match self
             .unlocking_chunks
             .binary_search_by(|x| x.unlock_era.cmp(&chunk.unlock_era))
         {
             // Merge with existing chunk if unlock_eras match
-            Ok(pos) => self.unlocking_chunks[pos].add_amount(chunk.amount),
+            Ok(pos) => {
+                self.unlocking_chunks[pos].add_amount(chunk.amount);
+                Ok(())
+            }
             // Otherwise insert where it should go. Note that this will in almost all cases return the last index.
-            Err(pos) => self.unlocking_chunks.insert(pos, chunk),
+            Err(pos) => self.unlocking_chunks.try_insert(pos, chunk),
+        }
+    }
+
+    /// Removes up to `value` from the unlocking chunks, starting with the newest one (last in, first out).
+    ///
+    /// Returns the amount which was removed.
+    fn rebond(&mut self, value: Balance) -> Balance {
+        let mut rebonded = Balance::zero();
+
+        while rebonded < value {
+            let chunk = match self.unlocking_chunks.iter_mut().last() {
+                Some(chunk) => chunk,
+                None => break,
+            };
+
+            let amount = chunk.amount.min(value.saturating_sub(rebonded));
+            chunk.amount = chunk.amount.saturating_sub(amount);
+            rebonded = rebonded.saturating_add(amount);
+
+            if chunk.amount.is_zero() {
+                self.unlocking_chunks
+                    .remove(self.unlocking_chunks.len() - 1);
+            }
         }
+
+        rebonded
     }
 
     /// Partitions the unlocking chunks into two groups:
@@ -512,13 +843,11 @@ where
     ///
     /// Order of chunks is preserved in the two new structs.
     fn partition(self, era: EraIndex) -> (Self, Self) {
-        let (matching_chunks, other_chunks): (
-            Vec<UnlockingChunk<Balance>>,
-            Vec<UnlockingChunk<Balance>>,
-        ) = self
-            .unlocking_chunks
-            .iter()
-            .partition(|chunk| chunk.unlock_era <= era);
+        let mut matching_chunks = self.unlocking_chunks.clone();
+        matching_chunks.retain(|chunk| chunk.unlock_era <= era);
+
+        let mut other_chunks = self.unlocking_chunks;
+        other_chunks.retain(|chunk| chunk.unlock_era > era);
 
         (
             Self {
@@ -530,10 +859,18 @@ where
         )
     }
 
+    /// Returns `(unlock era, amount)` pairs of all unlocking chunks, sorted by unlock era.
+    fn unlock_eras(&self) -> Vec<(EraIndex, Balance)> {
+        self.unlocking_chunks
+            .iter()
+            .map(|chunk| (chunk.unlock_era, chunk.amount))
+            .collect()
+    }
+
     #[cfg(test)]
     /// Return clone of the internal vector. Should only be used for testing.
     fn vec(&self) -> Vec<UnlockingChunk<Balance>> {
-        self.unlocking_chunks.clone()
+        self.unlocking_chunks.to_vec()
     }
 }
 
@@ -541,12 +878,16 @@ where
 /// In order to make staking more competitive, majority of stakers will want to
 /// automatically restake anything they earn.
 #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub enum RewardDestination {
     /// Rewards are transferred to stakers free balance without any further action.
     FreeBalance,
     /// Rewards are transferred to stakers balance and are immediately re-staked
     /// on the contract from which the reward was received.
     StakeBalance,
+    /// Rewards are transferred to stakers balance and the specified fraction of them
+    /// is immediately re-staked on the contract from which the reward was received.
+    Split(Perbill),
 }
 
 impl Default for RewardDestination {
@@ -555,19 +896,48 @@ impl Default for RewardDestination {
     }
 }
 
+impl RewardDestination {
+    /// Part of the `reward` which should be re-staked according to the reward destination.
+    pub fn restake_part<Balance: AtLeast32BitUnsigned>(&self, reward: Balance) -> Balance {
+        match self {
+            RewardDestination::FreeBalance => Zero::zero(),
+            RewardDestination::StakeBalance => reward,
+            RewardDestination::Split(restake_ratio) => *restake_ratio * reward,
+        }
+    }
+}
+
 /// Contains information about account's locked & unbonding balances.
-#[derive(Clone, PartialEq, Encode, Decode, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-pub struct AccountLedger<Balance: AtLeast32BitUnsigned + Default + Copy + MaxEncodedLen> {
+#[derive(
+    CloneNoBound,
+    PartialEqNoBound,
+    Encode,
+    Decode,
+    DefaultNoBound,
+    RuntimeDebugNoBound,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(MaxUnlockingChunks))]
+#[codec(mel_bound(Balance: MaxEncodedLen, MaxUnlockingChunks: Get<u32>))]
+pub struct AccountLedger<
+    Balance: AtLeast32BitUnsigned + Default + Copy + Debug + MaxEncodedLen,
+    MaxUnlockingChunks: Get<u32>,
+> {
     /// Total balance locked.
     #[codec(compact)]
     pub locked: Balance,
     /// Information about unbonding chunks.
-    unbonding_info: UnbondingInfo<Balance>,
+    unbonding_info: UnbondingInfo<Balance, MaxUnlockingChunks>,
     /// Instruction on how to handle reward payout
     reward_destination: RewardDestination,
 }
 
-impl<Balance: AtLeast32BitUnsigned + Default + Copy + MaxEncodedLen> AccountLedger<Balance> {
+impl<
+        Balance: AtLeast32BitUnsigned + Default + Copy + Debug + MaxEncodedLen,
+        MaxUnlockingChunks: Get<u32>,
+    > AccountLedger<Balance, MaxUnlockingChunks>
+{
     /// `true` if ledger is empty (no locked funds, no unbonding chunks), `false` otherwise.
     pub fn is_empty(&self) -> bool {
         self.locked.is_zero() && self.unbonding_info.is_empty()
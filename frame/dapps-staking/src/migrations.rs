@@ -0,0 +1,859 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: PolyForm-Noncommercial-1.0.0
+
+// You should have received a copy of the PolyForm-Noncommercial license with this crate.
+// If not, see <https://polyformproject.org/licenses/noncommercial/1.0.0//>.
+
+//! Dapps staking storage migrations.
+
+use super::*;
+use codec::DecodeAll;
+use frame_support::{
+    log,
+    storage::unhashed,
+    traits::{
+        ConstU32, ExistenceRequirement, Imbalance, OnRuntimeUpgrade, OnUnbalanced, WithdrawReasons,
+    },
+    weights::Weight,
+    BoundedVec, WeakBoundedVec,
+};
+use sp_runtime::traits::Saturating;
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "try-runtime")]
+use frame_support::ensure;
+
+/// Migrates `ContractEraStake` to the lazy model, where entries are only written for eras in which contract stake changed.
+///
+/// Prior to this migration, `ContractEraStake` entries of all registered contracts were copied over to the next era
+/// at each era boundary. This means every registered, staked contract already has an entry for the current era,
+/// while every unregistered, staked contract has an entry for the era in which it was unregistered.
+/// It's enough to mark that era as the latest era in which contract stake changed, regardless of the contract state,
+/// so the stake remains accessible if the contract is registered again.
+/// All older entries are kept as they are and remain directly accessible.
+pub struct LazyContractStakeMigration<T: Config>(PhantomData<T>);
+impl<T: Config> LazyContractStakeMigration<T> {
+    /// Last era in which contract stake was copied over, in the old storage layout.
+    fn latest_stake_era(state: &DAppState, current_era: EraIndex) -> EraIndex {
+        match state {
+            DAppState::Registered => current_era,
+            DAppState::Unregistered(unregistered_era) => *unregistered_era,
+        }
+    }
+}
+
+impl<T: Config> OnRuntimeUpgrade for LazyContractStakeMigration<T> {
+    fn on_runtime_upgrade() -> Weight {
+        if StorageVersion::<T>::get() != Version::V4_0_0 {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let current_era = CurrentEra::<T>::get();
+        let mut consumed_weight = T::DbWeight::get().reads_writes(2, 1);
+
+        for (contract_id, dapp_info) in RegisteredDapps::<T>::iter() {
+            consumed_weight = consumed_weight.saturating_add(T::DbWeight::get().reads(2));
+
+            let latest_era = Self::latest_stake_era(&dapp_info.state, current_era);
+            if ContractEraStake::<T>::contains_key(&contract_id, latest_era) {
+                ContractStakeHistory::<T>::insert(
+                    &contract_id,
+                    BoundedVec::truncate_from(sp_std::vec![latest_era]),
+                );
+                consumed_weight = consumed_weight.saturating_add(T::DbWeight::get().writes(1));
+            }
+        }
+
+        StorageVersion::<T>::put(Version::V5_0_0);
+
+        consumed_weight
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+        let current_era = CurrentEra::<T>::get();
+        let staked_contracts = RegisteredDapps::<T>::iter()
+            .filter(|(contract_id, dapp_info)| {
+                ContractEraStake::<T>::contains_key(
+                    contract_id,
+                    Self::latest_stake_era(&dapp_info.state, current_era),
+                )
+            })
+            .count() as u32;
+
+        Ok(staked_contracts.encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+        let staked_contracts: u32 = Decode::decode(&mut state.as_ref())
+            .map_err(|_| "Failed to decode pre-upgrade state.")?;
+
+        ensure!(
+            StorageVersion::<T>::get() == Version::V5_0_0,
+            "Storage version must be V5_0_0 at the end of migration."
+        );
+
+        let current_era = CurrentEra::<T>::get();
+        let mut latest_stake_eras = 0_u32;
+        for (contract_id, history) in ContractStakeHistory::<T>::iter() {
+            let era = *history.last().ok_or("Stake history must not be empty.")?;
+            let dapp_info =
+                RegisteredDapps::<T>::get(&contract_id).ok_or("Contract must be registered.")?;
+            ensure!(
+                era == Self::latest_stake_era(&dapp_info.state, current_era),
+                "Latest stake era must match the contract state."
+            );
+            ensure!(
+                ContractEraStake::<T>::contains_key(&contract_id, era),
+                "Latest stake era must have a stake entry."
+            );
+            latest_stake_eras += 1;
+        }
+        ensure!(
+            latest_stake_eras == staked_contracts,
+            "Each staked contract must have the latest stake era set."
+        );
+
+        Ok(())
+    }
+}
+
+/// Layout of `AccountLedger` prior to bounding the unlocking chunks vector.
+/// Old `UnbondingInfo` was a wrapper around the vector so it's encoded the same way.
+#[derive(Decode)]
+struct OldAccountLedger<Balance: HasCompact + MaxEncodedLen> {
+    #[codec(compact)]
+    locked: Balance,
+    unlocking_chunks: Vec<UnlockingChunk<Balance>>,
+    reward_destination: RewardDestination,
+}
+
+/// State of the multi-block storage migrations, `BoundedStorageMigration` and `StakeBoostMigration`.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, RuntimeDebug, MaxEncodedLen)]
+pub enum MigrationState {
+    /// No migration in progress
+    NotInProgress,
+    /// In the middle of `Ledger` migration. The const for max size is an overestimate but that's fine.
+    Ledger(Option<WeakBoundedVec<u8, ConstU32<1000>>>),
+    /// In the middle of `GeneralStakerInfo` migration. The const for max size is an overestimate but that's fine.
+    GeneralStakerInfo(Option<WeakBoundedVec<u8, ConstU32<1000>>>),
+    /// In the middle of `GeneralEraInfo` migration. The const for max size is an overestimate but that's fine.
+    GeneralEraInfo(Option<WeakBoundedVec<u8, ConstU32<1000>>>),
+    /// In the middle of `ContractEraStake` migration. The const for max size is an overestimate but that's fine.
+    ContractEraStake(Option<WeakBoundedVec<u8, ConstU32<1000>>>),
+}
+
+impl Default for MigrationState {
+    fn default() -> Self {
+        MigrationState::NotInProgress
+    }
+}
+
+/// Migrates `Ledger` and `GeneralStakerInfo` values to their bounded representation.
+///
+/// Encoding of bounded values is the same as before so only the values which exceed
+/// `MaxUnlockingChunks` or `MaxEraStakeValues` need to be modified:
+///
+/// * excess unlocking chunks are merged into a single chunk, unlocking in the latest unlock era
+/// * staker rewards of the oldest eras are claimed until the excess era stakes can be removed, so no reward is lost
+///
+/// Values exceeding the bounds cannot be decoded until they're migrated, so the pallet is put into
+/// maintenance mode for the duration of the migration. Migration is started as part of the runtime upgrade
+/// and is continued via the `migrate` call, over multiple blocks.
+///
+/// Once finished, `StakeBoostMigration` continues as part of the same state machine, so the pallet
+/// stays in maintenance mode until storage is fully migrated to `V7_0_0`.
+pub struct BoundedStorageMigration<T: Config>(PhantomData<T>);
+impl<T: Config> BoundedStorageMigration<T> {
+    /// Migrate as many values as possible within the provided weight limit.
+    /// Returns the consumed weight.
+    pub fn migrate(requested_weight_limit: Option<Weight>) -> Weight {
+        let mut consumed_weight = T::DbWeight::get().reads(1);
+        if StorageVersion::<T>::get() != Version::V5_0_0 {
+            return consumed_weight;
+        }
+
+        let max_allowed_call_weight = Self::max_call_weight();
+        let weight_limit = requested_weight_limit
+            .unwrap_or(max_allowed_call_weight)
+            .min(max_allowed_call_weight);
+
+        let mut migration_state = MigrationStateStorage::<T>::get();
+        consumed_weight = consumed_weight.saturating_add(T::DbWeight::get().reads(1));
+        if migration_state == MigrationState::NotInProgress {
+            migration_state = MigrationState::Ledger(None);
+        }
+
+        if let MigrationState::Ledger(last_processed_key) = migration_state.clone() {
+            let key_iter = if let Some(previous_key) = last_processed_key {
+                Ledger::<T>::iter_keys_from(previous_key.into_inner())
+            } else {
+                Ledger::<T>::iter_keys()
+            };
+
+            for account in key_iter {
+                let key = Ledger::<T>::hashed_key_for(&account);
+                consumed_weight = consumed_weight.saturating_add(Self::bound_ledger(&key));
+
+                if consumed_weight.any_gt(weight_limit) {
+                    return Self::suspend(
+                        MigrationState::Ledger(Some(WeakBoundedVec::force_from(key, None))),
+                        consumed_weight,
+                        weight_limit,
+                    );
+                }
+            }
+
+            migration_state = MigrationState::GeneralStakerInfo(None);
+        }
+
+        if let MigrationState::GeneralStakerInfo(last_processed_key) = migration_state {
+            let key_iter = if let Some(previous_key) = last_processed_key {
+                GeneralStakerInfo::<T>::iter_keys_from(previous_key.into_inner())
+            } else {
+                GeneralStakerInfo::<T>::iter_keys()
+            };
+
+            for (staker, contract_id) in key_iter {
+                let key = GeneralStakerInfo::<T>::hashed_key_for(&staker, &contract_id);
+                consumed_weight = consumed_weight.saturating_add(Self::bound_staker_info(
+                    &key,
+                    &staker,
+                    &contract_id,
+                ));
+
+                if consumed_weight.any_gt(weight_limit) {
+                    return Self::suspend(
+                        MigrationState::GeneralStakerInfo(Some(WeakBoundedVec::force_from(
+                            key, None,
+                        ))),
+                        consumed_weight,
+                        weight_limit,
+                    );
+                }
+            }
+        }
+
+        // Clean up storage value so the migration doesn't leave any leftovers
+        MigrationStateStorage::<T>::kill();
+        StorageVersion::<T>::put(Version::V6_0_0);
+        consumed_weight = consumed_weight.saturating_add(T::DbWeight::get().writes(2));
+
+        // Pallet remains disabled, stake boost migration continues with the remaining weight
+        consumed_weight.saturating_add(StakeBoostMigration::<T>::migrate(Some(
+            weight_limit.saturating_sub(consumed_weight),
+        )))
+    }
+
+    /// Max allowed weight that migration should be allowed to consume
+    pub fn max_call_weight() -> Weight {
+        // 50% of block should be fine
+        T::BlockWeights::get().max_block / 2
+    }
+
+    /// Store the migration state so it can be continued in one of the following blocks.
+    fn suspend(state: MigrationState, consumed_weight: Weight, weight_limit: Weight) -> Weight {
+        MigrationStateStorage::<T>::put(state);
+        let consumed_weight = consumed_weight.saturating_add(T::DbWeight::get().writes(1));
+
+        // we want try-runtime to execute the entire migration
+        if cfg!(feature = "try-runtime") {
+            Self::migrate(Some(weight_limit)).saturating_add(consumed_weight)
+        } else {
+            consumed_weight
+        }
+    }
+
+    /// Bound the `AccountLedger` value under the given key.
+    /// Returns conservative weight estimate of the operation.
+    fn bound_ledger(key: &[u8]) -> Weight {
+        let raw_value = match unhashed::get_raw(key) {
+            Some(raw_value) => raw_value,
+            None => {
+                return Weight::from_parts(
+                    T::DbWeight::get().reads(1).ref_time(),
+                    AccountLedgerOf::<T>::max_encoded_len() as u64,
+                )
+            }
+        };
+        let read_weight = Weight::from_parts(
+            T::DbWeight::get().reads(1).ref_time(),
+            raw_value.len() as u64,
+        );
+
+        let old_ledger = match OldAccountLedger::<BalanceOf<T>>::decode(&mut raw_value.as_ref()) {
+            Ok(old_ledger) => old_ledger,
+            Err(_) => return read_weight,
+        };
+
+        let max_chunks = T::MaxUnlockingChunks::get() as usize;
+        if old_ledger.unlocking_chunks.len() <= max_chunks {
+            return read_weight;
+        }
+
+        // Chunks are sorted by unlock era, so the excess ones are merged into the latest one.
+        // `MaxUnlockingChunks` is greater than zero, as ensured by the `integrity_test`, so the merged chunk is kept.
+        let mut unlocking_chunks = old_ledger.unlocking_chunks;
+        let excess_chunks = unlocking_chunks.split_off(max_chunks.saturating_sub(1));
+        if let Some(latest_chunk) = excess_chunks.last() {
+            unlocking_chunks.push(UnlockingChunk {
+                amount: excess_chunks
+                    .iter()
+                    .fold(Zero::zero(), |sum: BalanceOf<T>, chunk| {
+                        sum.saturating_add(chunk.amount)
+                    }),
+                unlock_era: latest_chunk.unlock_era,
+            });
+        }
+
+        let ledger = AccountLedgerOf::<T> {
+            locked: old_ledger.locked,
+            unbonding_info: UnbondingInfo {
+                unlocking_chunks: BoundedVec::truncate_from(unlocking_chunks),
+            },
+            reward_destination: old_ledger.reward_destination,
+        };
+        unhashed::put(key, &ledger);
+
+        read_weight.saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Bound the `StakerInfo` value of the staker & contract pairing, stored under the given key.
+    /// Returns conservative weight estimate of the operation.
+    fn bound_staker_info(
+        key: &[u8],
+        staker: &T::AccountId,
+        contract_id: &T::SmartContract,
+    ) -> Weight {
+        let raw_value = match unhashed::get_raw(key) {
+            Some(raw_value) => raw_value,
+            None => {
+                return Weight::from_parts(
+                    T::DbWeight::get().reads(1).ref_time(),
+                    StakerInfoOf::<T>::max_encoded_len() as u64,
+                )
+            }
+        };
+        let read_weight = Weight::from_parts(
+            T::DbWeight::get().reads(1).ref_time(),
+            raw_value.len() as u64,
+        );
+
+        // Old `StakerInfo` was a wrapper around the vector so it's encoded the same way.
+        let mut stakes = match Vec::<EraStake<BalanceOf<T>>>::decode(&mut raw_value.as_ref()) {
+            Ok(stakes) => stakes,
+            Err(_) => return read_weight,
+        };
+
+        let max_stakes = T::MaxEraStakeValues::get() as usize;
+        if stakes.len() <= max_stakes {
+            return read_weight;
+        }
+
+        // Rewards of the eras covered by the excess stakes are claimed, so the stakes can be removed.
+        let remaining_stakes = stakes.split_off(stakes.len().saturating_sub(max_stakes));
+        let claim_weight = Self::claim_excess_stakes(
+            staker,
+            contract_id,
+            &stakes,
+            remaining_stakes
+                .first()
+                .map_or(0, |era_stake| era_stake.era),
+        );
+
+        let staker_info = StakerInfoOf::<T> {
+            stakes: BoundedVec::truncate_from(remaining_stakes),
+        };
+        unhashed::put(key, &staker_info);
+
+        read_weight
+            .saturating_add(claim_weight)
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Claim staker rewards of the eras in range `[stakes[0].era, end_era)`, where each stake applies until the next one.
+    /// Rewards are paid out to the staker, regardless of the reward destination, and rewards of the expired eras
+    /// are swept to `ExpiredStakerRewards`.
+    ///
+    /// Era and contract stake values may still be in the layout prior to `StakeBoostMigration`, so they're decoded
+    /// in either layout. Stakes couldn't be time-locked before, so staker reward only depends on the staked amount.
+    ///
+    /// Returns conservative weight estimate of the operation.
+    fn claim_excess_stakes(
+        staker: &T::AccountId,
+        contract_id: &T::SmartContract,
+        stakes: &[EraStake<BalanceOf<T>>],
+        end_era: EraIndex,
+    ) -> Weight {
+        let dapp_info = match RegisteredDapps::<T>::get(contract_id) {
+            Some(dapp_info) => dapp_info,
+            None => return T::DbWeight::get().reads(1),
+        };
+        let unregistered_periods = Pallet::<T>::unregistered_periods(contract_id);
+        let claimable_eras = Pallet::<T>::claimable_staker_eras();
+        let mut consumed_weight = T::DbWeight::get().reads(3);
+
+        let mut era_rewards = Vec::new();
+        for (index, era_stake) in stakes.iter().enumerate() {
+            let next_era = stakes.get(index + 1).map_or(end_era, |next| next.era);
+            if era_stake.staked.is_zero() {
+                continue;
+            }
+
+            for era in era_stake.era..next_era {
+                if matches!(
+                    dapp_info.state,
+                    DAppState::Unregistered(unregister_era) if era >= unregister_era
+                ) || Pallet::<T>::reregistered_era(&unregistered_periods, era).is_some()
+                {
+                    continue;
+                }
+
+                // Contract stake, contract stake history, era info and dApp commission
+                consumed_weight = consumed_weight.saturating_add(T::DbWeight::get().reads(5));
+                let (staking_info, era_info) = match (
+                    Self::contract_stake_info(contract_id, era),
+                    Self::era_info(era),
+                ) {
+                    (Some(staking_info), Some(era_info)) => (staking_info, era_info),
+                    _ => continue,
+                };
+
+                let (developer_reward, stakers_joint_reward) =
+                    Pallet::<T>::dev_stakers_split(&staking_info, &era_info);
+                let (_, shared_reward) = Pallet::<T>::commission_split(
+                    developer_reward,
+                    Pallet::<T>::dapp_commission_at(contract_id, era),
+                );
+                let staker_portion = Perbill::from_rational(era_stake.staked, staking_info.total);
+                let reward = (staker_portion * stakers_joint_reward)
+                    .saturating_add(staker_portion * shared_reward);
+                era_rewards.push((era, reward, era < claimable_eras.start));
+            }
+        }
+
+        let (mut paid, mut expired) = (BalanceOf::<T>::zero(), BalanceOf::<T>::zero());
+        for (_, reward, is_expired) in era_rewards.iter() {
+            if *is_expired {
+                expired = expired.saturating_add(*reward);
+            } else {
+                paid = paid.saturating_add(*reward);
+            }
+        }
+
+        let reward_imbalance = match T::Currency::withdraw(
+            &Pallet::<T>::account_id(),
+            paid.saturating_add(expired),
+            WithdrawReasons::TRANSFER,
+            ExistenceRequirement::AllowDeath,
+        ) {
+            Ok(reward_imbalance) => reward_imbalance,
+            Err(_) => {
+                log::error!(
+                    "Rewards of {:?} on {:?} before era {:?} couldn't be withdrawn from the pallet account.",
+                    staker,
+                    contract_id,
+                    end_era,
+                );
+                return consumed_weight.saturating_add(T::DbWeight::get().reads(1));
+            }
+        };
+        let (paid_imbalance, expired_imbalance) = reward_imbalance.split(paid);
+        T::Currency::resolve_creating(staker, paid_imbalance);
+        T::ExpiredStakerRewards::on_unbalanced(expired_imbalance);
+
+        for (era, reward, is_expired) in era_rewards {
+            let event = if is_expired {
+                Event::<T>::StakerRewardExpired(staker.clone(), contract_id.clone(), era, reward)
+            } else {
+                Event::<T>::Reward(staker.clone(), contract_id.clone(), era, reward)
+            };
+            Pallet::<T>::deposit_event(event);
+        }
+
+        // Pallet account, staker account and the expired rewards handler
+        consumed_weight.saturating_add(T::DbWeight::get().reads_writes(3, 3))
+    }
+
+    /// Contract stake info of `era`, the same way as `Pallet::contract_stake_info`, in either layout.
+    fn contract_stake_info(
+        contract_id: &T::SmartContract,
+        era: EraIndex,
+    ) -> Option<ContractStakeInfo<BalanceOf<T>>> {
+        let decode = |stake_era: EraIndex| {
+            let raw_value = unhashed::get_raw(&ContractEraStake::<T>::hashed_key_for(
+                contract_id,
+                stake_era,
+            ))?;
+            ContractStakeInfo::<BalanceOf<T>>::decode_all(&mut raw_value.as_ref())
+                .ok()
+                .or_else(|| {
+                    OldContractStakeInfo::<BalanceOf<T>>::decode_all(&mut raw_value.as_ref())
+                        .ok()
+                        .map(|old_staking_info| ContractStakeInfo {
+                            total: old_staking_info.total,
+                            number_of_stakers: old_staking_info.number_of_stakers,
+                            contract_reward_claimed: old_staking_info.contract_reward_claimed,
+                            boost: Zero::zero(),
+                        })
+                })
+        };
+        if let Some(staking_info) = decode(era) {
+            return Some(staking_info);
+        }
+
+        let history = ContractStakeHistory::<T>::get(contract_id);
+        let index = history.partition_point(|stake_era| *stake_era <= era);
+        let stake_era = *history.get(index.checked_sub(1)?)?;
+
+        decode(stake_era).map(|mut staking_info| {
+            staking_info.contract_reward_claimed = false;
+            staking_info
+        })
+    }
+
+    /// Era info of `era`, in either layout.
+    fn era_info(era: EraIndex) -> Option<EraInfo<BalanceOf<T>>> {
+        let raw_value = unhashed::get_raw(&GeneralEraInfo::<T>::hashed_key_for(era))?;
+        EraInfo::<BalanceOf<T>>::decode_all(&mut raw_value.as_ref())
+            .ok()
+            .or_else(|| {
+                OldEraInfo::<BalanceOf<T>>::decode_all(&mut raw_value.as_ref())
+                    .ok()
+                    .map(|old_era_info| EraInfo {
+                        rewards: old_era_info.rewards,
+                        staked: old_era_info.staked,
+                        locked: old_era_info.locked,
+                        boost: Zero::zero(),
+                    })
+            })
+    }
+}
+
+impl<T: Config> OnRuntimeUpgrade for BoundedStorageMigration<T> {
+    fn on_runtime_upgrade() -> Weight {
+        if StorageVersion::<T>::get() != Version::V5_0_0 {
+            return T::DbWeight::get().reads(1);
+        }
+
+        // Values which exceed the bounds cannot be decoded until migrated.
+        PalletDisabled::<T>::put(true);
+
+        // Ensures that first step only starts the migration with minimal changes in case of production build.
+        // In case of `try-runtime`, we want predefined limit.
+        let limit = if cfg!(feature = "try-runtime") {
+            None
+        } else {
+            Some(Weight::zero())
+        };
+
+        Self::migrate(limit).saturating_add(T::DbWeight::get().reads_writes(1, 1))
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+        let ledgers = Ledger::<T>::iter_keys().count() as u32;
+        let staker_infos = GeneralStakerInfo::<T>::iter_keys().count() as u32;
+
+        Ok((ledgers, staker_infos).encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+        let (ledgers, staker_infos): (u32, u32) = Decode::decode(&mut state.as_ref())
+            .map_err(|_| "Failed to decode pre-upgrade state.")?;
+
+        ensure!(
+            StorageVersion::<T>::get() == Version::V7_0_0,
+            "Storage version must be V7_0_0 at the end of migration, since stake boost migration follows."
+        );
+        ensure!(
+            !MigrationStateStorage::<T>::exists(),
+            "MigrationStateStorage has to be killed at the end of migration."
+        );
+        ensure!(
+            !PalletDisabled::<T>::get(),
+            "Pallet must be enabled at the end of migration."
+        );
+
+        // Values which cannot be decoded are skipped by the iterator.
+        ensure!(
+            Ledger::<T>::iter_keys().count() as u32 == ledgers
+                && Ledger::<T>::iter_values().count() as u32 == ledgers,
+            "All ledgers must be decodable as bounded values."
+        );
+        ensure!(
+            GeneralStakerInfo::<T>::iter_keys().count() as u32 == staker_infos
+                && GeneralStakerInfo::<T>::iter_values().count() as u32 == staker_infos,
+            "All staker infos must be decodable as bounded values."
+        );
+
+        Ok(())
+    }
+}
+
+/// Layout of `EraInfo` prior to tracking the reward weight boost of the time-locked stakes.
+#[derive(Decode)]
+struct OldEraInfo<Balance: HasCompact + MaxEncodedLen> {
+    rewards: RewardInfo<Balance>,
+    #[codec(compact)]
+    staked: Balance,
+    #[codec(compact)]
+    locked: Balance,
+}
+
+/// Layout of `ContractStakeInfo` prior to tracking the reward weight boost of the time-locked stakes.
+#[derive(Decode)]
+struct OldContractStakeInfo<Balance: HasCompact + MaxEncodedLen> {
+    #[codec(compact)]
+    total: Balance,
+    #[codec(compact)]
+    number_of_stakers: u32,
+    contract_reward_claimed: bool,
+}
+
+/// Adds the `boost` field to the `GeneralEraInfo` and `ContractEraStake` values.
+///
+/// Stakes couldn't be time-locked prior to this migration, so nothing is boosted.
+///
+/// Number of values grows with the number of eras and dApps, so they're migrated over multiple blocks,
+/// the same way as in `BoundedStorageMigration`. Old values cannot be decoded until migrated, so the pallet
+/// is in maintenance mode for the duration of the migration.
+///
+/// Continues the state machine of `BoundedStorageMigration`, which is run first in case storage is still
+/// at `V5_0_0`. This way both can be applied in the same runtime upgrade, and the pallet is only taken out
+/// of maintenance mode once storage is at `V7_0_0`.
+pub struct StakeBoostMigration<T: Config>(PhantomData<T>);
+impl<T: Config> StakeBoostMigration<T> {
+    /// Migrate as many values as possible within the provided weight limit.
+    /// Returns the consumed weight.
+    pub fn migrate(requested_weight_limit: Option<Weight>) -> Weight {
+        let mut consumed_weight = T::DbWeight::get().reads(1);
+        match StorageVersion::<T>::get() {
+            Version::V5_0_0 => {
+                return consumed_weight.saturating_add(BoundedStorageMigration::<T>::migrate(
+                    requested_weight_limit,
+                ))
+            }
+            Version::V6_0_0 => (),
+            _ => return consumed_weight,
+        }
+
+        let max_allowed_call_weight = BoundedStorageMigration::<T>::max_call_weight();
+        let weight_limit = requested_weight_limit
+            .unwrap_or(max_allowed_call_weight)
+            .min(max_allowed_call_weight);
+
+        let mut migration_state = MigrationStateStorage::<T>::get();
+        consumed_weight = consumed_weight.saturating_add(T::DbWeight::get().reads(1));
+        if migration_state == MigrationState::NotInProgress {
+            migration_state = MigrationState::GeneralEraInfo(None);
+        }
+
+        if let MigrationState::GeneralEraInfo(last_processed_key) = migration_state.clone() {
+            let key_iter = if let Some(previous_key) = last_processed_key {
+                GeneralEraInfo::<T>::iter_keys_from(previous_key.into_inner())
+            } else {
+                GeneralEraInfo::<T>::iter_keys()
+            };
+
+            for era in key_iter {
+                let key = GeneralEraInfo::<T>::hashed_key_for(era);
+                consumed_weight = consumed_weight.saturating_add(Self::boost_era_info(&key));
+
+                if consumed_weight.any_gt(weight_limit) {
+                    return Self::suspend(
+                        MigrationState::GeneralEraInfo(Some(WeakBoundedVec::force_from(key, None))),
+                        consumed_weight,
+                        weight_limit,
+                    );
+                }
+            }
+
+            migration_state = MigrationState::ContractEraStake(None);
+        }
+
+        if let MigrationState::ContractEraStake(last_processed_key) = migration_state {
+            let key_iter = if let Some(previous_key) = last_processed_key {
+                ContractEraStake::<T>::iter_keys_from(previous_key.into_inner())
+            } else {
+                ContractEraStake::<T>::iter_keys()
+            };
+
+            for (contract_id, era) in key_iter {
+                let key = ContractEraStake::<T>::hashed_key_for(&contract_id, era);
+                consumed_weight =
+                    consumed_weight.saturating_add(Self::boost_contract_stake_info(&key));
+
+                if consumed_weight.any_gt(weight_limit) {
+                    return Self::suspend(
+                        MigrationState::ContractEraStake(Some(WeakBoundedVec::force_from(
+                            key, None,
+                        ))),
+                        consumed_weight,
+                        weight_limit,
+                    );
+                }
+            }
+        }
+
+        // Clean up storage value so the migration doesn't leave any leftovers
+        MigrationStateStorage::<T>::kill();
+        StorageVersion::<T>::put(Version::V7_0_0);
+        PalletDisabled::<T>::put(false);
+
+        consumed_weight.saturating_add(T::DbWeight::get().writes(3))
+    }
+
+    /// Store the migration state so it can be continued in one of the following blocks.
+    fn suspend(state: MigrationState, consumed_weight: Weight, weight_limit: Weight) -> Weight {
+        MigrationStateStorage::<T>::put(state);
+        let consumed_weight = consumed_weight.saturating_add(T::DbWeight::get().writes(1));
+
+        // we want try-runtime to execute the entire migration
+        if cfg!(feature = "try-runtime") {
+            Self::migrate(Some(weight_limit)).saturating_add(consumed_weight)
+        } else {
+            consumed_weight
+        }
+    }
+
+    /// Add the `boost` field to the `EraInfo` value under the given key.
+    /// Returns conservative weight estimate of the operation.
+    fn boost_era_info(key: &[u8]) -> Weight {
+        let raw_value = match unhashed::get_raw(key) {
+            Some(raw_value) => raw_value,
+            None => {
+                return Weight::from_parts(
+                    T::DbWeight::get().reads(1).ref_time(),
+                    EraInfo::<BalanceOf<T>>::max_encoded_len() as u64,
+                )
+            }
+        };
+        let read_weight = Weight::from_parts(
+            T::DbWeight::get().reads(1).ref_time(),
+            raw_value.len() as u64,
+        );
+
+        // Values which are already in the new layout cannot be decoded as a whole.
+        let old_era_info = match OldEraInfo::<BalanceOf<T>>::decode_all(&mut raw_value.as_ref()) {
+            Ok(old_era_info) => old_era_info,
+            Err(_) => return read_weight,
+        };
+
+        let era_info = EraInfo {
+            rewards: old_era_info.rewards,
+            staked: old_era_info.staked,
+            locked: old_era_info.locked,
+            boost: Zero::zero(),
+        };
+        unhashed::put(key, &era_info);
+
+        read_weight.saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Add the `boost` field to the `ContractStakeInfo` value under the given key.
+    /// Returns conservative weight estimate of the operation.
+    fn boost_contract_stake_info(key: &[u8]) -> Weight {
+        let raw_value = match unhashed::get_raw(key) {
+            Some(raw_value) => raw_value,
+            None => {
+                return Weight::from_parts(
+                    T::DbWeight::get().reads(1).ref_time(),
+                    ContractStakeInfo::<BalanceOf<T>>::max_encoded_len() as u64,
+                )
+            }
+        };
+        let read_weight = Weight::from_parts(
+            T::DbWeight::get().reads(1).ref_time(),
+            raw_value.len() as u64,
+        );
+
+        // Values which are already in the new layout cannot be decoded as a whole.
+        let old_staking_info =
+            match OldContractStakeInfo::<BalanceOf<T>>::decode_all(&mut raw_value.as_ref()) {
+                Ok(old_staking_info) => old_staking_info,
+                Err(_) => return read_weight,
+            };
+
+        let staking_info = ContractStakeInfo {
+            total: old_staking_info.total,
+            number_of_stakers: old_staking_info.number_of_stakers,
+            contract_reward_claimed: old_staking_info.contract_reward_claimed,
+            boost: Zero::zero(),
+        };
+        unhashed::put(key, &staking_info);
+
+        read_weight.saturating_add(T::DbWeight::get().writes(1))
+    }
+}
+
+impl<T: Config> OnRuntimeUpgrade for StakeBoostMigration<T> {
+    fn on_runtime_upgrade() -> Weight {
+        if !matches!(
+            StorageVersion::<T>::get(),
+            Version::V5_0_0 | Version::V6_0_0
+        ) {
+            return T::DbWeight::get().reads(1);
+        }
+
+        // Old values cannot be decoded until migrated.
+        PalletDisabled::<T>::put(true);
+
+        // Ensures that first step only starts the migration with minimal changes in case of production build.
+        // In case of `try-runtime`, we want predefined limit.
+        let limit = if cfg!(feature = "try-runtime") {
+            None
+        } else {
+            Some(Weight::zero())
+        };
+
+        Self::migrate(limit).saturating_add(T::DbWeight::get().reads_writes(1, 1))
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+        let era_infos = GeneralEraInfo::<T>::iter_keys().count() as u32;
+        let contract_stakes = ContractEraStake::<T>::iter_keys().count() as u32;
+
+        Ok((era_infos, contract_stakes).encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+        let (era_infos, contract_stakes): (u32, u32) = Decode::decode(&mut state.as_ref())
+            .map_err(|_| "Failed to decode pre-upgrade state.")?;
+
+        ensure!(
+            StorageVersion::<T>::get() == Version::V7_0_0,
+            "Storage version must be V7_0_0 at the end of migration."
+        );
+        ensure!(
+            !MigrationStateStorage::<T>::exists(),
+            "MigrationStateStorage has to be killed at the end of migration."
+        );
+        ensure!(
+            !PalletDisabled::<T>::get(),
+            "Pallet must be enabled at the end of migration."
+        );
+
+        // Values which cannot be decoded are skipped by the iterator.
+        ensure!(
+            GeneralEraInfo::<T>::iter_values().count() as u32 == era_infos,
+            "All era infos must be decodable."
+        );
+        ensure!(
+            ContractEraStake::<T>::iter_values().count() as u32 == contract_stakes,
+            "All contract stake infos must be decodable."
+        );
+        ensure!(
+            GeneralEraInfo::<T>::iter_values().all(|era_info| era_info.boost.is_zero()),
+            "Nothing can be boosted right after the migration."
+        );
+
+        Ok(())
+    }
+}
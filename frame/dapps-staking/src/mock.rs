@@ -10,17 +10,20 @@ use crate::{self as pallet_dapps_staking, weights};
 
 use frame_support::{
     construct_runtime, parameter_types,
-    traits::{Currency, OnFinalize, OnInitialize},
+    traits::{Currency, OnFinalize, OnInitialize, OnUnbalanced},
     weights::Weight,
     PalletId,
 };
+use pallet_balances::NegativeImbalance;
 use sp_core::{H160, H256};
 
 use codec::{Decode, Encode, MaxEncodedLen};
+use serde::{Deserialize, Serialize};
 use sp_io::TestExternalities;
 use sp_runtime::{
     testing::Header,
     traits::{BlakeTwo256, ConstU32, IdentityLookup},
+    Perbill,
 };
 
 pub(crate) type AccountId = u64;
@@ -41,6 +44,22 @@ pub(crate) const MAX_UNLOCKING_CHUNKS: u32 = 4;
 pub(crate) const UNBONDING_PERIOD: EraIndex = 3;
 pub(crate) const MAX_ERA_STAKE_VALUES: u32 = 8;
 pub(crate) const REWARD_RETENTION_PERIOD: u32 = 2;
+pub(crate) const MAX_BATCH_CLAIM_ERAS: u32 = 5;
+pub(crate) const MAX_SLASH_ERAS: u32 = 8;
+pub(crate) const MAX_BATCH_NOMINATION_TRANSFERS: u32 = 4;
+pub(crate) const MAX_CONTRACT_STAKE_HISTORY: u32 = 48;
+pub(crate) const ERA_HISTORY_DEPTH: u32 = 2;
+pub(crate) const STAKER_CLAIM_WINDOW: u32 = 40;
+pub(crate) const INSTANT_UNBOND_PENALTY: Perbill = Perbill::from_percent(10);
+pub(crate) const SHORT_STAKE_LOCK: (EraIndex, Perbill) = (3, Perbill::from_percent(20));
+pub(crate) const LONG_STAKE_LOCK: (EraIndex, Perbill) = (6, Perbill::from_percent(50));
+pub(crate) const MAX_STAKE_LOCKS: u32 = 3;
+pub(crate) const MAX_BOOST_EXPIRIES_PER_ERA: u32 = 2;
+pub(crate) const MAX_REREGISTRATIONS: u32 = 2;
+pub(crate) const METADATA_DEPOSIT_PER_BYTE: Balance = 2;
+pub(crate) const MAX_METADATA_FIELD_LENGTH: u32 = 16;
+/// Account which receives the expired staker rewards and instant unbond penalties.
+pub(crate) const TREASURY_ACCOUNT: AccountId = 9999;
 
 // Do note that this needs to at least be 3 for tests to be valid. It can be greater but not smaller.
 pub(crate) const BLOCKS_PER_ERA: BlockNumber = 3;
@@ -135,6 +154,9 @@ parameter_types! {
     pub const MaxUnlockingChunks: u32 = MAX_UNLOCKING_CHUNKS;
     pub const UnbondingPeriod: EraIndex = UNBONDING_PERIOD;
     pub const MaxEraStakeValues: u32 = MAX_ERA_STAKE_VALUES;
+    pub const InstantUnbondPenalty: Perbill = INSTANT_UNBOND_PENALTY;
+    pub const MetadataDepositPerByte: Balance = METADATA_DEPOSIT_PER_BYTE;
+    pub StakeLockOptions: Vec<(EraIndex, Perbill)> = vec![SHORT_STAKE_LOCK, LONG_STAKE_LOCK];
 }
 
 impl pallet_dapps_staking::Config for TestRuntime {
@@ -152,10 +174,47 @@ impl pallet_dapps_staking::Config for TestRuntime {
     type UnbondingPeriod = UnbondingPeriod;
     type MaxEraStakeValues = MaxEraStakeValues;
     type UnregisteredDappRewardRetention = ConstU32<REWARD_RETENTION_PERIOD>;
+    type MaxBatchClaimEras = ConstU32<MAX_BATCH_CLAIM_ERAS>;
+    type MaxSlashEras = ConstU32<MAX_SLASH_ERAS>;
+    type MaxBatchNominationTransfers = ConstU32<MAX_BATCH_NOMINATION_TRANSFERS>;
+    type MaxContractStakeHistory = ConstU32<MAX_CONTRACT_STAKE_HISTORY>;
+    type InstantUnbondPenalty = InstantUnbondPenalty;
+    type InstantUnbondPenaltyHandler = DepositToTreasury;
+    type StakerClaimWindow = ConstU32<STAKER_CLAIM_WINDOW>;
+    type ExpiredStakerRewards = DepositToTreasury;
+    type EraHistoryDepth = ConstU32<ERA_HISTORY_DEPTH>;
+    type StakeLockOptions = StakeLockOptions;
+    type MaxStakeLocks = ConstU32<MAX_STAKE_LOCKS>;
+    type MaxBoostExpiriesPerEra = ConstU32<MAX_BOOST_EXPIRIES_PER_ERA>;
+    type MaxReregistrations = ConstU32<MAX_REREGISTRATIONS>;
+    type MetadataDepositPerByte = MetadataDepositPerByte;
+    type MaxMetadataFieldLength = ConstU32<MAX_METADATA_FIELD_LENGTH>;
+    type RegisterOrigin = frame_system::EnsureRoot<AccountId>;
+    type UnregisterOrigin = frame_system::EnsureRoot<AccountId>;
+    type SlashOrigin = frame_system::EnsureRoot<AccountId>;
+    type SlashHandler = DepositToTreasury;
+    type MetadataOrigin = frame_system::EnsureRoot<AccountId>;
+}
+
+pub struct DepositToTreasury;
+impl OnUnbalanced<NegativeImbalance<TestRuntime>> for DepositToTreasury {
+    fn on_nonzero_unbalanced(amount: NegativeImbalance<TestRuntime>) {
+        Balances::resolve_creating(&TREASURY_ACCOUNT, amount);
+    }
 }
 
 #[derive(
-    PartialEq, Eq, Copy, Clone, Encode, Decode, Debug, scale_info::TypeInfo, MaxEncodedLen,
+    PartialEq,
+    Eq,
+    Copy,
+    Clone,
+    Encode,
+    Decode,
+    Debug,
+    scale_info::TypeInfo,
+    MaxEncodedLen,
+    Serialize,
+    Deserialize,
 )]
 pub enum MockSmartContract<AccountId> {
     Evm(sp_core::H160),
@@ -172,6 +231,13 @@ pub struct ExternalityBuilder;
 
 impl ExternalityBuilder {
     pub fn build() -> TestExternalities {
+        Self::build_with_genesis(None)
+    }
+
+    /// Builds externalities, optionally assimilating the provided dapps staking genesis config.
+    pub fn build_with_genesis(
+        dapps_staking_genesis: Option<pallet_dapps_staking::GenesisConfig<TestRuntime>>,
+    ) -> TestExternalities {
         let mut storage = frame_system::GenesisConfig::default()
             .build_storage::<TestRuntime>()
             .unwrap();
@@ -197,6 +263,10 @@ impl ExternalityBuilder {
         .assimilate_storage(&mut storage)
         .ok();
 
+        if let Some(genesis) = dapps_staking_genesis {
+            genesis.assimilate_storage(&mut storage).unwrap();
+        }
+
         let mut ext = TestExternalities::from(storage);
         ext.execute_with(|| System::set_block_number(1));
         ext
@@ -211,6 +281,8 @@ pub fn run_to_block(n: u64) {
         // This is performed outside of dapps staking but we expect it before on_initialize
         payout_block_rewards();
         DappsStaking::on_initialize(System::block_number());
+        // Storage invariants must hold after each block
+        DappsStaking::do_try_state().unwrap();
     }
 }
 
@@ -11,11 +11,11 @@
 use super::*;
 use frame_support::{
     dispatch::DispatchResult,
-    ensure,
+    ensure, log,
     pallet_prelude::*,
     traits::{
-        Currency, ExistenceRequirement, Get, Imbalance, LockIdentifier, LockableCurrency,
-        ReservableCurrency, WithdrawReasons,
+        BalanceStatus, Currency, EnsureOrigin, ExistenceRequirement, Get, Imbalance,
+        LockIdentifier, LockableCurrency, OnUnbalanced, ReservableCurrency, WithdrawReasons,
     },
     weights::Weight,
     PalletId,
@@ -25,7 +25,7 @@ use sp_runtime::{
     traits::{AccountIdConversion, Saturating, Zero},
     Perbill,
 };
-use sp_std::{convert::From, mem};
+use sp_std::{convert::From, mem, ops::Range};
 
 const STAKING_ID: LockIdentifier = *b"dapstake";
 
@@ -42,6 +42,21 @@ pub mod pallet {
     #[pallet::generate_store(pub(crate) trait Store)]
     pub struct Pallet<T>(PhantomData<T>);
 
+    /// Staker info type of this pallet, bounded by `MaxEraStakeValues`.
+    pub type StakerInfoOf<T> = StakerInfo<BalanceOf<T>, <T as Config>::MaxEraStakeValues>;
+
+    /// Account ledger type of this pallet, bounded by `MaxUnlockingChunks`.
+    pub type AccountLedgerOf<T> = AccountLedger<BalanceOf<T>, <T as Config>::MaxUnlockingChunks>;
+
+    /// Stake locks type of this pallet, bounded by `MaxStakeLocks`.
+    pub type StakeLocksOf<T> = BoundedVec<StakeLock<BalanceOf<T>>, <T as Config>::MaxStakeLocks>;
+
+    /// dApp metadata type of this pallet, bounded by `MaxMetadataFieldLength`.
+    pub type DAppMetadataOf<T> = DAppMetadata<<T as Config>::MaxMetadataFieldLength>;
+
+    /// Staking parameters type of this pallet.
+    pub type StakingParametersOf<T> = StakingParametersInfo<BlockNumberFor<T>, BalanceOf<T>>;
+
     // Negative imbalance type of this pallet.
     type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
         <T as frame_system::Config>::AccountId,
@@ -54,9 +69,10 @@ pub mod pallet {
             + ReservableCurrency<Self::AccountId>;
 
         /// Describes smart contract in the context required by dapps staking.
-        type SmartContract: Default + Parameter + Member + MaxEncodedLen;
+        type SmartContract: Default + Parameter + Member + MaxEncodedLen + MaybeSerializeDeserialize;
 
         /// Number of blocks per era.
+        /// Used until changed via `set_staking_parameters`.
         #[pallet::constant]
         type BlockPerEra: Get<BlockNumberFor<Self>>;
 
@@ -65,11 +81,13 @@ pub mod pallet {
         type RegisterDeposit: Get<BalanceOf<Self>>;
 
         /// Maximum number of unique stakers per contract.
+        /// Used until changed via `set_staking_parameters`.
         #[pallet::constant]
         type MaxNumberOfStakersPerContract: Get<u32>;
 
         /// Minimum amount user must have staked on contract.
         /// User can stake less if they already have the minimum staking amount staked on that particular contract.
+        /// Used until changed via `set_staking_parameters`.
         #[pallet::constant]
         type MinimumStakingAmount: Get<BalanceOf<Self>>;
 
@@ -90,6 +108,7 @@ pub mod pallet {
         /// Number of eras that need to pass until unstaked value can be withdrawn.
         /// Current era is always counted as full era (regardless how much blocks are remaining).
         /// When set to `0`, it's equal to having no unbonding period.
+        /// Used until changed via `set_staking_parameters`.
         #[pallet::constant]
         type UnbondingPeriod: Get<u32>;
 
@@ -106,9 +125,94 @@ pub mod pallet {
         /// Developer can still claim rewards after this period has passed, iff it hasn't been burned yet.
         ///
         /// For example, if retention is set to `2` and current era is `10`, it means that all unclaimed rewards bellow era `8` can be burned.
+        /// Used until changed via `set_staking_parameters`.
         #[pallet::constant]
         type UnregisteredDappRewardRetention: Get<u32>;
 
+        /// Max number of eras a staker can claim in a single `claim_staker_batch` call.
+        #[pallet::constant]
+        type MaxBatchClaimEras: Get<u32>;
+
+        /// Max number of eras for which dApp rewards can be slashed in a single `slash_dapp` call.
+        #[pallet::constant]
+        type MaxSlashEras: Get<u32>;
+
+        /// Max number of eras in which contract stake changed that are kept in the contract stake history.
+        /// History is trimmed when old eras are pruned, once their rewards have been claimed or have expired.
+        /// In case history is full, contract stake can't change until it's trimmed.
+        ///
+        /// Should be greater than `StakerClaimWindow`, so stakes can change in every era of the claim window.
+        #[pallet::constant]
+        type MaxContractStakeHistory: Get<u32>;
+
+        /// Max number of nomination transfers in a single `batch_nomination_transfer` call.
+        #[pallet::constant]
+        type MaxBatchNominationTransfers: Get<u32>;
+
+        /// Part of the value which is taken as a penalty when funds are unbonded instantly.
+        /// Used until changed via `set_staking_parameters`.
+        #[pallet::constant]
+        type InstantUnbondPenalty: Get<Perbill>;
+
+        /// Handler for the instant unbonding penalties, e.g. treasury. Penalties are burned if `()` is used.
+        type InstantUnbondPenaltyHandler: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+        /// Number of the latest eras for which stakers can claim rewards.
+        /// Staker rewards of the older eras expire and are swept to `ExpiredStakerRewards`.
+        #[pallet::constant]
+        type StakerClaimWindow: Get<u32>;
+
+        /// Handler for the expired staker rewards, e.g. treasury. Rewards are burned if `()` is used.
+        type ExpiredStakerRewards: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+        /// Number of the latest eras for which era data is never pruned.
+        /// Older era data is pruned in `on_idle` once no rewards can be claimed for it anymore.
+        #[pallet::constant]
+        type EraHistoryDepth: Get<u32>;
+
+        /// Lock durations (in eras) which stakers can choose from when staking via `bond_and_stake_with_lock`,
+        /// together with the additional reward weight of the locked stake.
+        ///
+        /// E.g. `(30, Perbill::from_percent(50))` means that stake locked for 30 eras has 1.5x reward weight.
+        #[pallet::constant]
+        type StakeLockOptions: Get<Vec<(EraIndex, Perbill)>>;
+
+        /// Max number of stake locks per account Id <-> contract Id pairing.
+        /// Stake locks are removed once they've expired and all rewards for the locked eras have been claimed.
+        #[pallet::constant]
+        type MaxStakeLocks: Get<u32>;
+
+        /// Max number of contracts whose stake locks can expire in a single era.
+        #[pallet::constant]
+        type MaxBoostExpiriesPerEra: Get<u32>;
+
+        /// Max number of times a contract can be re-registered after being unregistered.
+        #[pallet::constant]
+        type MaxReregistrations: Get<u32>;
+
+        /// Deposit reserved from the developer per byte of the dApp metadata.
+        #[pallet::constant]
+        type MetadataDepositPerByte: Get<BalanceOf<Self>>;
+
+        /// Max length, in bytes, of each text field of the dApp metadata.
+        #[pallet::constant]
+        type MaxMetadataFieldLength: Get<u32>;
+
+        /// Origin that can register a contract on behalf of any developer account.
+        type RegisterOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Origin that can unregister any registered contract.
+        type UnregisterOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Origin that can slash a misbehaving dApp, e.g. governance.
+        type SlashOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Handler for the slashed developer rewards and register deposits, e.g. treasury. Slashed funds are burned if `()` is used.
+        type SlashHandler: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+        /// Origin that can clear the metadata of any dApp, e.g. governance.
+        type MetadataOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
         /// The overarching event type.
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
@@ -126,7 +230,12 @@ pub mod pallet {
     #[pallet::storage]
     #[pallet::getter(fn ledger)]
     pub type Ledger<T: Config> =
-        StorageMap<_, Blake2_128Concat, T::AccountId, AccountLedger<BalanceOf<T>>, ValueQuery>;
+        StorageMap<_, Blake2_128Concat, T::AccountId, AccountLedgerOf<T>, ValueQuery>;
+
+    /// Operator accounts, which can manage the stake on behalf of the staker without being able to withdraw it.
+    #[pallet::storage]
+    #[pallet::getter(fn operator)]
+    pub type Operators<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId>;
 
     /// The current era index.
     #[pallet::storage]
@@ -150,6 +259,30 @@ pub mod pallet {
     #[pallet::getter(fn force_era)]
     pub type ForceEra<T> = StorageValue<_, Forcing, ValueQuery, ForceEraOnEmpty>;
 
+    #[pallet::type_value]
+    pub fn DefaultStakingParameters<T: Config>() -> StakingParametersOf<T> {
+        StakingParametersInfo {
+            block_per_era: T::BlockPerEra::get(),
+            minimum_staking_amount: T::MinimumStakingAmount::get(),
+            unbonding_period: T::UnbondingPeriod::get(),
+            max_number_of_stakers_per_contract: T::MaxNumberOfStakersPerContract::get(),
+            unregistered_dapp_reward_retention: T::UnregisteredDappRewardRetention::get(),
+            instant_unbond_penalty: T::InstantUnbondPenalty::get(),
+        }
+    }
+
+    /// Staking parameters in use. Config values are used until parameters are changed for the first time.
+    #[pallet::storage]
+    #[pallet::whitelist_storage]
+    #[pallet::getter(fn staking_parameters)]
+    pub type StakingParameters<T: Config> =
+        StorageValue<_, StakingParametersOf<T>, ValueQuery, DefaultStakingParameters<T>>;
+
+    /// Staking parameters which will be used from the start of the next era.
+    #[pallet::storage]
+    #[pallet::getter(fn pending_staking_parameters)]
+    pub type PendingStakingParameters<T: Config> = StorageValue<_, StakingParametersOf<T>>;
+
     /// Stores the block number of when the next era starts
     #[pallet::storage]
     #[pallet::whitelist_storage]
@@ -168,6 +301,62 @@ pub mod pallet {
     pub(crate) type RegisteredDapps<T: Config> =
         StorageMap<_, Blake2_128Concat, T::SmartContract, DAppInfo<T::AccountId>>;
 
+    /// Pending ownership transfers, where smart contract points to the proposed new developer account.
+    #[pallet::storage]
+    #[pallet::getter(fn pending_ownership_transfer)]
+    pub type PendingOwnershipTransfer<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::SmartContract, T::AccountId>;
+
+    /// Eras in which a re-registered contract was unregistered, as `(unregistered era, re-registered era)` pairs.
+    /// No rewards can be claimed for these eras.
+    #[pallet::storage]
+    #[pallet::getter(fn unregistered_periods)]
+    pub type UnregisteredPeriods<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::SmartContract,
+        BoundedVec<(EraIndex, EraIndex), T::MaxReregistrations>,
+        ValueQuery,
+    >;
+
+    /// Contracts which were frozen via `slash_dapp`, together with the era in which they were frozen.
+    /// Frozen contracts can't be staked on, but existing stakes can still be unstaked.
+    /// Freezing is permanent, contract remains frozen even if it's unregistered and registered again.
+    #[pallet::storage]
+    #[pallet::getter(fn frozen_dapps)]
+    pub type FrozenDapps<T: Config> = StorageMap<_, Blake2_128Concat, T::SmartContract, EraIndex>;
+
+    /// Part of the contract's `RegisterDeposit` which was slashed via `slash_dapp`, and is no longer reserved.
+    #[pallet::storage]
+    #[pallet::getter(fn slashed_deposit)]
+    pub type SlashedDeposits<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::SmartContract, BalanceOf<T>, ValueQuery>;
+
+    /// Metadata of a registered dApp, together with the deposit reserved for it from the developer.
+    #[pallet::storage]
+    #[pallet::getter(fn dapp_metadata)]
+    pub type DappMetadata<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::SmartContract, (DAppMetadataOf<T>, BalanceOf<T>)>;
+
+    /// Latest commission setting of a dApp, i.e. part of the developer reward shared with the contract stakers.
+    ///
+    /// Use `dapp_commission_at` to get the commission which applies to an arbitrary era.
+    #[pallet::storage]
+    #[pallet::getter(fn dapp_commission)]
+    pub type DappCommission<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::SmartContract, DAppCommission>;
+
+    /// Links the commission setting which applies from the given era to the setting it replaced.
+    #[pallet::storage]
+    pub type DappCommissionHistory<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::SmartContract,
+        Twox64Concat,
+        EraIndex,
+        DAppCommission,
+    >;
+
     /// General information about an era like TVL, total staked value, rewards.
     #[pallet::storage]
     #[pallet::getter(fn general_era_info)]
@@ -175,8 +364,10 @@ pub mod pallet {
         StorageMap<_, Twox64Concat, EraIndex, EraInfo<BalanceOf<T>>>;
 
     /// Staking information about contract in a particular era.
+    ///
+    /// Entries are only written for eras in which contract stake changed (or reward was claimed),
+    /// use `contract_stake_info` to get the info for an arbitrary era.
     #[pallet::storage]
-    #[pallet::getter(fn contract_stake_info)]
     pub type ContractEraStake<T: Config> = StorageDoubleMap<
         _,
         Blake2_128Concat,
@@ -186,6 +377,18 @@ pub mod pallet {
         ContractStakeInfo<BalanceOf<T>>,
     >;
 
+    /// Eras in which contract stake changed, i.e. eras for which `ContractEraStake` entry was written, in ascending order.
+    /// Used to find the stake info for eras in which contract stake didn't change.
+    #[pallet::storage]
+    #[pallet::getter(fn contract_stake_history)]
+    pub type ContractStakeHistory<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::SmartContract,
+        BoundedVec<EraIndex, T::MaxContractStakeHistory>,
+        ValueQuery,
+    >;
+
     /// Info about stakers stakes on particular contracts.
     #[pallet::storage]
     #[pallet::getter(fn staker_info)]
@@ -195,20 +398,60 @@ pub mod pallet {
         T::AccountId,
         Blake2_128Concat,
         T::SmartContract,
-        StakerInfo<BalanceOf<T>>,
+        StakerInfoOf<T>,
+        ValueQuery,
+    >;
+
+    /// Time-locked stakes of a staker on a particular contract.
+    #[pallet::storage]
+    #[pallet::getter(fn stake_locks)]
+    pub type StakeLocks<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::SmartContract,
+        StakeLocksOf<T>,
+        ValueQuery,
+    >;
+
+    /// Reward weight boosts which expire at the start of an era, per contract.
+    #[pallet::storage]
+    #[pallet::getter(fn boost_expiries)]
+    pub type BoostExpiries<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        EraIndex,
+        BoundedVec<(T::SmartContract, BalanceOf<T>), T::MaxBoostExpiriesPerEra>,
         ValueQuery,
     >;
 
+    /// Progress of the era history pruning, done in `on_idle`.
+    #[pallet::storage]
+    #[pallet::getter(fn era_pruning)]
+    pub type EraPruning<T: Config> =
+        StorageValue<_, EraPruningInfo<T::AccountId, T::SmartContract>, ValueQuery>;
+
     /// Stores the current pallet storage version.
     #[pallet::storage]
     #[pallet::getter(fn storage_version)]
     pub(crate) type StorageVersion<T> = StorageValue<_, Version, ValueQuery>;
 
+    /// Progress of the ongoing multi-block storage migration.
+    #[pallet::storage]
+    #[pallet::getter(fn migration_state)]
+    pub(crate) type MigrationStateStorage<T> =
+        StorageValue<_, migrations::MigrationState, ValueQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(crate) fn deposit_event)]
     pub enum Event<T: Config> {
         /// Account has bonded and staked funds on a smart contract.
         BondAndStake(T::AccountId, T::SmartContract, BalanceOf<T>),
+        /// Account has bonded and staked funds on a smart contract, locking them until the specified era.
+        ///
+        /// \(staker account, smart contract, amount, unlock era\)
+        BondAndStakeWithLock(T::AccountId, T::SmartContract, BalanceOf<T>, EraIndex),
         /// Account has unbonded & unstaked some funds. Unbonding process begins.
         UnbondAndUnstake(T::AccountId, T::SmartContract, BalanceOf<T>),
         /// Account has fully withdrawn all staked amount from an unregistered contract.
@@ -219,6 +462,8 @@ pub mod pallet {
         NewContract(T::AccountId, T::SmartContract),
         /// Contract removed from dapps staking.
         ContractRemoved(T::AccountId, T::SmartContract),
+        /// Previously unregistered contract has been registered for dapps staking again.
+        ContractReregistered(T::AccountId, T::SmartContract),
         /// New dapps staking era. Distribute era rewards to contracts.
         NewDappStakingEra(EraIndex),
         /// Reward paid to staker or developer.
@@ -240,6 +485,90 @@ pub mod pallet {
         ///
         /// \(developer account, smart contract, era, amount burned\)
         StaleRewardBurned(T::AccountId, T::SmartContract, EraIndex, BalanceOf<T>),
+        /// Staker has claimed rewards for multiple eras in a single call.
+        /// Reward for each individual era is reported via a separate `Reward` event.
+        ///
+        /// \(staker account, smart contract, number of claimed eras, total reward\)
+        BatchRewardClaimed(T::AccountId, T::SmartContract, u32, BalanceOf<T>),
+        /// Developer has proposed to transfer contract ownership to another account.
+        ///
+        /// \(current developer account, smart contract, proposed developer account\)
+        OwnershipTransferProposed(T::AccountId, T::SmartContract, T::AccountId),
+        /// Contract ownership has been transferred to a new developer account.
+        ///
+        /// \(previous developer account, smart contract, new developer account\)
+        OwnershipTransferred(T::AccountId, T::SmartContract, T::AccountId),
+        /// Account has moved funds from unlocking chunks back into stake on a smart contract.
+        Rebonded(T::AccountId, T::SmartContract, BalanceOf<T>),
+        /// New staking parameters have been set, they will be used from the start of the next era.
+        StakingParametersScheduled(StakingParametersOf<T>),
+        /// Account has unbonded & unstaked some funds, which were unlocked immediately for a penalty.
+        ///
+        /// \(staker account, smart contract, unstaked amount, penalty\)
+        InstantUnbondAndUnstake(T::AccountId, T::SmartContract, BalanceOf<T>, BalanceOf<T>),
+        /// Account has withdrawn funds undergoing the unbonding process immediately, for a penalty.
+        ///
+        /// \(staker account, withdrawn amount, penalty\)
+        InstantWithdrawn(T::AccountId, BalanceOf<T>, BalanceOf<T>),
+        /// Staker reward for an era has expired and was swept to the expired rewards handler.
+        ///
+        /// \(staker account, smart contract, era, amount\)
+        StakerRewardExpired(T::AccountId, T::SmartContract, EraIndex, BalanceOf<T>),
+        /// Era data which can no longer be used to claim rewards has been pruned.
+        ///
+        /// \(era before which data was pruned, number of removed era info entries, number of removed contract stake entries\)
+        EraHistoryPruned(EraIndex, u32, u32),
+        /// Developer has changed the part of the dApp reward shared with the contract stakers.
+        ///
+        /// \(developer account, smart contract, commission, era from which it applies\)
+        DappCommissionChanged(T::AccountId, T::SmartContract, Perbill, EraIndex),
+        /// dApp reward has been split between the developer and the contract stakers.
+        /// Emitted after the `Reward` event which holds the developer part.
+        ///
+        /// \(developer account, smart contract, era, developer reward, reward shared with stakers\)
+        DappRewardSplit(
+            T::AccountId,
+            T::SmartContract,
+            EraIndex,
+            BalanceOf<T>,
+            BalanceOf<T>,
+        ),
+        /// Staker reward contains a part of the dApp reward shared by the developer.
+        /// Emitted after the `Reward` event which holds the total amount.
+        ///
+        /// \(staker account, smart contract, era, staking reward, commission reward\)
+        StakerRewardSplit(
+            T::AccountId,
+            T::SmartContract,
+            EraIndex,
+            BalanceOf<T>,
+            BalanceOf<T>,
+        ),
+        /// Part of the unclaimed developer reward for an era has been slashed.
+        /// Remaining reward is paid out to the developer, and reported via the `Reward` event.
+        ///
+        /// \(developer account, smart contract, era, slashed amount\)
+        DappRewardSlashed(T::AccountId, T::SmartContract, EraIndex, BalanceOf<T>),
+        /// Register deposit of a dApp has been slashed.
+        ///
+        /// \(developer account, smart contract, slashed amount\)
+        DappDepositSlashed(T::AccountId, T::SmartContract, BalanceOf<T>),
+        /// dApp has been frozen, it can no longer be staked on.
+        DappFrozen(T::SmartContract),
+        /// Developer has set the dApp metadata.
+        ///
+        /// \(developer account, smart contract, reserved metadata deposit\)
+        DappMetadataSet(T::AccountId, T::SmartContract, BalanceOf<T>),
+        /// dApp metadata has been cleared and its deposit returned to the developer.
+        DappMetadataCleared(T::SmartContract),
+        /// Staker has granted the operator role to an account.
+        ///
+        /// \(staker account, operator account\)
+        OperatorSet(T::AccountId, T::AccountId),
+        /// Staker has revoked the operator role from an account.
+        ///
+        /// \(staker account, operator account\)
+        OperatorRemoved(T::AccountId, T::AccountId),
     }
 
     #[pallet::error]
@@ -292,6 +621,108 @@ pub mod pallet {
         NotActiveStaker,
         /// Transfering nomination to the same contract
         NominationTransferToSameContract,
+        /// Number of eras to claim must be greater than zero and not exceed `MaxBatchClaimEras`.
+        InvalidNumberOfEras,
+        /// There is no pending ownership transfer of the contract to the account.
+        NoPendingOwnershipTransfer,
+        /// There are no unlocking chunks which could be rebonded.
+        NothingToRebond,
+        /// Staking parameters would prevent pallet from working correctly.
+        InvalidStakingParameters,
+        /// Staker reward for the era has expired and can no longer be claimed.
+        RewardExpired,
+        /// Lock duration isn't one of the `StakeLockOptions`.
+        InvalidStakeLockDuration,
+        /// Too many stake locks for (staker, contract) pairing.
+        /// Claim rewards for the eras in which the expired stakes were locked to fix this problem.
+        TooManyStakeLocks,
+        /// Too many contracts have stake locks expiring in the same era. Choose another lock duration or try in the next era.
+        TooManyBoostExpiries,
+        /// Value can't be unstaked since it's locked on the contract.
+        StakeLocked,
+        /// Contract has been frozen and can no longer be staked on.
+        DappFrozen,
+        /// Slash must forfeit some reward, slash the deposit or freeze the contract.
+        NothingToSlash,
+        /// Contract has already been re-registered `MaxReregistrations` times.
+        TooManyReregistrations,
+        /// dApp has no metadata set.
+        NoDappMetadata,
+        /// Staker has no operator set.
+        NoOperator,
+        /// Caller isn't the operator of the staker.
+        NotOperator,
+        /// Number of nomination transfers must be greater than zero.
+        InvalidNumberOfNominationTransfers,
+        /// Staker can't be its own operator.
+        OperatorIsStaker,
+        /// Contract stake history is full, so contract stake can't change until old eras are claimed and pruned.
+        ContractStakeHistoryFull,
+        /// Number of eras to slash must not exceed `MaxSlashEras`.
+        InvalidNumberOfSlashEras,
+    }
+
+    impl<T: Config> From<StakerInfoError> for Error<T> {
+        fn from(error: StakerInfoError) -> Self {
+            match error {
+                StakerInfoError::UnexpectedEra => Error::<T>::UnexpectedStakeInfoEra,
+                StakerInfoError::TooManyEraStakeValues => Error::<T>::TooManyEraStakeValues,
+            }
+        }
+    }
+
+    #[pallet::genesis_config]
+    pub struct GenesisConfig<T: Config> {
+        /// Era in which dApps staking starts, stakes are registered for it.
+        pub current_era: EraIndex,
+        /// `(developer, contract)` pairs of dApps registered for staking.
+        /// `RegisterDeposit` is reserved from the developer.
+        pub dapps: Vec<(T::AccountId, T::SmartContract)>,
+        /// `(staker, contract, amount)` stakes on the registered dApps.
+        /// Same rules as for `bond_and_stake` apply.
+        pub stakes: Vec<(T::AccountId, T::SmartContract, BalanceOf<T>)>,
+        /// `(staker, reward destination)` pairs, staker must have a stake.
+        pub reward_destinations: Vec<(T::AccountId, RewardDestination)>,
+    }
+
+    #[cfg(feature = "std")]
+    impl<T: Config> Default for GenesisConfig<T> {
+        fn default() -> Self {
+            Self {
+                current_era: Default::default(),
+                dapps: Default::default(),
+                stakes: Default::default(),
+                reward_destinations: Default::default(),
+            }
+        }
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+        fn build(&self) {
+            CurrentEra::<T>::put(self.current_era);
+            GeneralEraInfo::<T>::insert(self.current_era, EraInfo::default());
+
+            for (developer, contract_id) in &self.dapps {
+                Pallet::<T>::do_register(developer.clone(), contract_id.clone())
+                    .expect("genesis dApp registration must be valid");
+            }
+
+            for (staker, contract_id, value) in &self.stakes {
+                Pallet::<T>::do_bond_and_stake(staker.clone(), contract_id.clone(), *value, None)
+                    .expect("genesis stake must be valid");
+            }
+
+            for (staker, reward_destination) in &self.reward_destinations {
+                let mut ledger = Pallet::<T>::ledger(staker);
+                assert!(
+                    !ledger.is_empty(),
+                    "genesis reward destination set for an account without stake"
+                );
+                ledger.reward_destination = *reward_destination;
+                Ledger::<T>::insert(staker, ledger);
+            }
+        }
     }
 
     #[pallet::hooks]
@@ -312,7 +743,12 @@ pub mod pallet {
 
             // Value is compared to 1 since genesis block is ignored
             if now >= next_era_starting_block || force_new_era || previous_era.is_zero() {
-                let blocks_per_era = T::BlockPerEra::get();
+                // Changed parameters only take effect at era boundary
+                if let Some(staking_parameters) = PendingStakingParameters::<T>::take() {
+                    StakingParameters::<T>::put(staking_parameters);
+                }
+
+                let blocks_per_era = Self::staking_parameters().block_per_era;
                 let next_era = previous_era + 1;
                 CurrentEra::<T>::put(next_era);
 
@@ -320,7 +756,7 @@ pub mod pallet {
 
                 let reward = BlockRewardAccumulator::<T>::take();
                 Self::reward_balance_snapshot(previous_era, reward);
-                let consumed_weight = Self::rotate_staking_info(previous_era);
+                let expired_boosts = Self::expire_boosts(next_era);
 
                 if force_new_era {
                     ForceEra::<T>::put(Forcing::NotForcing);
@@ -328,19 +764,37 @@ pub mod pallet {
 
                 Self::deposit_event(Event::<T>::NewDappStakingEra(next_era));
 
-                consumed_weight + T::DbWeight::get().reads_writes(5, 3)
+                T::DbWeight::get().reads_writes(8, 7).saturating_add(
+                    T::DbWeight::get()
+                        .reads_writes(3, 3)
+                        .saturating_mul(expired_boosts.into()),
+                )
             } else {
                 T::DbWeight::get().reads(4)
             }
         }
+
+        fn on_idle(_now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::prune_era_history(remaining_weight)
+        }
+
+        fn integrity_test() {
+            // Unbonding isn't possible without any unlocking chunks, and migrated ledgers need one for the merged chunks
+            assert!(T::MaxUnlockingChunks::get() > 0);
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), &'static str> {
+            Self::do_try_state()
+        }
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Used to register contract for dapps staking.
-        /// The origin account used is treated as the `developer` account.
+        /// The `developer` account is set as the contract owner.
         ///
-        /// Depending on the pallet configuration/state it is possible that developer needs to be whitelisted prior to registration.
+        /// The dispatch origin must be `RegisterOrigin`. Developers can register their own contracts via `self_register`.
         ///
         /// As part of this call, `RegisterDeposit` will be reserved from devs account.
         #[pallet::call_index(0)]
@@ -351,33 +805,21 @@ pub mod pallet {
             contract_id: T::SmartContract,
         ) -> DispatchResultWithPostInfo {
             Self::ensure_pallet_enabled()?;
-            ensure_root(origin)?;
+            T::RegisterOrigin::ensure_origin(origin)?;
 
-            ensure!(
-                !RegisteredDevelopers::<T>::contains_key(&developer),
-                Error::<T>::AlreadyUsedDeveloperAccount,
-            );
-            ensure!(
-                !RegisteredDapps::<T>::contains_key(&contract_id),
-                Error::<T>::AlreadyRegisteredContract,
-            );
-
-            T::Currency::reserve(&developer, T::RegisterDeposit::get())?;
-
-            RegisteredDapps::<T>::insert(contract_id.clone(), DAppInfo::new(developer.clone()));
-            RegisteredDevelopers::<T>::insert(&developer, contract_id.clone());
-
-            Self::deposit_event(Event::<T>::NewContract(developer, contract_id));
+            Self::do_register(developer, contract_id)?;
 
             Ok(().into())
         }
 
         /// Unregister existing contract from dapps staking, making it ineligible for rewards from current era onwards.
-        /// This must be called by the root (at the moment).
+        ///
+        /// The dispatch origin must be `UnregisterOrigin`. Developers can unregister their own contracts via `self_unregister`.
         ///
         /// Deposit is returned to the developer but existing stakers should manually call `withdraw_from_unregistered` if they wish to to unstake.
+        /// dApp metadata is removed and its deposit returned as well.
         ///
-        /// **Warning**: After this action ,contract can not be registered for dapps staking again.
+        /// **Warning**: After this action, contract can only be registered for dapps staking again via `reregister`.
         #[pallet::call_index(1)]
         #[pallet::weight(T::WeightInfo::unregister())]
         pub fn unregister(
@@ -385,23 +827,11 @@ pub mod pallet {
             contract_id: T::SmartContract,
         ) -> DispatchResultWithPostInfo {
             Self::ensure_pallet_enabled()?;
-            ensure_root(origin)?;
+            T::UnregisterOrigin::ensure_origin(origin)?;
 
-            let mut dapp_info =
+            let dapp_info =
                 RegisteredDapps::<T>::get(&contract_id).ok_or(Error::<T>::NotOperatedContract)?;
-            ensure!(
-                dapp_info.state == DAppState::Registered,
-                Error::<T>::NotOperatedContract
-            );
-            let developer = dapp_info.developer.clone();
-
-            let current_era = Self::current_era();
-            dapp_info.state = DAppState::Unregistered(current_era);
-            RegisteredDapps::<T>::insert(&contract_id, dapp_info);
-
-            T::Currency::unreserve(&developer, T::RegisterDeposit::get());
-
-            Self::deposit_event(Event::<T>::ContractRemoved(developer, contract_id));
+            Self::do_unregister(contract_id, dapp_info)?;
 
             Ok(().into())
         }
@@ -447,11 +877,23 @@ pub mod pallet {
 
             Self::update_staker_info(&staker, &contract_id, Default::default());
 
+            // Stake locks no longer apply since the contract can't be staked on anymore
             let current_era = Self::current_era();
+            let released_boost = Self::release_stake_locks(&staker, &contract_id, current_era);
+
+            // Withdrawn stake mustn't be counted in case contract is re-registered
+            let mut staking_info =
+                Self::contract_stake_info(&contract_id, current_era).unwrap_or_default();
+            staking_info.total = staking_info.total.saturating_sub(staked_value);
+            staking_info.number_of_stakers = staking_info.number_of_stakers.saturating_sub(1);
+            staking_info.boost = staking_info.boost.saturating_sub(released_boost);
+            Self::update_contract_stake_info(&contract_id, current_era, staking_info)?;
+
             GeneralEraInfo::<T>::mutate(&current_era, |value| {
                 if let Some(x) = value {
                     x.staked = x.staked.saturating_sub(staked_value);
                     x.locked = x.locked.saturating_sub(staked_value);
+                    x.boost = x.boost.saturating_sub(released_boost);
                 }
             });
 
@@ -480,52 +922,7 @@ pub mod pallet {
             Self::ensure_pallet_enabled()?;
             let staker = ensure_signed(origin)?;
 
-            // Check that contract is ready for staking.
-            ensure!(
-                Self::is_active(&contract_id),
-                Error::<T>::NotOperatedContract
-            );
-
-            // Get the staking ledger or create an entry if it doesn't exist.
-            let mut ledger = Self::ledger(&staker);
-            let available_balance = Self::available_staking_balance(&staker, &ledger);
-            let value_to_stake = value.min(available_balance);
-            ensure!(
-                value_to_stake > Zero::zero(),
-                Error::<T>::StakingWithNoValue
-            );
-
-            let current_era = Self::current_era();
-            let mut staking_info =
-                Self::contract_stake_info(&contract_id, current_era).unwrap_or_default();
-            let mut staker_info = Self::staker_info(&staker, &contract_id);
-
-            Self::stake_on_contract(
-                &mut staker_info,
-                &mut staking_info,
-                value_to_stake,
-                current_era,
-            )?;
-
-            ledger.locked = ledger.locked.saturating_add(value_to_stake);
-
-            // Update storage
-            GeneralEraInfo::<T>::mutate(&current_era, |value| {
-                if let Some(x) = value {
-                    x.staked = x.staked.saturating_add(value_to_stake);
-                    x.locked = x.locked.saturating_add(value_to_stake);
-                }
-            });
-
-            Self::update_ledger(&staker, ledger);
-            Self::update_staker_info(&staker, &contract_id, staker_info);
-            ContractEraStake::<T>::insert(&contract_id, current_era, staking_info);
-
-            Self::deposit_event(Event::<T>::BondAndStake(
-                staker,
-                contract_id,
-                value_to_stake,
-            ));
+            Self::do_bond_and_stake(staker, contract_id, value, None)?;
             Ok(().into())
         }
 
@@ -537,6 +934,8 @@ pub mod pallet {
         ///
         /// In case remaining staked balance on contract is below minimum staking amount,
         /// entire stake for that contract will be unstaked.
+        ///
+        /// Stake which is still locked via `bond_and_stake_with_lock` can't be unstaked.
         #[pallet::call_index(4)]
         #[pallet::weight(T::WeightInfo::unbond_and_unstake())]
         pub fn unbond_and_unstake(
@@ -564,18 +963,22 @@ pub mod pallet {
                 value,
                 current_era,
             )?;
+            ensure!(
+                staker_info.latest_staked_value()
+                    >= Self::locked_stake(&staker, &contract_id, current_era),
+                Error::<T>::StakeLocked
+            );
 
             // Update the chunks and write them to storage
             let mut ledger = Self::ledger(&staker);
-            ledger.unbonding_info.add(UnlockingChunk {
-                amount: value_to_unstake,
-                unlock_era: current_era + T::UnbondingPeriod::get(),
-            });
-            // This should be done AFTER insertion since it's possible for chunks to merge
-            ensure!(
-                ledger.unbonding_info.len() <= T::MaxUnlockingChunks::get(),
-                Error::<T>::TooManyUnlockingChunks
-            );
+            // It's possible for chunks to merge, in which case no additional space is required
+            ledger
+                .unbonding_info
+                .add(UnlockingChunk {
+                    amount: value_to_unstake,
+                    unlock_era: current_era + Self::staking_parameters().unbonding_period,
+                })
+                .map_err(|_| Error::<T>::TooManyUnlockingChunks)?;
 
             Self::update_ledger(&staker, ledger);
 
@@ -586,7 +989,7 @@ pub mod pallet {
                 }
             });
             Self::update_staker_info(&staker, &contract_id, staker_info);
-            ContractEraStake::<T>::insert(&contract_id, current_era, contract_stake_info);
+            Self::update_contract_stake_info(&contract_id, current_era, contract_stake_info)?;
 
             Self::deposit_event(Event::<T>::UnbondAndUnstake(
                 staker,
@@ -631,179 +1034,153 @@ pub mod pallet {
             Ok(().into())
         }
 
-        /// Transfer nomination from one contract to another.
+        /// Unstake balance from the contract and unlock it immediately, without waiting for the unbonding period.
         ///
-        /// Same rules as for `bond_and_stake` and `unbond_and_unstake` apply.
-        /// Minor difference is that there is no unbonding period so this call won't
-        /// check whether max number of unbonding chunks is exceeded.
+        /// Part of the unstaked value, defined by the instant unbond penalty staking parameter, is taken from the staker
+        /// and handed over to `InstantUnbondPenaltyHandler`.
         ///
-        #[pallet::call_index(6)]
-        #[pallet::weight(T::WeightInfo::nomination_transfer())]
-        pub fn nomination_transfer(
+        /// Same as for `unbond_and_unstake`, in case remaining staked balance on contract is below minimum staking amount,
+        /// entire stake for that contract will be unstaked.
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::WeightInfo::instant_unbond_and_unstake())]
+        pub fn instant_unbond_and_unstake(
             origin: OriginFor<T>,
-            origin_contract_id: T::SmartContract,
+            contract_id: T::SmartContract,
             #[pallet::compact] value: BalanceOf<T>,
-            target_contract_id: T::SmartContract,
         ) -> DispatchResultWithPostInfo {
             Self::ensure_pallet_enabled()?;
             let staker = ensure_signed(origin)?;
 
-            // Contracts must differ and both must be active
-            ensure!(
-                origin_contract_id != target_contract_id,
-                Error::<T>::NominationTransferToSameContract
-            );
-            ensure!(
-                Self::is_active(&origin_contract_id),
-                Error::<T>::NotOperatedContract
-            );
+            ensure!(value > Zero::zero(), Error::<T>::UnstakingWithNoValue);
             ensure!(
-                Self::is_active(&target_contract_id),
-                Error::<T>::NotOperatedContract
+                Self::is_active(&contract_id),
+                Error::<T>::NotOperatedContract,
             );
 
-            // Validate origin contract related data & update it
             let current_era = Self::current_era();
-            let mut origin_staker_info = Self::staker_info(&staker, &origin_contract_id);
-            let mut origin_staking_info =
-                Self::contract_stake_info(&origin_contract_id, current_era).unwrap_or_default();
+            let mut staker_info = Self::staker_info(&staker, &contract_id);
+            let mut contract_stake_info =
+                Self::contract_stake_info(&contract_id, current_era).unwrap_or_default();
 
-            let origin_to_target_transfer_value = Self::unstake_from_contract(
-                &mut origin_staker_info,
-                &mut origin_staking_info,
+            let value_to_unstake = Self::unstake_from_contract(
+                &mut staker_info,
+                &mut contract_stake_info,
                 value,
                 current_era,
             )?;
+            ensure!(
+                staker_info.latest_staked_value()
+                    >= Self::locked_stake(&staker, &contract_id, current_era),
+                Error::<T>::StakeLocked
+            );
 
-            // Validate target contract related data & update it
-            let mut target_staker_info = Self::staker_info(&staker, &target_contract_id);
-            let mut target_staking_info =
-                Self::contract_stake_info(&target_contract_id, current_era).unwrap_or_default();
-
-            Self::stake_on_contract(
-                &mut target_staker_info,
-                &mut target_staking_info,
-                origin_to_target_transfer_value,
-                current_era,
-            )?;
+            // Unstaked funds are unlocked immediately, without going through unlocking chunks
+            let mut ledger = Self::ledger(&staker);
+            ledger.locked = ledger.locked.saturating_sub(value_to_unstake);
+            Self::update_ledger(&staker, ledger);
 
-            // Update origin data
-            ContractEraStake::<T>::insert(&origin_contract_id, current_era, origin_staking_info);
-            Self::update_staker_info(&staker, &origin_contract_id, origin_staker_info);
+            let penalty = Self::take_instant_unbond_penalty(&staker, value_to_unstake)?;
 
-            // Update target data
-            ContractEraStake::<T>::insert(&target_contract_id, current_era, target_staking_info);
-            Self::update_staker_info(&staker, &target_contract_id, target_staker_info);
+            GeneralEraInfo::<T>::mutate(&current_era, |value| {
+                if let Some(x) = value {
+                    x.staked = x.staked.saturating_sub(value_to_unstake);
+                    x.locked = x.locked.saturating_sub(value_to_unstake);
+                }
+            });
+            Self::update_staker_info(&staker, &contract_id, staker_info);
+            Self::update_contract_stake_info(&contract_id, current_era, contract_stake_info)?;
 
-            Self::deposit_event(Event::<T>::NominationTransfer(
+            Self::deposit_event(Event::<T>::InstantUnbondAndUnstake(
                 staker,
-                origin_contract_id,
-                origin_to_target_transfer_value,
-                target_contract_id,
+                contract_id,
+                value_to_unstake,
+                penalty,
             ));
 
             Ok(().into())
         }
 
-        // TODO: do we need to add force methods or at least methods that allow others to claim for someone else?
-
-        /// Claim earned staker rewards for the oldest unclaimed era.
-        /// In order to claim multiple eras, this call has to be called multiple times.
+        /// Withdraw all funds undergoing the unbonding process immediately, including the ones
+        /// which haven't completed the unbonding period yet.
         ///
-        /// The rewards are always added to the staker's free balance (account) but depending on the reward destination configuration,
-        /// they might be immediately re-staked.
-        #[pallet::call_index(7)]
-        #[pallet::weight(T::WeightInfo::claim_staker_with_restake().max(T::WeightInfo::claim_staker_without_restake()))]
-        pub fn claim_staker(
-            origin: OriginFor<T>,
-            contract_id: T::SmartContract,
-        ) -> DispatchResultWithPostInfo {
+        /// Part of the withdrawn value, defined by the instant unbond penalty staking parameter, is taken from the staker
+        /// and handed over to `InstantUnbondPenaltyHandler`. Penalty is applied to the entire withdrawn value.
+        #[pallet::call_index(25)]
+        #[pallet::weight(T::WeightInfo::instant_withdraw_unbonded())]
+        pub fn instant_withdraw_unbonded(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
             Self::ensure_pallet_enabled()?;
             let staker = ensure_signed(origin)?;
 
-            // Ensure we have something to claim
-            let mut staker_info = Self::staker_info(&staker, &contract_id);
-            let (era, staked) = staker_info.claim();
-            ensure!(staked > Zero::zero(), Error::<T>::NotStakedContract);
-
-            let dapp_info =
-                RegisteredDapps::<T>::get(&contract_id).ok_or(Error::<T>::NotOperatedContract)?;
-
-            if let DAppState::Unregistered(unregister_era) = dapp_info.state {
-                ensure!(era < unregister_era, Error::<T>::NotOperatedContract);
-            }
-
+            let mut ledger = Self::ledger(&staker);
             let current_era = Self::current_era();
-            ensure!(era < current_era, Error::<T>::EraOutOfBounds);
-
-            let staking_info = Self::contract_stake_info(&contract_id, era).unwrap_or_default();
-            let reward_and_stake =
-                Self::general_era_info(era).ok_or(Error::<T>::UnknownEraReward)?;
-
-            let (_, stakers_joint_reward) =
-                Self::dev_stakers_split(&staking_info, &reward_and_stake);
-            let staker_reward =
-                Perbill::from_rational(staked, staking_info.total) * stakers_joint_reward;
 
-            let mut ledger = Self::ledger(&staker);
+            let withdraw_amount = ledger.unbonding_info.sum();
+            ensure!(!withdraw_amount.is_zero(), Error::<T>::NothingToWithdraw);
 
-            let should_restake_reward = Self::should_restake_reward(
-                ledger.reward_destination,
-                dapp_info.state,
-                staker_info.latest_staked_value(),
-            );
+            ledger.locked = ledger.locked.saturating_sub(withdraw_amount);
+            ledger.unbonding_info = Default::default();
+            Self::update_ledger(&staker, ledger);
 
-            if should_restake_reward {
-                staker_info
-                    .stake(current_era, staker_reward)
-                    .map_err(|_| Error::<T>::UnexpectedStakeInfoEra)?;
+            let penalty = Self::take_instant_unbond_penalty(&staker, withdraw_amount)?;
 
-                // Restaking will, in the worst case, remove one, and add one record,
-                // so it's fine if the vector is full
-                ensure!(
-                    staker_info.len() <= T::MaxEraStakeValues::get(),
-                    Error::<T>::TooManyEraStakeValues
-                );
-            }
+            GeneralEraInfo::<T>::mutate(&current_era, |value| {
+                if let Some(x) = value {
+                    x.locked = x.locked.saturating_sub(withdraw_amount)
+                }
+            });
 
-            // Withdraw reward funds from the dapps staking pot
-            let reward_imbalance = T::Currency::withdraw(
-                &Self::account_id(),
-                staker_reward,
-                WithdrawReasons::TRANSFER,
-                ExistenceRequirement::AllowDeath,
-            )?;
+            Self::deposit_event(Event::<T>::InstantWithdrawn(
+                staker,
+                withdraw_amount,
+                penalty,
+            ));
 
-            if should_restake_reward {
-                ledger.locked = ledger.locked.saturating_add(staker_reward);
-                Self::update_ledger(&staker, ledger);
+            Ok(().into())
+        }
 
-                // Update storage
-                GeneralEraInfo::<T>::mutate(&current_era, |value| {
-                    if let Some(x) = value {
-                        x.staked = x.staked.saturating_add(staker_reward);
-                        x.locked = x.locked.saturating_add(staker_reward);
-                    }
-                });
+        /// Transfer nomination from one contract to another.
+        ///
+        /// Same rules as for `bond_and_stake` and `unbond_and_unstake` apply.
+        /// Minor difference is that there is no unbonding period so this call won't
+        /// check whether max number of unbonding chunks is exceeded.
+        ///
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::nomination_transfer())]
+        pub fn nomination_transfer(
+            origin: OriginFor<T>,
+            origin_contract_id: T::SmartContract,
+            #[pallet::compact] value: BalanceOf<T>,
+            target_contract_id: T::SmartContract,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let staker = ensure_signed(origin)?;
 
-                ContractEraStake::<T>::mutate(contract_id.clone(), current_era, |staking_info| {
-                    if let Some(x) = staking_info {
-                        x.total = x.total.saturating_add(staker_reward);
-                    }
-                });
+            Self::do_nomination_transfer(staker, origin_contract_id, value, target_contract_id)?;
+            Ok(().into())
+        }
 
-                Self::deposit_event(Event::<T>::BondAndStake(
-                    staker.clone(),
-                    contract_id.clone(),
-                    staker_reward,
-                ));
-            }
+        /// Claim earned staker rewards for the oldest unclaimed era.
+        /// In order to claim multiple eras, this call has to be called multiple times or `claim_staker_batch` can be used instead.
+        ///
+        /// The rewards are always added to the staker's free balance (account) but depending on the reward destination configuration,
+        /// they might be immediately re-staked, either fully or partially.
+        ///
+        /// Rewards older than `StakerClaimWindow` eras have expired. If the oldest unclaimed era has expired,
+        /// its reward is swept to `ExpiredStakerRewards` instead, and the call has to be repeated to claim the newer eras.
+        /// Sweeping succeeds even if no claimable era remains, in which case nothing is paid out to the staker
+        /// and only `StakerRewardExpired` events are emitted.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::claim_staker_with_restake().max(T::WeightInfo::claim_staker_without_restake()))]
+        pub fn claim_staker(
+            origin: OriginFor<T>,
+            contract_id: T::SmartContract,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let staker = ensure_signed(origin)?;
 
-            T::Currency::resolve_creating(&staker, reward_imbalance);
-            Self::update_staker_info(&staker, &contract_id, staker_info);
-            Self::deposit_event(Event::<T>::Reward(staker, contract_id, era, staker_reward));
+            let (_, _, restaked) = Self::do_claim_staker(&staker, &contract_id, 1)?;
 
-            Ok(Some(if should_restake_reward {
+            Ok(Some(if restaked {
                 T::WeightInfo::claim_staker_with_restake()
             } else {
                 T::WeightInfo::claim_staker_without_restake()
@@ -830,7 +1207,8 @@ pub mod pallet {
             let mut contract_stake_info =
                 Self::contract_stake_info(&contract_id, era).unwrap_or_default();
 
-            let dapp_reward = Self::calculate_dapp_reward(&contract_stake_info, &dapp_info, era)?;
+            let (dapp_reward, shared_reward) =
+                Self::calculate_dapp_reward(&contract_stake_info, &dapp_info, &contract_id, era)?;
 
             // Withdraw reward funds from the dapps staking
             let reward_imbalance = T::Currency::withdraw(
@@ -842,11 +1220,20 @@ pub mod pallet {
 
             T::Currency::resolve_creating(&dapp_info.developer, reward_imbalance);
             Self::deposit_event(Event::<T>::Reward(
-                dapp_info.developer,
+                dapp_info.developer.clone(),
                 contract_id.clone(),
                 era,
                 dapp_reward,
             ));
+            if !shared_reward.is_zero() {
+                Self::deposit_event(Event::<T>::DappRewardSplit(
+                    dapp_info.developer,
+                    contract_id.clone(),
+                    era,
+                    dapp_reward,
+                    shared_reward,
+                ));
+            }
 
             // updated counter for total rewards paid to the contract
             contract_stake_info.contract_reward_claimed = true;
@@ -901,22 +1288,16 @@ pub mod pallet {
         ) -> DispatchResultWithPostInfo {
             Self::ensure_pallet_enabled()?;
             let staker = ensure_signed(origin)?;
-            let mut ledger = Self::ledger(&staker);
-
-            ensure!(!ledger.is_empty(), Error::<T>::NotActiveStaker);
 
-            // this is done directly instead of using update_ledger helper
-            // because there's no need to interact with the Currency locks
-            ledger.reward_destination = reward_destination;
-            Ledger::<T>::insert(&staker, ledger);
-
-            Self::deposit_event(Event::<T>::RewardDestination(staker, reward_destination));
+            Self::do_set_reward_destination(staker, reward_destination)?;
             Ok(().into())
         }
 
         /// Used to force set `ContractEraStake` storage values.
         /// The purpose of this call is only for fixing one of the issues detected with dapps-staking.
         ///
+        /// Only the specified era entry is set, eras in which contract stake changed aren't affected.
+        ///
         /// The dispatch origin must be Root.
         #[pallet::call_index(12)]
         #[pallet::weight(T::DbWeight::get().writes(1))]
@@ -933,72 +1314,1567 @@ pub mod pallet {
             Ok(().into())
         }
 
-        /// Used to burn unclaimed & stale rewards from an unregistered contract.
-        #[pallet::call_index(13)]
-        #[pallet::weight(T::WeightInfo::claim_dapp())]
-        pub fn burn_stale_reward(
-            origin: OriginFor<T>,
-            contract_id: T::SmartContract,
-            #[pallet::compact] era: EraIndex,
-        ) -> DispatchResultWithPostInfo {
-            Self::ensure_pallet_enabled()?;
-            ensure_root(origin)?;
+        /// Used to burn unclaimed & stale rewards from an unregistered contract.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::claim_dapp())]
+        pub fn burn_stale_reward(
+            origin: OriginFor<T>,
+            contract_id: T::SmartContract,
+            #[pallet::compact] era: EraIndex,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            ensure_root(origin)?;
+
+            let dapp_info =
+                RegisteredDapps::<T>::get(&contract_id).ok_or(Error::<T>::NotOperatedContract)?;
+            ensure!(
+                dapp_info.is_unregistered(),
+                Error::<T>::NotUnregisteredContract
+            );
+
+            let current_era = Self::current_era();
+
+            let burn_era_limit = current_era
+                .saturating_sub(Self::staking_parameters().unregistered_dapp_reward_retention);
+            ensure!(era < burn_era_limit, Error::<T>::EraOutOfBounds);
+
+            let mut contract_stake_info =
+                Self::contract_stake_info(&contract_id, era).unwrap_or_default();
+
+            // Part of the reward shared with stakers can still be claimed by them, so it isn't burned
+            let (dapp_reward, _) =
+                Self::calculate_dapp_reward(&contract_stake_info, &dapp_info, &contract_id, era)?;
+
+            // Withdraw reward funds from the dapps staking pot and burn them
+            let imbalance_to_burn = T::Currency::withdraw(
+                &Self::account_id(),
+                dapp_reward,
+                WithdrawReasons::TRANSFER,
+                ExistenceRequirement::AllowDeath,
+            )?;
+            mem::drop(imbalance_to_burn);
+
+            // mark entry as `claimed` but it means it's just handled (want to avoid rename since pallet will soon be redesigned).
+            contract_stake_info.contract_reward_claimed = true;
+            ContractEraStake::<T>::insert(&contract_id, era, contract_stake_info);
+
+            Self::deposit_event(Event::<T>::StaleRewardBurned(
+                dapp_info.developer,
+                contract_id.clone(),
+                era,
+                dapp_reward,
+            ));
+
+            Ok(().into())
+        }
+
+        /// Claim earned staker rewards for up to `number_of_eras` oldest unclaimed eras.
+        ///
+        /// Same rules as for `claim_staker` apply. Claiming stops at the first era which cannot be claimed,
+        /// so the number of claimed eras can be lower than requested. At least one era must be claimable though.
+        ///
+        /// The total reward is paid out at once and, depending on the reward destination configuration,
+        /// it might be immediately re-staked.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::claim_staker_batch(*number_of_eras))]
+        pub fn claim_staker_batch(
+            origin: OriginFor<T>,
+            contract_id: T::SmartContract,
+            #[pallet::compact] number_of_eras: u32,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let staker = ensure_signed(origin)?;
+
+            ensure!(
+                number_of_eras > 0 && number_of_eras <= T::MaxBatchClaimEras::get(),
+                Error::<T>::InvalidNumberOfEras
+            );
+
+            let (claimed_eras, total_reward, _) =
+                Self::do_claim_staker(&staker, &contract_id, number_of_eras)?;
+
+            Self::deposit_event(Event::<T>::BatchRewardClaimed(
+                staker,
+                contract_id,
+                claimed_eras,
+                total_reward,
+            ));
+
+            Ok(Some(T::WeightInfo::claim_staker_batch(claimed_eras)).into())
+        }
+
+        /// Claim earned staker rewards on behalf of `staker` for up to `number_of_eras` oldest unclaimed eras.
+        ///
+        /// Can be called by any signed account, which makes it possible to run reward claiming bots.
+        /// Same rules as for `claim_staker_batch` apply. Rewards are always paid out to the `staker`
+        /// and handled according to the staker's reward destination configuration.
+        ///
+        /// Dapp rewards can already be claimed by anyone via `claim_dapp`.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::claim_staker_batch(*number_of_eras))]
+        pub fn claim_staker_for(
+            origin: OriginFor<T>,
+            staker: T::AccountId,
+            contract_id: T::SmartContract,
+            #[pallet::compact] number_of_eras: u32,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let _ = ensure_signed(origin)?;
+
+            ensure!(
+                number_of_eras > 0 && number_of_eras <= T::MaxBatchClaimEras::get(),
+                Error::<T>::InvalidNumberOfEras
+            );
+
+            let (claimed_eras, total_reward, _) =
+                Self::do_claim_staker(&staker, &contract_id, number_of_eras)?;
+
+            Self::deposit_event(Event::<T>::BatchRewardClaimed(
+                staker,
+                contract_id,
+                claimed_eras,
+                total_reward,
+            ));
+
+            Ok(Some(T::WeightInfo::claim_staker_batch(claimed_eras)).into())
+        }
+
+        /// Used to register contract for dapps staking without involvement of the `RegisterOrigin`.
+        /// The origin account used is treated as the `developer` account.
+        ///
+        /// As part of this call, `RegisterDeposit` will be reserved from devs account.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::register())]
+        pub fn self_register(
+            origin: OriginFor<T>,
+            contract_id: T::SmartContract,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let developer = ensure_signed(origin)?;
+
+            Self::do_register(developer, contract_id)?;
+
+            Ok(().into())
+        }
+
+        /// Unregister own contract from dapps staking, making it ineligible for rewards from current era onwards.
+        /// Can only be called by the developer account which registered the contract.
+        ///
+        /// Same rules as for `unregister` apply.
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::unregister())]
+        pub fn self_unregister(
+            origin: OriginFor<T>,
+            contract_id: T::SmartContract,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let developer = ensure_signed(origin)?;
+
+            let dapp_info =
+                RegisteredDapps::<T>::get(&contract_id).ok_or(Error::<T>::NotOperatedContract)?;
+            ensure!(
+                dapp_info.developer == developer,
+                Error::<T>::NotOwnedContract
+            );
+            Self::do_unregister(contract_id, dapp_info)?;
+
+            Ok(().into())
+        }
+
+        /// Propose to transfer the ownership of a registered contract to `new_developer` account.
+        /// Can only be called by the current developer account of the contract.
+        ///
+        /// Transfer only takes effect once `new_developer` accepts it via `accept_ownership_transfer`.
+        /// Any previous pending proposal for the contract is overwritten.
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::propose_ownership_transfer())]
+        pub fn propose_ownership_transfer(
+            origin: OriginFor<T>,
+            contract_id: T::SmartContract,
+            new_developer: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let developer = ensure_signed(origin)?;
+
+            let dapp_info =
+                RegisteredDapps::<T>::get(&contract_id).ok_or(Error::<T>::NotOperatedContract)?;
+            ensure!(
+                dapp_info.state == DAppState::Registered,
+                Error::<T>::NotOperatedContract
+            );
+            ensure!(
+                dapp_info.developer == developer,
+                Error::<T>::NotOwnedContract
+            );
+            ensure!(
+                !RegisteredDevelopers::<T>::contains_key(&new_developer),
+                Error::<T>::AlreadyUsedDeveloperAccount
+            );
+
+            PendingOwnershipTransfer::<T>::insert(&contract_id, new_developer.clone());
+
+            Self::deposit_event(Event::<T>::OwnershipTransferProposed(
+                developer,
+                contract_id,
+                new_developer,
+            ));
+
+            Ok(().into())
+        }
+
+        /// Accept the pending ownership transfer of the contract.
+        /// Can only be called by the account proposed via `propose_ownership_transfer`.
+        ///
+        /// `RegisterDeposit` and the metadata deposit are moved from the previous developer account to the new one, remaining reserved.
+        /// All future dapp rewards are paid out to the new developer account.
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::accept_ownership_transfer())]
+        pub fn accept_ownership_transfer(
+            origin: OriginFor<T>,
+            contract_id: T::SmartContract,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let new_developer = ensure_signed(origin)?;
+
+            ensure!(
+                PendingOwnershipTransfer::<T>::get(&contract_id) == Some(new_developer.clone()),
+                Error::<T>::NoPendingOwnershipTransfer
+            );
+
+            let mut dapp_info =
+                RegisteredDapps::<T>::get(&contract_id).ok_or(Error::<T>::NotOperatedContract)?;
+            ensure!(
+                dapp_info.state == DAppState::Registered,
+                Error::<T>::NotOperatedContract
+            );
+            ensure!(
+                !RegisteredDevelopers::<T>::contains_key(&new_developer),
+                Error::<T>::AlreadyUsedDeveloperAccount
+            );
+            let previous_developer = dapp_info.developer.clone();
+
+            // Register and metadata deposits must remain reserved, only the owner changes
+            let metadata_deposit = DappMetadata::<T>::get(&contract_id)
+                .map_or(Zero::zero(), |(_, metadata_deposit)| metadata_deposit);
+            let not_moved = T::Currency::repatriate_reserved(
+                &previous_developer,
+                &new_developer,
+                Self::reserved_deposit(&contract_id).saturating_add(metadata_deposit),
+                BalanceStatus::Reserved,
+            )?;
+            debug_assert!(not_moved.is_zero());
+
+            dapp_info.developer = new_developer.clone();
+            RegisteredDapps::<T>::insert(&contract_id, dapp_info);
+            RegisteredDevelopers::<T>::remove(&previous_developer);
+            RegisteredDevelopers::<T>::insert(&new_developer, contract_id.clone());
+            PendingOwnershipTransfer::<T>::remove(&contract_id);
+
+            Self::deposit_event(Event::<T>::OwnershipTransferred(
+                previous_developer,
+                contract_id,
+                new_developer,
+            ));
+
+            Ok(().into())
+        }
+
+        /// Continue the ongoing multi-block storage migration.
+        ///
+        /// Bounds the `Ledger` and `GeneralStakerInfo` values first, in case storage is still at `V5_0_0`,
+        /// and then adds the `boost` field to the `GeneralEraInfo` and `ContractEraStake` values.
+        /// Both are steps of the same state machine.
+        ///
+        /// Can be called by any signed origin while the migration is in progress.
+        /// Pallet remains in maintenance mode until the migration is finished.
+        #[pallet::call_index(20)]
+        #[pallet::weight({
+            let max_allowed_call_weight = migrations::BoundedStorageMigration::<T>::max_call_weight();
+            weight_limit
+                .unwrap_or(max_allowed_call_weight)
+                .min(max_allowed_call_weight)
+        })]
+        pub fn migrate(
+            origin: OriginFor<T>,
+            weight_limit: Option<Weight>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            let consumed_weight = migrations::StakeBoostMigration::<T>::migrate(weight_limit);
+
+            Ok(Some(consumed_weight).into())
+        }
+
+        /// Move funds from the unlocking chunks back into stake on the specified contract.
+        ///
+        /// Funds are taken from the newest unlocking chunks first. If less than `value` is undergoing
+        /// the unbonding process, everything that is unbonding is rebonded.
+        ///
+        /// Rebonded funds are staked in the current era and are subject to the same checks as `bond_and_stake`.
+        ///
+        /// The dispatch origin for this call must be _Signed_ by the staker's account.
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::rebond())]
+        pub fn rebond(
+            origin: OriginFor<T>,
+            contract_id: T::SmartContract,
+            #[pallet::compact] value: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let staker = ensure_signed(origin)?;
+
+            ensure!(
+                Self::is_active(&contract_id),
+                Error::<T>::NotOperatedContract
+            );
+            ensure!(
+                !FrozenDapps::<T>::contains_key(&contract_id),
+                Error::<T>::DappFrozen
+            );
+
+            let mut ledger = Self::ledger(&staker);
+            let value_to_rebond = ledger.unbonding_info.rebond(value);
+            ensure!(value_to_rebond > Zero::zero(), Error::<T>::NothingToRebond);
+
+            let current_era = Self::current_era();
+            let mut staking_info =
+                Self::contract_stake_info(&contract_id, current_era).unwrap_or_default();
+            let mut staker_info = Self::staker_info(&staker, &contract_id);
+
+            Self::stake_on_contract(
+                &mut staker_info,
+                &mut staking_info,
+                value_to_rebond,
+                current_era,
+            )?;
+
+            // Funds are already locked, only the staked amount changes
+            GeneralEraInfo::<T>::mutate(&current_era, |value| {
+                if let Some(x) = value {
+                    x.staked = x.staked.saturating_add(value_to_rebond);
+                }
+            });
+
+            Self::update_ledger(&staker, ledger);
+            Self::update_staker_info(&staker, &contract_id, staker_info);
+            Self::update_contract_stake_info(&contract_id, current_era, staking_info)?;
+
+            Self::deposit_event(Event::<T>::Rebonded(staker, contract_id, value_to_rebond));
+
+            Ok(().into())
+        }
+
+        /// Set the part of the dApp reward which is shared with the contract stakers, pro-rata to their stake.
+        ///
+        /// New commission applies from the next era, rewards for the current and past eras are not affected.
+        /// Setting the commission again in the same era overwrites the previously set value.
+        ///
+        /// The dispatch origin for this call must be _Signed_ by the developer account of a registered contract.
+        #[pallet::call_index(22)]
+        #[pallet::weight(T::WeightInfo::set_dapp_commission())]
+        pub fn set_dapp_commission(
+            origin: OriginFor<T>,
+            contract_id: T::SmartContract,
+            commission: Perbill,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let developer = ensure_signed(origin)?;
+
+            let dapp_info =
+                RegisteredDapps::<T>::get(&contract_id).ok_or(Error::<T>::NotOperatedContract)?;
+            ensure!(
+                dapp_info.state == DAppState::Registered,
+                Error::<T>::NotOperatedContract
+            );
+            ensure!(
+                dapp_info.developer == developer,
+                Error::<T>::NotOwnedContract
+            );
+
+            let since_era = Self::current_era().saturating_add(1);
+            if let Some(latest_commission) = DappCommission::<T>::get(&contract_id) {
+                // Setting which doesn't apply yet is simply overwritten
+                if latest_commission.since_era != since_era {
+                    DappCommissionHistory::<T>::insert(&contract_id, since_era, latest_commission);
+                }
+            }
+            DappCommission::<T>::insert(
+                &contract_id,
+                DAppCommission {
+                    commission,
+                    since_era,
+                },
+            );
+
+            Self::deposit_event(Event::<T>::DappCommissionChanged(
+                developer,
+                contract_id,
+                commission,
+                since_era,
+            ));
+
+            Ok(().into())
+        }
+
+        /// Set new staking parameters, which are used from the start of the next era.
+        ///
+        /// Values derived from the previous parameters are not affected, e.g. unlock eras of the existing unlocking chunks
+        /// remain the same even if the unbonding period is changed. Setting parameters again before the next era
+        /// overwrites the previously set values.
+        ///
+        /// The dispatch origin must be Root.
+        #[pallet::call_index(23)]
+        #[pallet::weight(T::WeightInfo::set_staking_parameters())]
+        pub fn set_staking_parameters(
+            origin: OriginFor<T>,
+            parameters: StakingParametersOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            ensure_root(origin)?;
+
+            ensure!(parameters.is_valid(), Error::<T>::InvalidStakingParameters);
+
+            PendingStakingParameters::<T>::put(parameters.clone());
+
+            Self::deposit_event(Event::<T>::StakingParametersScheduled(parameters));
+
+            Ok(().into())
+        }
+
+        /// Lock up and stake balance of the origin account, locking the stake on the contract for `lock_eras` eras.
+        ///
+        /// `lock_eras` must be one of the `StakeLockOptions`. Locked stake can't be unstaked from the contract
+        /// until it's unlocked but in return, it has a higher reward weight when staker rewards are calculated.
+        /// Same rules as for `bond_and_stake` apply.
+        ///
+        /// The dispatch origin for this call must be _Signed_ by the staker's account.
+        #[pallet::call_index(26)]
+        #[pallet::weight(T::WeightInfo::bond_and_stake_with_lock())]
+        pub fn bond_and_stake_with_lock(
+            origin: OriginFor<T>,
+            contract_id: T::SmartContract,
+            #[pallet::compact] value: BalanceOf<T>,
+            lock_eras: EraIndex,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let staker = ensure_signed(origin)?;
+
+            Self::do_bond_and_stake(staker, contract_id, value, Some(lock_eras))?;
+            Ok(().into())
+        }
+
+        /// Slash a misbehaving dApp.
+        ///
+        /// * `reward_slash` - part of the unclaimed developer reward which is forfeited, for each era in
+        ///   `[from_era, from_era + number_of_eras)`. The rest of the reward is paid out to the developer and
+        ///   these eras are considered claimed. Eras without unclaimed developer reward are skipped.
+        ///   Part of the dApp reward shared with stakers isn't affected.
+        /// * `slash_deposit` - whether the reserved `RegisterDeposit` should be slashed. Contract must be registered,
+        ///   with some of the deposit still reserved.
+        /// * `freeze` - whether the contract should be frozen, so it can no longer be staked on.
+        ///   Freezing is permanent, there's no way to unfreeze the contract.
+        ///
+        /// Slashed funds are handed over to `SlashHandler`.
+        ///
+        /// The dispatch origin must be `SlashOrigin`.
+        #[pallet::call_index(27)]
+        #[pallet::weight(T::WeightInfo::slash_dapp(*number_of_eras))]
+        pub fn slash_dapp(
+            origin: OriginFor<T>,
+            contract_id: T::SmartContract,
+            #[pallet::compact] from_era: EraIndex,
+            #[pallet::compact] number_of_eras: u32,
+            reward_slash: Perbill,
+            slash_deposit: bool,
+            freeze: bool,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            T::SlashOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                number_of_eras <= T::MaxSlashEras::get(),
+                Error::<T>::InvalidNumberOfSlashEras
+            );
+            let slash_reward = !reward_slash.is_zero() && !number_of_eras.is_zero();
+            ensure!(
+                slash_reward || slash_deposit || freeze,
+                Error::<T>::NothingToSlash
+            );
+
+            let dapp_info =
+                RegisteredDapps::<T>::get(&contract_id).ok_or(Error::<T>::NotOperatedContract)?;
+            ensure!(
+                !slash_deposit || dapp_info.state == DAppState::Registered,
+                Error::<T>::NotOperatedContract
+            );
+            let reserved_deposit = Self::reserved_deposit(&contract_id);
+            ensure!(
+                !slash_deposit || !reserved_deposit.is_zero(),
+                Error::<T>::NothingToSlash
+            );
+
+            let current_era = Self::current_era();
+            let to_era = from_era.saturating_add(number_of_eras);
+            ensure!(to_era <= current_era, Error::<T>::EraOutOfBounds);
+
+            if slash_reward {
+                for era in from_era..to_era {
+                    Self::slash_dapp_reward(&contract_id, &dapp_info, era, reward_slash)?;
+                }
+            }
+
+            if slash_deposit {
+                let (slashed_imbalance, _) =
+                    T::Currency::slash_reserved(&dapp_info.developer, reserved_deposit);
+                let slashed_deposit = slashed_imbalance.peek();
+                T::SlashHandler::on_unbalanced(slashed_imbalance);
+
+                SlashedDeposits::<T>::mutate(&contract_id, |value| {
+                    *value = value.saturating_add(slashed_deposit)
+                });
+                Self::deposit_event(Event::<T>::DappDepositSlashed(
+                    dapp_info.developer.clone(),
+                    contract_id.clone(),
+                    slashed_deposit,
+                ));
+            }
+
+            if freeze && !FrozenDapps::<T>::contains_key(&contract_id) {
+                FrozenDapps::<T>::insert(&contract_id, current_era);
+                Self::deposit_event(Event::<T>::DappFrozen(contract_id));
+            }
+
+            Ok(().into())
+        }
+
+        /// Register a previously unregistered contract for dapps staking again, with the same developer account.
+        /// Contract is eligible for rewards from the current era onwards.
+        ///
+        /// Rewards for the eras before the contract was unregistered can still be claimed, while no rewards can be
+        /// claimed for the eras in which it was unregistered. Stakers who haven't withdrawn their stake via
+        /// `withdraw_from_unregistered` remain staked on the contract. Their stake is recorded for the current era,
+        /// repairing the contract stake history if it's missing.
+        ///
+        /// The dispatch origin must be `RegisterOrigin`.
+        ///
+        /// As part of this call, `RegisterDeposit` will be reserved from devs account again.
+        #[pallet::call_index(28)]
+        #[pallet::weight(T::WeightInfo::reregister())]
+        pub fn reregister(
+            origin: OriginFor<T>,
+            contract_id: T::SmartContract,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            T::RegisterOrigin::ensure_origin(origin)?;
+
+            let mut dapp_info =
+                RegisteredDapps::<T>::get(&contract_id).ok_or(Error::<T>::NotOperatedContract)?;
+            let unregistered_era = if let DAppState::Unregistered(x) = dapp_info.state {
+                x
+            } else {
+                return Err(Error::<T>::NotUnregisteredContract.into());
+            };
+
+            let current_era = Self::current_era();
+            if unregistered_era < current_era {
+                UnregisteredPeriods::<T>::try_mutate(&contract_id, |periods| {
+                    periods.try_push((unregistered_era, current_era))
+                })
+                .map_err(|_| Error::<T>::TooManyReregistrations)?;
+            }
+
+            let developer = dapp_info.developer.clone();
+            T::Currency::reserve(&developer, T::RegisterDeposit::get())?;
+
+            // Stake which remained on the contract is written for the current era, so it doesn't depend on
+            // the stake history. In case history is missing, the stake from the unregistration era is used.
+            let maybe_staking_info = Self::contract_stake_info(&contract_id, current_era)
+                .or_else(|| ContractEraStake::<T>::get(&contract_id, unregistered_era));
+            if let Some(staking_info) = maybe_staking_info {
+                Self::update_contract_stake_info(&contract_id, current_era, staking_info)?;
+            }
+
+            dapp_info.state = DAppState::Registered;
+            RegisteredDapps::<T>::insert(&contract_id, dapp_info);
+
+            Self::deposit_event(Event::<T>::ContractReregistered(developer, contract_id));
+
+            Ok(().into())
+        }
+
+        /// Set the metadata of a registered dApp, overwriting the previous one.
+        ///
+        /// Deposit proportional to the metadata size (`MetadataDepositPerByte`) is reserved from the developer account.
+        /// If the previous metadata deposit was larger, the difference is unreserved.
+        ///
+        /// The dispatch origin for this call must be _Signed_ by the developer account of a registered contract.
+        #[pallet::call_index(29)]
+        #[pallet::weight(T::WeightInfo::set_dapp_metadata(metadata.byte_size()))]
+        pub fn set_dapp_metadata(
+            origin: OriginFor<T>,
+            contract_id: T::SmartContract,
+            metadata: DAppMetadataOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let developer = ensure_signed(origin)?;
+
+            let dapp_info =
+                RegisteredDapps::<T>::get(&contract_id).ok_or(Error::<T>::NotOperatedContract)?;
+            ensure!(
+                dapp_info.state == DAppState::Registered,
+                Error::<T>::NotOperatedContract
+            );
+            ensure!(
+                dapp_info.developer == developer,
+                Error::<T>::NotOwnedContract
+            );
+
+            let deposit =
+                T::MetadataDepositPerByte::get().saturating_mul(metadata.byte_size().into());
+            let previous_deposit = DappMetadata::<T>::get(&contract_id)
+                .map_or(Zero::zero(), |(_, previous_deposit)| previous_deposit);
+            if deposit > previous_deposit {
+                T::Currency::reserve(&developer, deposit - previous_deposit)?;
+            } else {
+                T::Currency::unreserve(&developer, previous_deposit - deposit);
+            }
+
+            DappMetadata::<T>::insert(&contract_id, (metadata, deposit));
+
+            Self::deposit_event(Event::<T>::DappMetadataSet(developer, contract_id, deposit));
+
+            Ok(().into())
+        }
+
+        /// Clear the metadata of a dApp, e.g. in case it contains abusive content.
+        /// Metadata deposit is returned to the developer.
+        ///
+        /// The dispatch origin must be `MetadataOrigin`.
+        #[pallet::call_index(30)]
+        #[pallet::weight(T::WeightInfo::clear_dapp_metadata())]
+        pub fn clear_dapp_metadata(
+            origin: OriginFor<T>,
+            contract_id: T::SmartContract,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            T::MetadataOrigin::ensure_origin(origin)?;
+
+            let (_, deposit) =
+                DappMetadata::<T>::take(&contract_id).ok_or(Error::<T>::NoDappMetadata)?;
+            let dapp_info =
+                RegisteredDapps::<T>::get(&contract_id).ok_or(Error::<T>::NotOperatedContract)?;
+            T::Currency::unreserve(&dapp_info.developer, deposit);
+
+            Self::deposit_event(Event::<T>::DappMetadataCleared(contract_id));
+
+            Ok(().into())
+        }
+
+        /// Grant the operator role to `operator`, replacing the existing operator if there is one.
+        ///
+        /// Operator can transfer nominations and set the reward destination on behalf of the staker,
+        /// via `operator_nomination_transfer` and `operator_set_reward_destination`.
+        /// Operator can't bond, unbond or withdraw any funds. Staker can't be its own operator.
+        ///
+        /// There is intentionally no operator variant of `claim_staker`, since rewards of any staker
+        /// can already be claimed by anyone via `claim_staker_for`.
+        #[pallet::call_index(31)]
+        #[pallet::weight(T::WeightInfo::set_operator())]
+        pub fn set_operator(
+            origin: OriginFor<T>,
+            operator: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let staker = ensure_signed(origin)?;
+            ensure!(staker != operator, Error::<T>::OperatorIsStaker);
+
+            Operators::<T>::insert(&staker, &operator);
+
+            Self::deposit_event(Event::<T>::OperatorSet(staker, operator));
+            Ok(().into())
+        }
+
+        /// Revoke the operator role from the current operator of the staker.
+        #[pallet::call_index(32)]
+        #[pallet::weight(T::WeightInfo::remove_operator())]
+        pub fn remove_operator(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let staker = ensure_signed(origin)?;
+
+            let operator = Operators::<T>::take(&staker).ok_or(Error::<T>::NoOperator)?;
+
+            Self::deposit_event(Event::<T>::OperatorRemoved(staker, operator));
+            Ok(().into())
+        }
+
+        /// Transfer nomination of `staker` from one contract to another.
+        ///
+        /// The dispatch origin must be the operator of the `staker`.
+        /// Same rules as for `nomination_transfer` apply.
+        #[pallet::call_index(33)]
+        #[pallet::weight(T::WeightInfo::nomination_transfer().saturating_add(T::DbWeight::get().reads(1)))]
+        pub fn operator_nomination_transfer(
+            origin: OriginFor<T>,
+            staker: T::AccountId,
+            origin_contract_id: T::SmartContract,
+            #[pallet::compact] value: BalanceOf<T>,
+            target_contract_id: T::SmartContract,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let operator = ensure_signed(origin)?;
+            Self::ensure_operator(&operator, &staker)?;
+
+            Self::do_nomination_transfer(staker, origin_contract_id, value, target_contract_id)?;
+
+            Ok(().into())
+        }
+
+        /// Set reward destination for rewards of `staker`.
+        ///
+        /// The dispatch origin must be the operator of the `staker`.
+        /// Same rules as for `set_reward_destination` apply.
+        #[pallet::call_index(34)]
+        #[pallet::weight(T::WeightInfo::set_reward_destination().saturating_add(T::DbWeight::get().reads(1)))]
+        pub fn operator_set_reward_destination(
+            origin: OriginFor<T>,
+            staker: T::AccountId,
+            reward_destination: RewardDestination,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let operator = ensure_signed(origin)?;
+            Self::ensure_operator(&operator, &staker)?;
+
+            Self::do_set_reward_destination(staker, reward_destination)?;
+            Ok(().into())
+        }
+
+        /// Transfer nominations between contracts, as a list of `(origin contract, target contract, value)` moves.
+        ///
+        /// Moves are applied in the given order, with the same rules as for `nomination_transfer`.
+        /// In case any of the moves fails, none of them are applied.
+        #[pallet::call_index(35)]
+        #[pallet::weight(T::WeightInfo::batch_nomination_transfer(transfers.len() as u32))]
+        pub fn batch_nomination_transfer(
+            origin: OriginFor<T>,
+            transfers: BoundedVec<
+                (T::SmartContract, T::SmartContract, BalanceOf<T>),
+                T::MaxBatchNominationTransfers,
+            >,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let staker = ensure_signed(origin)?;
+
+            ensure!(
+                !transfers.is_empty(),
+                Error::<T>::InvalidNumberOfNominationTransfers
+            );
+
+            for (origin_contract_id, target_contract_id, value) in transfers {
+                Self::do_nomination_transfer(
+                    staker.clone(),
+                    origin_contract_id,
+                    value,
+                    target_contract_id,
+                )?;
+            }
+
+            Ok(().into())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Ensures that `operator` is the operator of the `staker`.
+        fn ensure_operator(operator: &T::AccountId, staker: &T::AccountId) -> DispatchResult {
+            ensure!(
+                Self::operator(staker).as_ref() == Some(operator),
+                Error::<T>::NotOperator
+            );
+            Ok(())
+        }
+
+        /// Transfers `value` of the staker's nomination from `origin_contract_id` to `target_contract_id`.
+        fn do_nomination_transfer(
+            staker: T::AccountId,
+            origin_contract_id: T::SmartContract,
+            value: BalanceOf<T>,
+            target_contract_id: T::SmartContract,
+        ) -> DispatchResult {
+            // Contracts must differ and both must be active
+            ensure!(
+                origin_contract_id != target_contract_id,
+                Error::<T>::NominationTransferToSameContract
+            );
+            ensure!(
+                Self::is_active(&origin_contract_id),
+                Error::<T>::NotOperatedContract
+            );
+            ensure!(
+                Self::is_active(&target_contract_id),
+                Error::<T>::NotOperatedContract
+            );
+            ensure!(
+                !FrozenDapps::<T>::contains_key(&target_contract_id),
+                Error::<T>::DappFrozen
+            );
+
+            // Validate origin contract related data & update it
+            let current_era = Self::current_era();
+            let mut origin_staker_info = Self::staker_info(&staker, &origin_contract_id);
+            let mut origin_staking_info =
+                Self::contract_stake_info(&origin_contract_id, current_era).unwrap_or_default();
+
+            let origin_to_target_transfer_value = Self::unstake_from_contract(
+                &mut origin_staker_info,
+                &mut origin_staking_info,
+                value,
+                current_era,
+            )?;
+            ensure!(
+                origin_staker_info.latest_staked_value()
+                    >= Self::locked_stake(&staker, &origin_contract_id, current_era),
+                Error::<T>::StakeLocked
+            );
+
+            // Validate target contract related data & update it
+            let mut target_staker_info = Self::staker_info(&staker, &target_contract_id);
+            let mut target_staking_info =
+                Self::contract_stake_info(&target_contract_id, current_era).unwrap_or_default();
+
+            Self::stake_on_contract(
+                &mut target_staker_info,
+                &mut target_staking_info,
+                origin_to_target_transfer_value,
+                current_era,
+            )?;
+
+            // Update origin data
+            Self::update_contract_stake_info(
+                &origin_contract_id,
+                current_era,
+                origin_staking_info,
+            )?;
+            Self::update_staker_info(&staker, &origin_contract_id, origin_staker_info);
+
+            // Update target data
+            Self::update_contract_stake_info(
+                &target_contract_id,
+                current_era,
+                target_staking_info,
+            )?;
+            Self::update_staker_info(&staker, &target_contract_id, target_staker_info);
+
+            Self::deposit_event(Event::<T>::NominationTransfer(
+                staker,
+                origin_contract_id,
+                origin_to_target_transfer_value,
+                target_contract_id,
+            ));
+
+            Ok(())
+        }
+
+        /// Sets the reward destination of an active staker.
+        fn do_set_reward_destination(
+            staker: T::AccountId,
+            reward_destination: RewardDestination,
+        ) -> DispatchResult {
+            let mut ledger = Self::ledger(&staker);
+
+            ensure!(!ledger.is_empty(), Error::<T>::NotActiveStaker);
+
+            // this is done directly instead of using update_ledger helper
+            // because there's no need to interact with the Currency locks
+            ledger.reward_destination = reward_destination;
+            Ledger::<T>::insert(&staker, ledger);
+
+            Self::deposit_event(Event::<T>::RewardDestination(staker, reward_destination));
+            Ok(())
+        }
+
+        /// Bonds and stakes `value` on the contract, or as much of it as is available.
+        ///
+        /// In case `lock_eras` is provided, staked value is locked on the contract for that many eras,
+        /// and its reward weight is boosted according to `StakeLockOptions`.
+        fn do_bond_and_stake(
+            staker: T::AccountId,
+            contract_id: T::SmartContract,
+            value: BalanceOf<T>,
+            lock_eras: Option<EraIndex>,
+        ) -> DispatchResult {
+            // Check that contract is ready for staking.
+            ensure!(
+                Self::is_active(&contract_id),
+                Error::<T>::NotOperatedContract
+            );
+            ensure!(
+                !FrozenDapps::<T>::contains_key(&contract_id),
+                Error::<T>::DappFrozen
+            );
+
+            let lock_option = lock_eras
+                .map(|lock_eras| {
+                    T::StakeLockOptions::get()
+                        .into_iter()
+                        .find(|(eras, _)| *eras == lock_eras && !eras.is_zero())
+                        .ok_or(Error::<T>::InvalidStakeLockDuration)
+                })
+                .transpose()?;
+
+            // Get the staking ledger or create an entry if it doesn't exist.
+            let mut ledger = Self::ledger(&staker);
+            let available_balance = Self::available_staking_balance(&staker, &ledger);
+            let value_to_stake = value.min(available_balance);
+            ensure!(
+                value_to_stake > Zero::zero(),
+                Error::<T>::StakingWithNoValue
+            );
+
+            let current_era = Self::current_era();
+            let mut staking_info =
+                Self::contract_stake_info(&contract_id, current_era).unwrap_or_default();
+            let mut staker_info = Self::staker_info(&staker, &contract_id);
+
+            Self::stake_on_contract(
+                &mut staker_info,
+                &mut staking_info,
+                value_to_stake,
+                current_era,
+            )?;
+
+            let stake_lock = if let Some((lock_eras, boost)) = lock_option {
+                let stake_lock = StakeLock {
+                    amount: value_to_stake,
+                    boost: boost * value_to_stake,
+                    start_era: current_era,
+                    unlock_era: current_era.saturating_add(lock_eras),
+                };
+                Self::add_stake_lock(&staker, &contract_id, &staker_info, stake_lock)?;
+                staking_info.boost = staking_info.boost.saturating_add(stake_lock.boost);
+                Some(stake_lock)
+            } else {
+                None
+            };
+
+            ledger.locked = ledger.locked.saturating_add(value_to_stake);
+
+            // Update storage
+            GeneralEraInfo::<T>::mutate(&current_era, |value| {
+                if let Some(x) = value {
+                    x.staked = x.staked.saturating_add(value_to_stake);
+                    x.locked = x.locked.saturating_add(value_to_stake);
+                    if let Some(stake_lock) = stake_lock {
+                        x.boost = x.boost.saturating_add(stake_lock.boost);
+                    }
+                }
+            });
+
+            Self::update_ledger(&staker, ledger);
+            Self::update_staker_info(&staker, &contract_id, staker_info);
+            Self::update_contract_stake_info(&contract_id, current_era, staking_info)?;
+
+            if let Some(stake_lock) = stake_lock {
+                Self::deposit_event(Event::<T>::BondAndStakeWithLock(
+                    staker,
+                    contract_id,
+                    value_to_stake,
+                    stake_lock.unlock_era,
+                ));
+            } else {
+                Self::deposit_event(Event::<T>::BondAndStake(
+                    staker,
+                    contract_id,
+                    value_to_stake,
+                ));
+            }
+
+            Ok(())
+        }
+
+        /// Adds a new stake lock for the `(staker, contract)` pairing and schedules expiry of its boost.
+        ///
+        /// Expired stake locks for which all rewards have been claimed are removed first, to make room for the new one.
+        fn add_stake_lock(
+            staker: &T::AccountId,
+            contract_id: &T::SmartContract,
+            staker_info: &StakerInfoOf<T>,
+            stake_lock: StakeLock<BalanceOf<T>>,
+        ) -> DispatchResult {
+            let mut stake_locks = Self::stake_locks(staker, contract_id);
+            Self::prune_stake_locks(&mut stake_locks, staker_info, stake_lock.start_era);
+
+            // Locks created in the same era, for the same duration, are merged
+            if let Some(existing_lock) = stake_locks.iter_mut().find(|existing_lock| {
+                existing_lock.start_era == stake_lock.start_era
+                    && existing_lock.unlock_era == stake_lock.unlock_era
+            }) {
+                existing_lock.amount = existing_lock.amount.saturating_add(stake_lock.amount);
+                existing_lock.boost = existing_lock.boost.saturating_add(stake_lock.boost);
+            } else {
+                stake_locks
+                    .try_push(stake_lock)
+                    .map_err(|_| Error::<T>::TooManyStakeLocks)?;
+            }
+
+            BoostExpiries::<T>::try_mutate(stake_lock.unlock_era, |expiries| {
+                if let Some((_, boost)) = expiries
+                    .iter_mut()
+                    .find(|(expiring_contract_id, _)| expiring_contract_id == contract_id)
+                {
+                    *boost = boost.saturating_add(stake_lock.boost);
+                    Ok(())
+                } else {
+                    expiries
+                        .try_push((contract_id.clone(), stake_lock.boost))
+                        .map_err(|_| Error::<T>::TooManyBoostExpiries)
+                }
+            })?;
+
+            StakeLocks::<T>::insert(staker, contract_id, stake_locks);
+
+            Ok(())
+        }
+
+        /// Removes stake locks which have expired and for which all rewards have been claimed.
+        fn prune_stake_locks(
+            stake_locks: &mut StakeLocksOf<T>,
+            staker_info: &StakerInfoOf<T>,
+            current_era: EraIndex,
+        ) {
+            let oldest_unclaimed_era = staker_info
+                .oldest_unclaimed_era()
+                .map_or(current_era, |era| era.min(current_era));
+            stake_locks.retain(|stake_lock| stake_lock.unlock_era > oldest_unclaimed_era);
+        }
+
+        /// Used to update stake locks of the `(staker, contract)` pairing, removing the entry if there are none left.
+        fn update_stake_locks(
+            staker: &T::AccountId,
+            contract_id: &T::SmartContract,
+            stake_locks: StakeLocksOf<T>,
+        ) {
+            if stake_locks.is_empty() {
+                StakeLocks::<T>::remove(staker, contract_id)
+            } else {
+                StakeLocks::<T>::insert(staker, contract_id, stake_locks)
+            }
+        }
+
+        /// Removes all stake locks of the `(staker, contract)` pairing, cancelling the scheduled expiry of their boost.
+        ///
+        /// Returns total boost of the stake locks which haven't expired yet.
+        fn release_stake_locks(
+            staker: &T::AccountId,
+            contract_id: &T::SmartContract,
+            current_era: EraIndex,
+        ) -> BalanceOf<T> {
+            let mut released_boost = BalanceOf::<T>::zero();
+            for stake_lock in StakeLocks::<T>::take(staker, contract_id)
+                .iter()
+                .filter(|stake_lock| stake_lock.unlock_era > current_era)
+            {
+                BoostExpiries::<T>::mutate(stake_lock.unlock_era, |expiries| {
+                    if let Some((_, boost)) = expiries
+                        .iter_mut()
+                        .find(|(expiring_contract_id, _)| expiring_contract_id == contract_id)
+                    {
+                        *boost = boost.saturating_sub(stake_lock.boost);
+                    }
+                });
+                released_boost = released_boost.saturating_add(stake_lock.boost);
+            }
+
+            released_boost
+        }
+
+        /// Stake of the `(staker, contract)` pairing which is still locked in the specified era.
+        fn locked_stake(
+            staker: &T::AccountId,
+            contract_id: &T::SmartContract,
+            era: EraIndex,
+        ) -> BalanceOf<T> {
+            Self::stake_locks(staker, contract_id)
+                .iter()
+                .filter(|stake_lock| stake_lock.unlock_era > era)
+                .fold(Zero::zero(), |total, stake_lock| {
+                    total.saturating_add(stake_lock.amount)
+                })
+        }
+
+        /// Removes the reward weight boosts of the stake locks which expire at the start of `era`,
+        /// from both the contract stake info and the era info.
+        ///
+        /// Returns number of contracts whose boost was removed.
+        fn expire_boosts(era: EraIndex) -> u32 {
+            let expiries = BoostExpiries::<T>::take(era);
+
+            let mut expired_boost = BalanceOf::<T>::zero();
+            for (contract_id, boost) in expiries.iter() {
+                // In case contract stake history is full, expiry is postponed to the next era
+                if !Self::can_update_contract_stake_info(contract_id, era) {
+                    let postponed = BoostExpiries::<T>::try_mutate(era + 1, |next_expiries| {
+                        next_expiries.try_push((contract_id.clone(), *boost))
+                    });
+                    if postponed.is_err() {
+                        log::error!(
+                            "Boost {:?} of contract {:?} couldn't expire in era {:?} and remains in effect.",
+                            boost,
+                            contract_id,
+                            era,
+                        );
+                    }
+                    continue;
+                }
+
+                if let Some(mut staking_info) = Self::contract_stake_info(contract_id, era) {
+                    staking_info.boost = staking_info.boost.saturating_sub(*boost);
+                    // Can't fail since there's space in the history
+                    let _ = Self::update_contract_stake_info(contract_id, era, staking_info);
+                }
+                expired_boost = expired_boost.saturating_add(*boost);
+            }
+
+            GeneralEraInfo::<T>::mutate(&era, |value| {
+                if let Some(x) = value {
+                    x.boost = x.boost.saturating_sub(expired_boost);
+                }
+            });
+
+            expiries.len() as u32
+        }
+
+        /// Registers `contract_id` for dapps staking with `developer` as its owner.
+        ///
+        /// `RegisterDeposit` is reserved from the developer account.
+        fn do_register(developer: T::AccountId, contract_id: T::SmartContract) -> DispatchResult {
+            ensure!(
+                !RegisteredDevelopers::<T>::contains_key(&developer),
+                Error::<T>::AlreadyUsedDeveloperAccount,
+            );
+            ensure!(
+                !RegisteredDapps::<T>::contains_key(&contract_id),
+                Error::<T>::AlreadyRegisteredContract,
+            );
+
+            T::Currency::reserve(&developer, T::RegisterDeposit::get())?;
+
+            RegisteredDapps::<T>::insert(contract_id.clone(), DAppInfo::new(developer.clone()));
+            RegisteredDevelopers::<T>::insert(&developer, contract_id.clone());
+
+            Self::deposit_event(Event::<T>::NewContract(developer, contract_id));
+
+            Ok(())
+        }
+
+        /// Unregisters `contract_id` from dapps staking, starting from the current era.
+        ///
+        /// Reserved part of the `RegisterDeposit` is returned to the developer.
+        fn do_unregister(
+            contract_id: T::SmartContract,
+            mut dapp_info: DAppInfo<T::AccountId>,
+        ) -> DispatchResult {
+            ensure!(
+                dapp_info.state == DAppState::Registered,
+                Error::<T>::NotOperatedContract
+            );
+            let developer = dapp_info.developer.clone();
+
+            let current_era = Self::current_era();
+            dapp_info.state = DAppState::Unregistered(current_era);
+            RegisteredDapps::<T>::insert(&contract_id, dapp_info);
+            PendingOwnershipTransfer::<T>::remove(&contract_id);
+
+            T::Currency::unreserve(&developer, Self::reserved_deposit(&contract_id));
+            SlashedDeposits::<T>::remove(&contract_id);
+
+            // Metadata is only kept for the registered dApps
+            if let Some((_, metadata_deposit)) = DappMetadata::<T>::take(&contract_id) {
+                T::Currency::unreserve(&developer, metadata_deposit);
+            }
+
+            Self::deposit_event(Event::<T>::ContractRemoved(developer, contract_id));
+
+            Ok(())
+        }
+
+        /// Claims staker rewards for at most `max_eras` oldest unclaimed eras of the `(staker, contract)` pairing.
+        ///
+        /// The first era must be claimable, otherwise an error is returned.
+        /// Remaining eras are claimed until the limit is reached or a non-claimable era is encountered.
+        ///
+        /// Total reward is paid out to the staker and, if reward destination allows it, restaked (fully or partially) on the contract.
+        /// A `Reward` event is deposited for each claimed era, followed by `StakerRewardSplit`
+        /// if part of the reward comes from the dApp commission.
+        ///
+        /// Returns `(number of claimed eras, total reward, restaked)` tuple.
+        fn do_claim_staker(
+            staker: &T::AccountId,
+            contract_id: &T::SmartContract,
+            max_eras: u32,
+        ) -> Result<(u32, BalanceOf<T>, bool), DispatchError> {
+            // Ensure we have something to claim
+            let mut staker_info = Self::staker_info(staker, contract_id);
+            ensure!(!staker_info.is_empty(), Error::<T>::NotStakedContract);
+            Self::skip_unregistered_periods(
+                &mut staker_info,
+                &Self::unregistered_periods(contract_id),
+            );
+
+            let dapp_info =
+                RegisteredDapps::<T>::get(contract_id).ok_or(Error::<T>::NotOperatedContract)?;
+            let current_era = Self::current_era();
+
+            // Expired eras have to be swept before newer rewards can be claimed
+            let claimable_eras = Self::claimable_staker_eras();
+            if staker_info
+                .oldest_unclaimed_era()
+                .map_or(false, |era| era < claimable_eras.start)
+            {
+                let (swept_eras, _) = Self::sweep_expired_staker_rewards(
+                    staker,
+                    contract_id,
+                    &dapp_info,
+                    &staker_info,
+                    max_eras,
+                )?;
+                return Ok((swept_eras, Zero::zero(), false));
+            }
+
+            let mut stake_locks = Self::stake_locks(staker, contract_id);
+            let (era_rewards, mut staker_info) = Self::calculate_staker_rewards(
+                &staker_info,
+                &stake_locks,
+                contract_id,
+                &dapp_info,
+                claimable_eras,
+                max_eras,
+            )?;
+            let total_reward = era_rewards.iter().fold(
+                BalanceOf::<T>::zero(),
+                |total, (_, staking_reward, commission_reward)| {
+                    total
+                        .saturating_add(*staking_reward)
+                        .saturating_add(*commission_reward)
+                },
+            );
+
+            let mut ledger = Self::ledger(staker);
+
+            // Rewards can't be restaked on a frozen contract, or one with a full stake history, they're paid out instead
+            let reward_to_restake = if !FrozenDapps::<T>::contains_key(contract_id)
+                && Self::can_update_contract_stake_info(contract_id, current_era)
+                && Self::should_restake_reward(
+                    ledger.reward_destination,
+                    dapp_info.state,
+                    staker_info.latest_staked_value(),
+                ) {
+                ledger.reward_destination.restake_part(total_reward)
+            } else {
+                Zero::zero()
+            };
+            let should_restake_reward = !reward_to_restake.is_zero();
+
+            if should_restake_reward {
+                // Restaking will, in the worst case, remove one, and add one record,
+                // so it's fine if the vector is full
+                staker_info
+                    .stake(current_era, reward_to_restake)
+                    .map_err(Error::<T>::from)?;
+            }
+
+            // Withdraw reward funds from the dapps staking pot
+            let reward_imbalance = T::Currency::withdraw(
+                &Self::account_id(),
+                total_reward,
+                WithdrawReasons::TRANSFER,
+                ExistenceRequirement::AllowDeath,
+            )?;
+
+            if should_restake_reward {
+                ledger.locked = ledger.locked.saturating_add(reward_to_restake);
+                Self::update_ledger(staker, ledger);
+
+                // Update storage
+                GeneralEraInfo::<T>::mutate(&current_era, |value| {
+                    if let Some(x) = value {
+                        x.staked = x.staked.saturating_add(reward_to_restake);
+                        x.locked = x.locked.saturating_add(reward_to_restake);
+                    }
+                });
+
+                if let Some(mut staking_info) = Self::contract_stake_info(contract_id, current_era)
+                {
+                    staking_info.total = staking_info.total.saturating_add(reward_to_restake);
+                    Self::update_contract_stake_info(contract_id, current_era, staking_info)?;
+                }
 
-            let dapp_info =
-                RegisteredDapps::<T>::get(&contract_id).ok_or(Error::<T>::NotOperatedContract)?;
-            ensure!(
-                dapp_info.is_unregistered(),
-                Error::<T>::NotUnregisteredContract
-            );
+                Self::deposit_event(Event::<T>::BondAndStake(
+                    staker.clone(),
+                    contract_id.clone(),
+                    reward_to_restake,
+                ));
+            }
 
-            let current_era = Self::current_era();
+            T::Currency::resolve_creating(staker, reward_imbalance);
+            Self::prune_stake_locks(&mut stake_locks, &staker_info, current_era);
+            Self::update_stake_locks(staker, contract_id, stake_locks);
+            Self::update_staker_info(staker, contract_id, staker_info);
 
-            let burn_era_limit =
-                current_era.saturating_sub(T::UnregisteredDappRewardRetention::get());
-            ensure!(era < burn_era_limit, Error::<T>::EraOutOfBounds);
+            let claimed_eras = era_rewards.len() as u32;
+            for (era, staking_reward, commission_reward) in era_rewards {
+                Self::deposit_event(Event::<T>::Reward(
+                    staker.clone(),
+                    contract_id.clone(),
+                    era,
+                    staking_reward.saturating_add(commission_reward),
+                ));
+                if !commission_reward.is_zero() {
+                    Self::deposit_event(Event::<T>::StakerRewardSplit(
+                        staker.clone(),
+                        contract_id.clone(),
+                        era,
+                        staking_reward,
+                        commission_reward,
+                    ));
+                }
+            }
+
+            Ok((claimed_eras, total_reward, should_restake_reward))
+        }
 
+        /// Slashes `reward_slash` part of the unclaimed developer reward for `era`, handing it over to `SlashHandler`.
+        /// The rest is paid out to the developer and era is marked as claimed.
+        ///
+        /// Nothing is done if the developer reward for `era` cannot be claimed.
+        fn slash_dapp_reward(
+            contract_id: &T::SmartContract,
+            dapp_info: &DAppInfo<T::AccountId>,
+            era: EraIndex,
+            reward_slash: Perbill,
+        ) -> DispatchResult {
             let mut contract_stake_info =
-                Self::contract_stake_info(&contract_id, era).unwrap_or_default();
+                Self::contract_stake_info(contract_id, era).unwrap_or_default();
 
-            let dapp_reward = Self::calculate_dapp_reward(&contract_stake_info, &dapp_info, era)?;
+            let dapp_reward = match Self::calculate_dapp_reward(
+                &contract_stake_info,
+                dapp_info,
+                contract_id,
+                era,
+            ) {
+                Ok((dapp_reward, _)) => dapp_reward,
+                Err(_) => return Ok(()),
+            };
 
-            // Withdraw reward funds from the dapps staking pot and burn them
-            let imbalance_to_burn = T::Currency::withdraw(
+            let reward_imbalance = T::Currency::withdraw(
                 &Self::account_id(),
                 dapp_reward,
                 WithdrawReasons::TRANSFER,
                 ExistenceRequirement::AllowDeath,
             )?;
-            mem::drop(imbalance_to_burn);
+            let (slashed_imbalance, reward_imbalance) =
+                reward_imbalance.split(reward_slash * dapp_reward);
+            let slashed_reward = slashed_imbalance.peek();
+            let remaining_reward = reward_imbalance.peek();
 
-            // mark entry as `claimed` but it means it's just handled (want to avoid rename since pallet will soon be redesigned).
-            contract_stake_info.contract_reward_claimed = true;
-            ContractEraStake::<T>::insert(&contract_id, era, contract_stake_info);
+            T::SlashHandler::on_unbalanced(slashed_imbalance);
+            T::Currency::resolve_creating(&dapp_info.developer, reward_imbalance);
 
-            Self::deposit_event(Event::<T>::StaleRewardBurned(
-                dapp_info.developer,
+            Self::deposit_event(Event::<T>::DappRewardSlashed(
+                dapp_info.developer.clone(),
                 contract_id.clone(),
                 era,
-                dapp_reward,
+                slashed_reward,
             ));
+            if !remaining_reward.is_zero() {
+                Self::deposit_event(Event::<T>::Reward(
+                    dapp_info.developer.clone(),
+                    contract_id.clone(),
+                    era,
+                    remaining_reward,
+                ));
+            }
 
-            Ok(().into())
+            contract_stake_info.contract_reward_claimed = true;
+            ContractEraStake::<T>::insert(contract_id, era, contract_stake_info);
+
+            Ok(())
+        }
+
+        /// Takes the instant unbond penalty for the unlocked `value` from the staker and hands it over
+        /// to `InstantUnbondPenaltyHandler`. Returns the penalty amount.
+        fn take_instant_unbond_penalty(
+            staker: &T::AccountId,
+            value: BalanceOf<T>,
+        ) -> Result<BalanceOf<T>, DispatchError> {
+            let penalty = Self::staking_parameters().instant_unbond_penalty * value;
+
+            let penalty_imbalance = T::Currency::withdraw(
+                staker,
+                penalty,
+                WithdrawReasons::TRANSFER,
+                ExistenceRequirement::KeepAlive,
+            )?;
+            T::InstantUnbondPenaltyHandler::on_unbalanced(penalty_imbalance);
+
+            Ok(penalty)
+        }
+
+        /// Sweeps staker rewards of up to `max_eras` oldest expired eras to `ExpiredStakerRewards`.
+        /// Returns number of swept eras and the swept amount.
+        fn sweep_expired_staker_rewards(
+            staker: &T::AccountId,
+            contract_id: &T::SmartContract,
+            dapp_info: &DAppInfo<T::AccountId>,
+            staker_info: &StakerInfoOf<T>,
+            max_eras: u32,
+        ) -> Result<(u32, BalanceOf<T>), DispatchError> {
+            let expired_eras = 0..Self::claimable_staker_eras().start;
+            let mut stake_locks = Self::stake_locks(staker, contract_id);
+            let (era_rewards, staker_info) = Self::calculate_staker_rewards(
+                staker_info,
+                &stake_locks,
+                contract_id,
+                dapp_info,
+                expired_eras,
+                max_eras,
+            )?;
+            let total_reward = era_rewards.iter().fold(
+                BalanceOf::<T>::zero(),
+                |total, (_, staking_reward, commission_reward)| {
+                    total
+                        .saturating_add(*staking_reward)
+                        .saturating_add(*commission_reward)
+                },
+            );
+
+            let expired_imbalance = T::Currency::withdraw(
+                &Self::account_id(),
+                total_reward,
+                WithdrawReasons::TRANSFER,
+                ExistenceRequirement::AllowDeath,
+            )?;
+            T::ExpiredStakerRewards::on_unbalanced(expired_imbalance);
+            Self::prune_stake_locks(&mut stake_locks, &staker_info, Self::current_era());
+            Self::update_stake_locks(staker, contract_id, stake_locks);
+            Self::update_staker_info(staker, contract_id, staker_info);
+
+            let swept_eras = era_rewards.len() as u32;
+            for (era, staking_reward, commission_reward) in era_rewards {
+                Self::deposit_event(Event::<T>::StakerRewardExpired(
+                    staker.clone(),
+                    contract_id.clone(),
+                    era,
+                    staking_reward.saturating_add(commission_reward),
+                ));
+            }
+
+            Ok((swept_eras, total_reward))
+        }
+
+        /// Eras for which stakers can claim rewards. Staker rewards of the older eras have expired.
+        pub(crate) fn claimable_staker_eras() -> Range<EraIndex> {
+            let current_era = Self::current_era();
+            current_era.saturating_sub(T::StakerClaimWindow::get())..current_era
+        }
+
+        /// Calculates staker rewards for at most `max_eras` oldest unclaimed eras within `eras` range,
+        /// without modifying any storage.
+        ///
+        /// The first era must be claimable, otherwise an error is returned.
+        /// Remaining eras are included until the limit is reached or a non-claimable era is encountered.
+        ///
+        /// Staker reward weight in an era is the staked amount, increased by the boost of the stakes locked in that era.
+        ///
+        /// Returns `(era, staking reward, commission reward)` tuples and the staker info as it would be after claiming them.
+        fn calculate_staker_rewards(
+            staker_info: &StakerInfoOf<T>,
+            stake_locks: &StakeLocksOf<T>,
+            contract_id: &T::SmartContract,
+            dapp_info: &DAppInfo<T::AccountId>,
+            eras: Range<EraIndex>,
+            max_eras: u32,
+        ) -> Result<(Vec<(EraIndex, BalanceOf<T>, BalanceOf<T>)>, StakerInfoOf<T>), Error<T>>
+        {
+            let mut staker_info = staker_info.clone();
+            let mut era_rewards = Vec::new();
+            let unregistered_periods = Self::unregistered_periods(contract_id);
+
+            while (era_rewards.len() as u32) < max_eras {
+                let mut next_staker_info = staker_info.clone();
+                Self::skip_unregistered_periods(&mut next_staker_info, &unregistered_periods);
+                let (era, staked) = next_staker_info.claim();
+
+                let era_check = if staked.is_zero() {
+                    Err(Error::<T>::NotStakedContract)
+                } else if matches!(
+                    dapp_info.state,
+                    DAppState::Unregistered(unregister_era) if era >= unregister_era
+                ) {
+                    Err(Error::<T>::NotOperatedContract)
+                } else if era >= eras.end {
+                    Err(Error::<T>::EraOutOfBounds)
+                } else if era < eras.start {
+                    Err(Error::<T>::RewardExpired)
+                } else {
+                    Ok(())
+                };
+
+                if let Err(error) = era_check {
+                    // Only the first era must be claimable, the rest is claimed on best-effort basis.
+                    if era_rewards.is_empty() {
+                        return Err(error);
+                    }
+                    break;
+                }
+
+                let staking_info = Self::contract_stake_info(contract_id, era).unwrap_or_default();
+                let reward_and_stake =
+                    Self::general_era_info(era).ok_or(Error::<T>::UnknownEraReward)?;
+
+                let (developer_reward, stakers_joint_reward) =
+                    Self::dev_stakers_split(&staking_info, &reward_and_stake);
+                let (_, shared_reward) = Self::commission_split(
+                    developer_reward,
+                    Self::dapp_commission_at(contract_id, era),
+                );
+
+                let boost = stake_locks
+                    .iter()
+                    .filter(|stake_lock| stake_lock.is_locked_in(era))
+                    .fold(BalanceOf::<T>::zero(), |total, stake_lock| {
+                        total.saturating_add(stake_lock.boost)
+                    });
+                let staker_portion = Perbill::from_rational(
+                    staked.saturating_add(boost),
+                    staking_info.total.saturating_add(staking_info.boost),
+                );
+                era_rewards.push((
+                    era,
+                    staker_portion * stakers_joint_reward,
+                    staker_portion * shared_reward,
+                ));
+                staker_info = next_staker_info;
+            }
+
+            Ok((era_rewards, staker_info))
         }
-    }
 
-    impl<T: Config> Pallet<T> {
         /// Calculate the dApp reward for the specified era.
-        /// If successfull, returns reward amount.
+        /// If successfull, returns `(developer reward, reward shared with stakers)` amounts.
         /// In case reward cannot be claimed or was already claimed, an error is raised.
         fn calculate_dapp_reward(
             contract_stake_info: &ContractStakeInfo<BalanceOf<T>>,
             dapp_info: &DAppInfo<T::AccountId>,
+            contract_id: &T::SmartContract,
             era: EraIndex,
-        ) -> Result<BalanceOf<T>, Error<T>> {
+        ) -> Result<(BalanceOf<T>, BalanceOf<T>), Error<T>> {
             let current_era = Self::current_era();
             if let DAppState::Unregistered(unregister_era) = dapp_info.state {
                 ensure!(era < unregister_era, Error::<T>::NotOperatedContract);
             }
+            ensure!(
+                Self::reregistered_era(&Self::unregistered_periods(contract_id), era).is_none(),
+                Error::<T>::NotOperatedContract
+            );
             ensure!(era < current_era, Error::<T>::EraOutOfBounds);
 
             ensure!(
@@ -1016,7 +2892,10 @@ pub mod pallet {
             // Calculate the contract reward for this era.
             let (dapp_reward, _) = Self::dev_stakers_split(&contract_stake_info, &reward_and_stake);
 
-            Ok(dapp_reward)
+            Ok(Self::commission_split(
+                dapp_reward,
+                Self::dapp_commission_at(contract_id, era),
+            ))
         }
 
         /// An utility method used to stake specified amount on an arbitrary contract.
@@ -1037,14 +2916,15 @@ pub mod pallet {
         /// If not, an error is returned and structs are left in an undefined state.
         ///
         fn stake_on_contract(
-            staker_info: &mut StakerInfo<BalanceOf<T>>,
+            staker_info: &mut StakerInfoOf<T>,
             staking_info: &mut ContractStakeInfo<BalanceOf<T>>,
             value: BalanceOf<T>,
             current_era: EraIndex,
         ) -> Result<(), Error<T>> {
             ensure!(
                 !staker_info.latest_staked_value().is_zero()
-                    || staking_info.number_of_stakers < T::MaxNumberOfStakersPerContract::get(),
+                    || staking_info.number_of_stakers
+                        < Self::staking_parameters().max_number_of_stakers_per_contract,
                 Error::<T>::MaxNumberOfStakersExceeded
             );
             if staker_info.latest_staked_value().is_zero() {
@@ -1053,14 +2933,15 @@ pub mod pallet {
 
             staker_info
                 .stake(current_era, value)
-                .map_err(|_| Error::<T>::UnexpectedStakeInfoEra)?;
+                .map_err(Error::<T>::from)?;
             ensure!(
                 // One spot should remain for compounding reward claim call
                 staker_info.len() < T::MaxEraStakeValues::get(),
                 Error::<T>::TooManyEraStakeValues
             );
             ensure!(
-                staker_info.latest_staked_value() >= T::MinimumStakingAmount::get(),
+                staker_info.latest_staked_value()
+                    >= Self::staking_parameters().minimum_staking_amount,
                 Error::<T>::InsufficientValue,
             );
 
@@ -1091,7 +2972,7 @@ pub mod pallet {
         /// If not, an error is returned and structs are left in an undefined state.
         ///
         fn unstake_from_contract(
-            staker_info: &mut StakerInfo<BalanceOf<T>>,
+            staker_info: &mut StakerInfoOf<T>,
             contract_stake_info: &mut ContractStakeInfo<BalanceOf<T>>,
             value: BalanceOf<T>,
             current_era: EraIndex,
@@ -1101,7 +2982,8 @@ pub mod pallet {
 
             // Calculate the value which will be unstaked.
             let remaining = staked_value.saturating_sub(value);
-            let value_to_unstake = if remaining < T::MinimumStakingAmount::get() {
+            let value_to_unstake = if remaining < Self::staking_parameters().minimum_staking_amount
+            {
                 contract_stake_info.number_of_stakers =
                     contract_stake_info.number_of_stakers.saturating_sub(1);
                 staked_value
@@ -1118,7 +3000,7 @@ pub mod pallet {
 
             staker_info
                 .unstake(current_era, value_to_unstake)
-                .map_err(|_| Error::<T>::UnexpectedStakeInfoEra)?;
+                .map_err(Error::<T>::from)?;
             ensure!(
                 // One spot should remain for compounding reward claim call
                 staker_info.len() < T::MaxEraStakeValues::get(),
@@ -1144,7 +3026,7 @@ pub mod pallet {
 
         /// Update the ledger for a staker. This will also update the stash lock.
         /// This lock will lock the entire funds except paying for further transactions.
-        fn update_ledger(staker: &T::AccountId, ledger: AccountLedger<BalanceOf<T>>) {
+        fn update_ledger(staker: &T::AccountId, ledger: AccountLedgerOf<T>) {
             if ledger.is_empty() {
                 Ledger::<T>::remove(&staker);
                 T::Currency::remove_lock(STAKING_ID, staker);
@@ -1159,7 +3041,7 @@ pub mod pallet {
         fn update_staker_info(
             staker: &T::AccountId,
             contract_id: &T::SmartContract,
-            staker_info: StakerInfo<BalanceOf<T>>,
+            staker_info: StakerInfoOf<T>,
         ) {
             if staker_info.is_empty() {
                 GeneralStakerInfo::<T>::remove(staker, contract_id)
@@ -1184,6 +3066,7 @@ pub mod pallet {
                     rewards: Default::default(),
                     staked: era_info.staked,
                     locked: era_info.locked,
+                    boost: era_info.boost,
                 },
             );
 
@@ -1193,42 +3076,440 @@ pub mod pallet {
             GeneralEraInfo::<T>::insert(era, era_info);
         }
 
-        /// Used to copy all `ContractStakeInfo` from the ending era over to the next era.
-        /// This is the most primitive solution since it scales with number of dApps.
-        /// It is possible to provide a hybrid solution which allows laziness but also prevents
-        /// a situation where we don't have access to the required data.
-        fn rotate_staking_info(current_era: EraIndex) -> Weight {
-            let next_era = current_era + 1;
+        /// Returns contract staking info for the specified era.
+        ///
+        /// `ContractEraStake` entries only exist for eras in which contract stake changed or reward was claimed.
+        /// In case there's no entry for the specified era, info is derived from the closest previous era in which
+        /// contract stake changed. Such info is never marked as claimed.
+        pub fn contract_stake_info(
+            contract_id: &T::SmartContract,
+            era: EraIndex,
+        ) -> Option<ContractStakeInfo<BalanceOf<T>>> {
+            if let Some(staking_info) = ContractEraStake::<T>::get(contract_id, era) {
+                return Some(staking_info);
+            }
+
+            // Find the latest change before the specified era
+            let history = ContractStakeHistory::<T>::get(contract_id);
+            let index = history.partition_point(|stake_era| *stake_era <= era);
+            let stake_era = *history.get(index.checked_sub(1)?)?;
 
-            let mut consumed_weight = Weight::zero();
+            ContractEraStake::<T>::get(contract_id, stake_era).map(|mut staking_info| {
+                staking_info.contract_reward_claimed = false;
+                staking_info
+            })
+        }
 
-            for (contract_id, dapp_info) in RegisteredDapps::<T>::iter() {
-                // Ignore dapp if it was unregistered
-                consumed_weight = consumed_weight.saturating_add(T::DbWeight::get().reads(1));
-                if let DAppState::Unregistered(_) = dapp_info.state {
-                    continue;
+        /// Returns the dApp commission which applies to the specified era.
+        pub fn dapp_commission_at(contract_id: &T::SmartContract, era: EraIndex) -> Perbill {
+            // Walk back from the latest setting until the one which applies to the specified era is found
+            let mut maybe_commission = DappCommission::<T>::get(contract_id);
+            while let Some(dapp_commission) = maybe_commission {
+                if dapp_commission.since_era <= era {
+                    return dapp_commission.commission;
                 }
+                maybe_commission =
+                    DappCommissionHistory::<T>::get(contract_id, dapp_commission.since_era);
+            }
 
-                // Copy data from era `X` to era `X + 1`
-                if let Some(mut staking_info) = Self::contract_stake_info(&contract_id, current_era)
-                {
-                    staking_info.contract_reward_claimed = false;
-                    ContractEraStake::<T>::insert(&contract_id, next_era, staking_info);
+            Perbill::zero()
+        }
 
-                    consumed_weight =
-                        consumed_weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
-                } else {
-                    consumed_weight = consumed_weight.saturating_add(T::DbWeight::get().reads(1));
+        /// Used to update contract staking info for the current era.
+        ///
+        /// Keeps track of the eras in which contract stake changed so the info can be derived for any era
+        /// covered by the contract stake history. Entries are only removed from the history by pruning,
+        /// so in case history is full, `ContractStakeHistoryFull` error is returned.
+        fn update_contract_stake_info(
+            contract_id: &T::SmartContract,
+            current_era: EraIndex,
+            staking_info: ContractStakeInfo<BalanceOf<T>>,
+        ) -> Result<(), Error<T>> {
+            let mut history = ContractStakeHistory::<T>::get(contract_id);
+            if history.last() != Some(&current_era) {
+                history
+                    .try_push(current_era)
+                    .map_err(|_| Error::<T>::ContractStakeHistoryFull)?;
+                ContractStakeHistory::<T>::insert(contract_id, history);
+            }
+
+            ContractEraStake::<T>::insert(contract_id, current_era, staking_info);
+            Ok(())
+        }
+
+        /// `true` if contract staking info can be updated for `era`, without exceeding the contract stake history.
+        fn can_update_contract_stake_info(contract_id: &T::SmartContract, era: EraIndex) -> bool {
+            let history = ContractStakeHistory::<T>::get(contract_id);
+            history.last() == Some(&era)
+                || (history.len() as u32) < T::MaxContractStakeHistory::get()
+        }
+
+        /// Prunes era data which can no longer be used to claim rewards, without exceeding the weight limit.
+        ///
+        /// Pruning pass is started once per era and is done in stages, which can span many blocks:
+        /// 1. stakers are scanned for the oldest era with unclaimed staker rewards, expired staker rewards are swept,
+        /// 2. dApps are scanned for the oldest era with unclaimed dApp rewards,
+        /// 3. `GeneralEraInfo` entries of the older eras are removed, making reward claims for them impossible,
+        /// 4. `ContractEraStake` entries of the older eras are removed, except the ones still needed to derive the stake info.
+        ///
+        /// Eras which are within `EraHistoryDepth` from the current era are never pruned.
+        pub(crate) fn prune_era_history(weight_limit: Weight) -> Weight {
+            let db_weight = T::DbWeight::get();
+
+            // Pallet state & pruning info are read, pruning info is written
+            let mut consumed_weight = db_weight.reads_writes(3, 1);
+            if !weight_limit.all_gte(consumed_weight) || PalletDisabled::<T>::get() {
+                return Weight::zero();
+            }
+
+            let current_era = Self::current_era();
+            let mut pruning_info = EraPruning::<T>::get();
+
+            // Upper bound of the number of contract stake entries, valid since older entries have been pruned
+            let max_contract_entries =
+                current_era.saturating_sub(pruning_info.pruned_before) as u64 + 2;
+            let scan_dapp_weight = db_weight.reads(max_contract_entries + 1);
+            let sweep_weight = T::WeightInfo::claim_staker_batch(T::MaxBatchClaimEras::get());
+            let claimable_eras_start = Self::claimable_staker_eras().start;
+            let prune_contract_weight =
+                db_weight.reads_writes(3 * max_contract_entries + 2, 2 * max_contract_entries);
+
+            loop {
+                match pruning_info.stage.clone() {
+                    EraPruningStage::Idle => {
+                        if pruning_info.pass_era >= current_era {
+                            break;
+                        }
+                        pruning_info.pass_era = current_era;
+                        pruning_info.stage = EraPruningStage::ScanStakers {
+                            last_key: None,
+                            oldest_unprunable_era: current_era
+                                .saturating_sub(T::EraHistoryDepth::get()),
+                        };
+                    }
+                    EraPruningStage::ScanStakers {
+                        last_key,
+                        oldest_unprunable_era,
+                    } => {
+                        let step_weight = db_weight.reads(3).saturating_add(sweep_weight);
+                        if !weight_limit.all_gte(consumed_weight.saturating_add(step_weight)) {
+                            break;
+                        }
+                        consumed_weight.saturating_accrue(db_weight.reads(2));
+
+                        let mut staker_info_iter = match last_key.clone() {
+                            Some((staker, contract_id)) => GeneralStakerInfo::<T>::iter_from(
+                                GeneralStakerInfo::<T>::hashed_key_for(staker, contract_id),
+                            ),
+                            None => GeneralStakerInfo::<T>::iter(),
+                        };
+
+                        pruning_info.stage = match staker_info_iter.next() {
+                            Some((staker, contract_id, staker_info)) => {
+                                let mut oldest_claimable_era =
+                                    Self::oldest_claimable_staker_era(&contract_id, &staker_info);
+                                let is_expired = |era: Option<EraIndex>| {
+                                    era.map_or(false, |era| era < claimable_eras_start)
+                                };
+
+                                // Expired rewards are swept, so they don't prevent pruning
+                                let mut sweep_again = false;
+                                if is_expired(oldest_claimable_era) {
+                                    if let Some(Ok((swept_eras, _))) =
+                                        RegisteredDapps::<T>::get(&contract_id).map(|dapp_info| {
+                                            Self::sweep_expired_staker_rewards(
+                                                &staker,
+                                                &contract_id,
+                                                &dapp_info,
+                                                &staker_info,
+                                                T::MaxBatchClaimEras::get(),
+                                            )
+                                        })
+                                    {
+                                        consumed_weight.saturating_accrue(
+                                            T::WeightInfo::claim_staker_batch(swept_eras)
+                                                .saturating_add(db_weight.reads(1)),
+                                        );
+                                        oldest_claimable_era = Self::oldest_claimable_staker_era(
+                                            &contract_id,
+                                            &Self::staker_info(&staker, &contract_id),
+                                        );
+                                        sweep_again = swept_eras == T::MaxBatchClaimEras::get()
+                                            && is_expired(oldest_claimable_era);
+                                    }
+                                }
+
+                                if sweep_again {
+                                    // Remaining expired eras are swept in the next step
+                                    EraPruningStage::ScanStakers {
+                                        last_key,
+                                        oldest_unprunable_era,
+                                    }
+                                } else {
+                                    EraPruningStage::ScanStakers {
+                                        last_key: Some((staker, contract_id)),
+                                        oldest_unprunable_era: oldest_claimable_era
+                                            .map_or(oldest_unprunable_era, |era| {
+                                                era.min(oldest_unprunable_era)
+                                            }),
+                                    }
+                                }
+                            }
+                            None => EraPruningStage::ScanDapps {
+                                last_key: None,
+                                oldest_unprunable_era,
+                            },
+                        };
+                    }
+                    EraPruningStage::ScanDapps {
+                        last_key,
+                        oldest_unprunable_era,
+                    } => {
+                        if oldest_unprunable_era <= pruning_info.pruned_before {
+                            // Nothing can be pruned in this pass
+                            pruning_info.stage = EraPruningStage::Idle;
+                            continue;
+                        }
+
+                        if !weight_limit.all_gte(consumed_weight.saturating_add(scan_dapp_weight)) {
+                            break;
+                        }
+
+                        let mut dapps_iter = match last_key {
+                            Some(contract_id) => RegisteredDapps::<T>::iter_from(
+                                RegisteredDapps::<T>::hashed_key_for(contract_id),
+                            ),
+                            None => RegisteredDapps::<T>::iter(),
+                        };
+
+                        pruning_info.stage = match dapps_iter.next() {
+                            Some((contract_id, dapp_info)) => {
+                                let (oldest_unclaimed_era, entries) =
+                                    Self::oldest_claimable_dapp_era(
+                                        &contract_id,
+                                        &dapp_info,
+                                        pruning_info.pruned_before,
+                                        oldest_unprunable_era,
+                                    );
+                                consumed_weight
+                                    .saturating_accrue(db_weight.reads(entries as u64 + 1));
+
+                                EraPruningStage::ScanDapps {
+                                    last_key: Some(contract_id),
+                                    oldest_unprunable_era: oldest_unclaimed_era
+                                        .unwrap_or(oldest_unprunable_era),
+                                }
+                            }
+                            None => {
+                                consumed_weight.saturating_accrue(db_weight.reads(1));
+                                EraPruningStage::PruneEras {
+                                    next_era: pruning_info.pruned_before,
+                                    prune_before: oldest_unprunable_era,
+                                    pruned_era_info: 0,
+                                }
+                            }
+                        };
+                    }
+                    EraPruningStage::PruneEras {
+                        next_era,
+                        prune_before,
+                        pruned_era_info,
+                    } => {
+                        if next_era >= prune_before {
+                            pruning_info.stage = EraPruningStage::PruneContracts {
+                                last_key: None,
+                                prune_before,
+                                pruned_era_info,
+                                pruned_contract_stakes: 0,
+                            };
+                            continue;
+                        }
+
+                        let step_weight = db_weight.reads_writes(1, 1);
+                        if !weight_limit.all_gte(consumed_weight.saturating_add(step_weight)) {
+                            break;
+                        }
+                        consumed_weight.saturating_accrue(step_weight);
+
+                        let pruned = GeneralEraInfo::<T>::take(next_era).is_some();
+                        pruning_info.stage = EraPruningStage::PruneEras {
+                            next_era: next_era + 1,
+                            prune_before,
+                            pruned_era_info: pruned_era_info + pruned as u32,
+                        };
+                    }
+                    EraPruningStage::PruneContracts {
+                        last_key,
+                        prune_before,
+                        pruned_era_info,
+                        pruned_contract_stakes,
+                    } => {
+                        if !weight_limit
+                            .all_gte(consumed_weight.saturating_add(prune_contract_weight))
+                        {
+                            break;
+                        }
+
+                        let mut dapps_iter = match last_key {
+                            Some(contract_id) => RegisteredDapps::<T>::iter_keys_from(
+                                RegisteredDapps::<T>::hashed_key_for(contract_id),
+                            ),
+                            None => RegisteredDapps::<T>::iter_keys(),
+                        };
+
+                        match dapps_iter.next() {
+                            Some(contract_id) => {
+                                let (pruned, step_weight) =
+                                    Self::prune_contract_era_stake(&contract_id, prune_before);
+                                consumed_weight.saturating_accrue(
+                                    step_weight.saturating_add(db_weight.reads(1)),
+                                );
+
+                                pruning_info.stage = EraPruningStage::PruneContracts {
+                                    last_key: Some(contract_id),
+                                    prune_before,
+                                    pruned_era_info,
+                                    pruned_contract_stakes: pruned_contract_stakes
+                                        .saturating_add(pruned),
+                                };
+                            }
+                            None => {
+                                consumed_weight.saturating_accrue(db_weight.reads(1));
+
+                                pruning_info.pruned_before = prune_before;
+                                pruning_info.stage = EraPruningStage::Idle;
+
+                                Self::deposit_event(Event::<T>::EraHistoryPruned(
+                                    prune_before,
+                                    pruned_era_info,
+                                    pruned_contract_stakes,
+                                ));
+                            }
+                        }
+                    }
                 }
             }
 
+            EraPruning::<T>::put(pruning_info);
+
             consumed_weight
         }
 
+        /// Returns the oldest era for which the staker can still claim the reward on the contract, if any.
+        fn oldest_claimable_staker_era(
+            contract_id: &T::SmartContract,
+            staker_info: &StakerInfoOf<T>,
+        ) -> Option<EraIndex> {
+            let mut staker_info = staker_info.clone();
+            Self::skip_unregistered_periods(
+                &mut staker_info,
+                &Self::unregistered_periods(contract_id),
+            );
+            let era = staker_info.oldest_unclaimed_era()?;
+
+            match RegisteredDapps::<T>::get(contract_id).map(|dapp_info| dapp_info.state) {
+                Some(DAppState::Unregistered(unregister_era)) if era >= unregister_era => None,
+                _ => Some(era),
+            }
+        }
+
+        /// Returns the oldest era in range `[from_era, to_era)` for which the dApp reward can still be claimed, if any.
+        /// Number of storage reads, i.e. read contract stake entries and unregistered periods, is returned as well.
+        ///
+        /// Stake info is derived by going through the contract stake entries in ascending order, instead of
+        /// calling `contract_stake_info` for each era. Entries written on reward claim contain the same stake info
+        /// as the last stake change entry, so the latest entry can be used for the eras without one.
+        fn oldest_claimable_dapp_era(
+            contract_id: &T::SmartContract,
+            dapp_info: &DAppInfo<T::AccountId>,
+            from_era: EraIndex,
+            to_era: EraIndex,
+        ) -> (Option<EraIndex>, u32) {
+            let mut stake_entries: Vec<_> =
+                ContractEraStake::<T>::iter_prefix(contract_id).collect();
+            let entries = stake_entries.len() as u32 + 1;
+            let unregistered_periods = Self::unregistered_periods(contract_id);
+            stake_entries.sort_by_key(|(era, _)| *era);
+
+            let first_era = match stake_entries.first() {
+                Some((era, _)) => *era,
+                None => return (None, entries),
+            };
+
+            let mut stake_entries = stake_entries.into_iter().peekable();
+            let mut stake_info: Option<ContractStakeInfo<BalanceOf<T>>> = None;
+            for era in first_era..to_era {
+                match stake_entries.next_if(|(stake_era, _)| *stake_era == era) {
+                    Some((_, info)) => stake_info = Some(info),
+                    // Stake didn't change and the reward hasn't been claimed since there's no entry
+                    None => {
+                        if let Some(info) = stake_info.as_mut() {
+                            info.contract_reward_claimed = false;
+                        }
+                    }
+                }
+
+                if era < from_era || Self::reregistered_era(&unregistered_periods, era).is_some() {
+                    continue;
+                }
+                if let DAppState::Unregistered(unregister_era) = dapp_info.state {
+                    if era >= unregister_era {
+                        break;
+                    }
+                }
+
+                if let Some(info) = &stake_info {
+                    if !info.total.is_zero() && !info.contract_reward_claimed {
+                        return (Some(era), entries);
+                    }
+                }
+            }
+
+            (None, entries)
+        }
+
+        /// Removes contract stake entries older than `era`.
+        /// Returns number of removed entries and consumed weight.
+        ///
+        /// The latest stake change before `era` is kept, since it's still needed to derive the stake info of `era` and later eras.
+        fn prune_contract_era_stake(
+            contract_id: &T::SmartContract,
+            era: EraIndex,
+        ) -> (u32, Weight) {
+            let mut history = ContractStakeHistory::<T>::get(contract_id);
+            let index = history.partition_point(|stake_era| *stake_era <= era);
+            let base_era = index.checked_sub(1).map(|base_index| history[base_index]);
+
+            let stake_eras: Vec<EraIndex> =
+                ContractEraStake::<T>::iter_key_prefix(contract_id).collect();
+            let reads = 1 + stake_eras.len();
+
+            let mut pruned = 0;
+            for stake_era in stake_eras {
+                if stake_era < era && Some(stake_era) != base_era {
+                    ContractEraStake::<T>::remove(contract_id, stake_era);
+                    pruned += 1;
+                }
+            }
+
+            // History is never searched past the kept stake change
+            let mut writes = pruned;
+            if index > 1 {
+                history.retain(|stake_era| Some(*stake_era) >= base_era);
+                ContractStakeHistory::<T>::insert(contract_id, history);
+                writes += 1;
+            }
+
+            (
+                pruned,
+                T::DbWeight::get().reads_writes(reads as u64, writes as u64),
+            )
+        }
+
         /// Returns available staking balance for the potential staker
         fn available_staking_balance(
             staker: &T::AccountId,
-            ledger: &AccountLedger<BalanceOf<T>>,
+            ledger: &AccountLedgerOf<T>,
         ) -> BalanceOf<T> {
             // Ensure that staker has enough balance to bond & stake.
             let free_balance =
@@ -1238,6 +3519,37 @@ pub mod pallet {
             free_balance.saturating_sub(ledger.locked)
         }
 
+        /// Era in which the contract was re-registered, if it was unregistered in `era`.
+        pub(crate) fn reregistered_era(
+            unregistered_periods: &[(EraIndex, EraIndex)],
+            era: EraIndex,
+        ) -> Option<EraIndex> {
+            unregistered_periods
+                .iter()
+                .find(|(unregistered_era, reregistered_era)| {
+                    *unregistered_era <= era && era < *reregistered_era
+                })
+                .map(|(_, reregistered_era)| *reregistered_era)
+        }
+
+        /// Skips the oldest unclaimed eras of the staker in which the contract was unregistered, since there are no rewards for them.
+        pub(crate) fn skip_unregistered_periods(
+            staker_info: &mut StakerInfoOf<T>,
+            unregistered_periods: &[(EraIndex, EraIndex)],
+        ) {
+            while let Some(reregistered_era) = staker_info
+                .oldest_unclaimed_era()
+                .and_then(|era| Self::reregistered_era(unregistered_periods, era))
+            {
+                staker_info.skip_to(reregistered_era);
+            }
+        }
+
+        /// Part of the `RegisterDeposit` which is still reserved for the contract, i.e. which hasn't been slashed.
+        fn reserved_deposit(contract_id: &T::SmartContract) -> BalanceOf<T> {
+            T::RegisterDeposit::get().saturating_sub(Self::slashed_deposit(contract_id))
+        }
+
         /// `true` if contract is active, `false` if it has been unregistered
         fn is_active(contract_id: &T::SmartContract) -> bool {
             RegisteredDapps::<T>::get(contract_id)
@@ -1250,13 +3562,22 @@ pub mod pallet {
             dapp_state: DAppState,
             latest_staked_value: BalanceOf<T>,
         ) -> bool {
-            reward_destination == RewardDestination::StakeBalance
+            let restake_enabled = match reward_destination {
+                RewardDestination::FreeBalance => false,
+                RewardDestination::StakeBalance => true,
+                RewardDestination::Split(restake_ratio) => !restake_ratio.is_zero(),
+            };
+
+            restake_enabled
                 && dapp_state == DAppState::Registered
                 && latest_staked_value > Zero::zero()
         }
 
         /// Calculate reward split between developer and stakers.
         ///
+        /// Developer reward is based on the contract stake, while joint stakers reward also takes
+        /// the reward weight boost of the time-locked stakes into account.
+        ///
         /// Returns (developer reward, joint stakers reward)
         pub(crate) fn dev_stakers_split(
             contract_info: &ContractStakeInfo<BalanceOf<T>>,
@@ -1264,13 +3585,32 @@ pub mod pallet {
         ) -> (BalanceOf<T>, BalanceOf<T>) {
             let contract_stake_portion =
                 Perbill::from_rational(contract_info.total, era_info.staked);
+            let contract_boosted_stake_portion = Perbill::from_rational(
+                contract_info.total.saturating_add(contract_info.boost),
+                era_info.staked.saturating_add(era_info.boost),
+            );
 
             let developer_reward_part = contract_stake_portion * era_info.rewards.dapps;
-            let stakers_joint_reward = contract_stake_portion * era_info.rewards.stakers;
+            let stakers_joint_reward = contract_boosted_stake_portion * era_info.rewards.stakers;
 
             (developer_reward_part, stakers_joint_reward)
         }
 
+        /// Split developer reward according to the dApp commission.
+        ///
+        /// Returns (developer reward, reward shared with stakers)
+        pub(crate) fn commission_split(
+            developer_reward: BalanceOf<T>,
+            commission: Perbill,
+        ) -> (BalanceOf<T>, BalanceOf<T>) {
+            let shared_reward = commission * developer_reward;
+
+            (
+                developer_reward.saturating_sub(shared_reward),
+                shared_reward,
+            )
+        }
+
         /// Adds `stakers` and `dapps` rewards to the reward pool.
         ///
         /// - `stakers` - portion of the reward that will be distributed to stakers
@@ -1297,5 +3637,176 @@ pub mod pallet {
                 Zero::zero()
             }
         }
+
+        /// Returns `(era, reward)` pairs for all eras in which staker can claim a reward for staking on the contract.
+        ///
+        /// Rewards are calculated the same way as when they are claimed.
+        pub fn staker_claimable_rewards(
+            staker: &T::AccountId,
+            contract_id: &T::SmartContract,
+        ) -> Vec<(EraIndex, BalanceOf<T>)> {
+            let staker_info = Self::staker_info(staker, contract_id);
+            let stake_locks = Self::stake_locks(staker, contract_id);
+            RegisteredDapps::<T>::get(contract_id)
+                .and_then(|dapp_info| {
+                    let claimable_eras = Self::claimable_staker_eras();
+
+                    // Skip the expired eras, they will be swept instead of claimed
+                    let (_, staker_info) = Self::calculate_staker_rewards(
+                        &staker_info,
+                        &stake_locks,
+                        contract_id,
+                        &dapp_info,
+                        0..claimable_eras.start,
+                        EraIndex::MAX,
+                    )
+                    .unwrap_or((Vec::new(), staker_info));
+
+                    Self::calculate_staker_rewards(
+                        &staker_info,
+                        &stake_locks,
+                        contract_id,
+                        &dapp_info,
+                        claimable_eras,
+                        EraIndex::MAX,
+                    )
+                    .ok()
+                })
+                .map_or(Vec::new(), |(era_rewards, _)| {
+                    era_rewards
+                        .into_iter()
+                        .map(|(era, staking_reward, commission_reward)| {
+                            (era, staking_reward.saturating_add(commission_reward))
+                        })
+                        .collect()
+                })
+        }
+
+        /// Returns `(era, reward)` pairs for all eras in which the dApp reward can be claimed.
+        ///
+        /// Rewards are calculated the same way as when they are claimed.
+        pub fn dapp_claimable_rewards(
+            contract_id: &T::SmartContract,
+        ) -> Vec<(EraIndex, BalanceOf<T>)> {
+            let dapp_info = match RegisteredDapps::<T>::get(contract_id) {
+                Some(dapp_info) => dapp_info,
+                None => return Vec::new(),
+            };
+
+            // First stake entry marks the first era in which contract could have earned a reward
+            let first_era = match ContractEraStake::<T>::iter_key_prefix(contract_id).min() {
+                Some(era) => era,
+                None => return Vec::new(),
+            };
+
+            (first_era..Self::current_era())
+                .filter_map(|era| {
+                    let contract_stake_info = Self::contract_stake_info(contract_id, era)?;
+                    Self::calculate_dapp_reward(&contract_stake_info, &dapp_info, contract_id, era)
+                        .ok()
+                        .map(|(reward, _)| (era, reward))
+                })
+                .collect()
+        }
+
+        /// Returns `(unlock era, amount)` pairs of the staker's unlocking chunks, sorted by unlock era.
+        pub fn unlocking_chunks(staker: &T::AccountId) -> Vec<(EraIndex, BalanceOf<T>)> {
+            Self::ledger(staker).unbonding_info.unlock_eras()
+        }
+
+        /// Checks the consistency of the pallet storage, returning an error describing the first broken invariant.
+        ///
+        /// Checks are skipped while the multi-block storage migration is in progress.
+        #[cfg(any(feature = "try-runtime", test))]
+        pub fn do_try_state() -> Result<(), &'static str> {
+            use sp_std::collections::btree_map::BTreeMap;
+
+            if Self::migration_state() != migrations::MigrationState::NotInProgress {
+                return Ok(());
+            }
+
+            let current_era = Self::current_era();
+
+            // Contract stake must match the stake of its stakers.
+            // Stakes are summed up per contract in a single pass, keyed by the encoded contract id.
+            let mut contract_stakes = BTreeMap::<Vec<u8>, (BalanceOf<T>, u32)>::new();
+            for (_, contract_id, staker_info) in GeneralStakerInfo::<T>::iter() {
+                let staked = staker_info.latest_staked_value();
+                if staked.is_zero() {
+                    continue;
+                }
+                let (total, count) = contract_stakes
+                    .entry(contract_id.encode())
+                    .or_insert((Zero::zero(), 0));
+                *total = total.saturating_add(staked);
+                *count = count.saturating_add(1);
+            }
+
+            for (contract_id, dapp_info) in RegisteredDapps::<T>::iter() {
+                if dapp_info.state != DAppState::Registered {
+                    continue;
+                }
+
+                let (total_staked, number_of_stakers) = contract_stakes
+                    .get(&contract_id.encode())
+                    .copied()
+                    .unwrap_or((Zero::zero(), 0));
+
+                let contract_stake_info =
+                    Self::contract_stake_info(&contract_id, current_era).unwrap_or_default();
+                ensure!(
+                    contract_stake_info.total == total_staked,
+                    "Contract total stake differs from the sum of its stakers' stakes."
+                );
+                ensure!(
+                    contract_stake_info.number_of_stakers == number_of_stakers,
+                    "Contract number of stakers differs from the actual number of stakers."
+                );
+            }
+
+            // Locked amount must cover exactly the staked and unbonding funds.
+            let mut total_locked = BalanceOf::<T>::zero();
+            for (staker, ledger) in Ledger::<T>::iter() {
+                let staked = GeneralStakerInfo::<T>::iter_prefix_values(&staker)
+                    .fold(BalanceOf::<T>::zero(), |total, staker_info| {
+                        total.saturating_add(staker_info.latest_staked_value())
+                    });
+                ensure!(
+                    ledger.locked == staked.saturating_add(ledger.unbonding_info.sum()),
+                    "Ledger locked amount differs from staked and unbonding amounts."
+                );
+                total_locked = total_locked.saturating_add(ledger.locked);
+            }
+            ensure!(
+                Self::tvl() == total_locked,
+                "Era locked amount differs from the sum of all ledgers."
+            );
+
+            // Pallet account must hold enough funds to pay out all outstanding rewards.
+            let accumulated = Self::block_reward_accumulator();
+            let staker_rewards = GeneralStakerInfo::<T>::iter_keys()
+                .flat_map(|(staker, contract_id)| {
+                    Self::staker_claimable_rewards(&staker, &contract_id)
+                })
+                .fold(BalanceOf::<T>::zero(), |total, (_, reward)| {
+                    total.saturating_add(reward)
+                });
+            let dapp_rewards = RegisteredDapps::<T>::iter_keys()
+                .flat_map(|contract_id| Self::dapp_claimable_rewards(&contract_id))
+                .fold(BalanceOf::<T>::zero(), |total, (_, reward)| {
+                    total.saturating_add(reward)
+                });
+            let required = accumulated
+                .stakers
+                .saturating_add(accumulated.dapps)
+                .saturating_add(staker_rewards)
+                .saturating_add(dapp_rewards);
+            ensure!(
+                T::Currency::free_balance(&Self::account_id()) >= required,
+                "Pallet account balance doesn't cover all outstanding rewards."
+            );
+
+            Ok(())
+        }
     }
 }
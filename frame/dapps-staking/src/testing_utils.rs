@@ -7,7 +7,7 @@
 // If not, see <https://polyformproject.org/licenses/noncommercial/1.0.0//>.
 
 use super::{pallet::pallet::Event, *};
-use frame_support::assert_ok;
+use frame_support::{assert_ok, traits::OnIdle, weights::Weight};
 use mock::{EraIndex, *};
 use sp_runtime::{traits::AccountIdConversion, Perbill};
 
@@ -15,10 +15,11 @@ use sp_runtime::{traits::AccountIdConversion, Perbill};
 pub(crate) struct MemorySnapshot {
     era_info: EraInfo<Balance>,
     dapp_info: DAppInfo<AccountId>,
-    staker_info: StakerInfo<Balance>,
+    dapp_frozen: bool,
+    staker_info: StakerInfoOf<TestRuntime>,
     contract_info: ContractStakeInfo<Balance>,
     free_balance: Balance,
-    ledger: AccountLedger<Balance>,
+    ledger: AccountLedgerOf<TestRuntime>,
 }
 
 impl MemorySnapshot {
@@ -31,6 +32,7 @@ impl MemorySnapshot {
         Self {
             era_info: DappsStaking::general_era_info(era).unwrap(),
             dapp_info: RegisteredDapps::<TestRuntime>::get(contract_id).unwrap(),
+            dapp_frozen: FrozenDapps::<TestRuntime>::contains_key(contract_id),
             staker_info: GeneralStakerInfo::<TestRuntime>::get(&account, contract_id),
             contract_info: DappsStaking::contract_stake_info(contract_id, era).unwrap_or_default(),
             ledger: DappsStaking::ledger(&account),
@@ -44,6 +46,7 @@ impl MemorySnapshot {
         Self {
             era_info: DappsStaking::general_era_info(era).unwrap(),
             dapp_info: RegisteredDapps::<TestRuntime>::get(contract_id).unwrap(),
+            dapp_frozen: FrozenDapps::<TestRuntime>::contains_key(contract_id),
             staker_info: Default::default(),
             contract_info: DappsStaking::contract_stake_info(contract_id, era).unwrap_or_default(),
             ledger: Default::default(),
@@ -69,6 +72,23 @@ pub(crate) fn get_total_reward_per_era() -> Balance {
 
 /// Used to register contract for staking and assert success.
 pub(crate) fn assert_register(developer: AccountId, contract_id: &MockSmartContract<AccountId>) {
+    assert_register_with_origin(developer, contract_id, false);
+}
+
+/// Used to register own contract for staking via `self_register` and assert success.
+pub(crate) fn assert_self_register(
+    developer: AccountId,
+    contract_id: &MockSmartContract<AccountId>,
+) {
+    assert_register_with_origin(developer, contract_id, true);
+}
+
+/// Registers contract either via privileged origin or by the developer itself.
+fn assert_register_with_origin(
+    developer: AccountId,
+    contract_id: &MockSmartContract<AccountId>,
+    self_register: bool,
+) {
     let init_reserved_balance = <TestRuntime as Config>::Currency::reserved_balance(&developer);
 
     // Contract shouldn't exist.
@@ -78,11 +98,22 @@ pub(crate) fn assert_register(developer: AccountId, contract_id: &MockSmartContr
     ));
 
     // Verify op is successful
-    assert_ok!(DappsStaking::register(
-        RuntimeOrigin::root(),
+    if self_register {
+        assert_ok!(DappsStaking::self_register(
+            RuntimeOrigin::signed(developer),
+            contract_id.clone()
+        ));
+    } else {
+        assert_ok!(DappsStaking::register(
+            RuntimeOrigin::root(),
+            developer,
+            contract_id.clone()
+        ));
+    }
+    System::assert_last_event(mock::RuntimeEvent::DappsStaking(Event::NewContract(
         developer,
-        contract_id.clone()
-    ));
+        contract_id.clone(),
+    )));
 
     let dapp_info = RegisteredDapps::<TestRuntime>::get(contract_id).unwrap();
     assert_eq!(dapp_info.state, DAppState::Registered);
@@ -101,18 +132,46 @@ pub(crate) fn assert_register(developer: AccountId, contract_id: &MockSmartContr
 
 /// Perform `unregister` with all the accompanied checks including before/after storage comparison.
 pub(crate) fn assert_unregister(developer: AccountId, contract_id: &MockSmartContract<AccountId>) {
+    assert_unregister_with_origin(developer, contract_id, false);
+}
+
+/// Perform `self_unregister` with all the accompanied checks including before/after storage comparison.
+pub(crate) fn assert_self_unregister(
+    developer: AccountId,
+    contract_id: &MockSmartContract<AccountId>,
+) {
+    assert_unregister_with_origin(developer, contract_id, true);
+}
+
+/// Unregisters contract either via privileged origin or by the developer itself.
+fn assert_unregister_with_origin(
+    developer: AccountId,
+    contract_id: &MockSmartContract<AccountId>,
+    self_unregister: bool,
+) {
     let current_era = DappsStaking::current_era();
     let init_state = MemorySnapshot::contract(current_era, contract_id);
     let init_reserved_balance = <TestRuntime as Config>::Currency::reserved_balance(&developer);
+    let reserved_deposit = <TestRuntime as Config>::RegisterDeposit::get()
+        - DappsStaking::slashed_deposit(contract_id)
+        + DappsStaking::dapp_metadata(contract_id)
+            .map_or(0, |(_, metadata_deposit)| metadata_deposit);
 
     // dApp should be registered prior to unregistering it
     assert_eq!(init_state.dapp_info.state, DAppState::Registered);
 
     // Ensure that contract can be unregistered
-    assert_ok!(DappsStaking::unregister(
-        RuntimeOrigin::root(),
-        contract_id.clone()
-    ));
+    if self_unregister {
+        assert_ok!(DappsStaking::self_unregister(
+            RuntimeOrigin::signed(developer),
+            contract_id.clone()
+        ));
+    } else {
+        assert_ok!(DappsStaking::unregister(
+            RuntimeOrigin::root(),
+            contract_id.clone()
+        ));
+    }
     System::assert_last_event(mock::RuntimeEvent::DappsStaking(Event::ContractRemoved(
         developer,
         contract_id.clone(),
@@ -122,8 +181,10 @@ pub(crate) fn assert_unregister(developer: AccountId, contract_id: &MockSmartCon
     let final_reserved_balance = <TestRuntime as Config>::Currency::reserved_balance(&developer);
     assert_eq!(
         final_reserved_balance,
-        init_reserved_balance - <TestRuntime as Config>::RegisterDeposit::get()
+        init_reserved_balance - reserved_deposit
     );
+    assert!(!SlashedDeposits::<TestRuntime>::contains_key(contract_id));
+    assert!(!DappMetadata::<TestRuntime>::contains_key(contract_id));
 
     assert_eq!(final_state.era_info.staked, init_state.era_info.staked);
 
@@ -143,6 +204,58 @@ pub(crate) fn assert_unregister(developer: AccountId, contract_id: &MockSmartCon
     assert_eq!(final_state.dapp_info.developer, developer);
 }
 
+/// Perform `reregister` with all the accompanied checks including before/after storage comparison.
+pub(crate) fn assert_reregister(contract_id: &MockSmartContract<AccountId>) {
+    let current_era = DappsStaking::current_era();
+    let init_state = MemorySnapshot::contract(current_era, contract_id);
+    let developer = init_state.dapp_info.developer;
+    let init_reserved_balance = <TestRuntime as Config>::Currency::reserved_balance(&developer);
+    let init_unregistered_periods = DappsStaking::unregistered_periods(contract_id);
+
+    // dApp should be unregistered prior to registering it again
+    let unregistered_era = if let DAppState::Unregistered(era) = init_state.dapp_info.state {
+        era
+    } else {
+        panic!("Contract should be unregistered.")
+    };
+
+    assert_ok!(DappsStaking::reregister(
+        RuntimeOrigin::root(),
+        contract_id.clone()
+    ));
+    System::assert_last_event(mock::RuntimeEvent::DappsStaking(
+        Event::ContractReregistered(developer, contract_id.clone()),
+    ));
+
+    let final_state = MemorySnapshot::contract(current_era, contract_id);
+    assert_eq!(final_state.dapp_info.state, DAppState::Registered);
+    assert_eq!(final_state.dapp_info.developer, developer);
+    assert_eq!(
+        <TestRuntime as Config>::Currency::reserved_balance(&developer),
+        init_reserved_balance + <TestRuntime as Config>::RegisterDeposit::get()
+    );
+
+    // Stakes which weren't withdrawn remain on the contract, recorded for the current era
+    assert_eq!(final_state.era_info, init_state.era_info);
+    assert_eq!(final_state.contract_info, init_state.contract_info);
+    if init_state.contract_info.total > 0 {
+        assert_eq!(
+            DappsStaking::contract_stake_history(contract_id).last(),
+            Some(&current_era)
+        );
+    }
+
+    // Eras in which the contract was unregistered are recorded, if there were any
+    let mut expected_unregistered_periods = init_unregistered_periods.into_inner();
+    if unregistered_era < current_era {
+        expected_unregistered_periods.push((unregistered_era, current_era));
+    }
+    assert_eq!(
+        DappsStaking::unregistered_periods(contract_id).into_inner(),
+        expected_unregistered_periods
+    );
+}
+
 /// Perform `withdraw_from_unregistered` with all the accompanied checks including before/after storage comparison.
 pub(crate) fn assert_withdraw_from_unregistered(
     staker: AccountId,
@@ -182,6 +295,14 @@ pub(crate) fn assert_withdraw_from_unregistered(
         final_state.era_info.locked + staked_value
     );
     assert_eq!(init_state.dapp_info, final_state.dapp_info);
+    assert_eq!(
+        init_state.contract_info.total,
+        final_state.contract_info.total + staked_value
+    );
+    assert_eq!(
+        init_state.contract_info.number_of_stakers,
+        final_state.contract_info.number_of_stakers + 1
+    );
     assert_eq!(
         init_state.ledger.locked,
         final_state.ledger.locked + staked_value
@@ -203,9 +324,30 @@ pub(crate) fn assert_bond_and_stake(
     staker: AccountId,
     contract_id: &MockSmartContract<AccountId>,
     value: Balance,
+) {
+    assert_bond_and_stake_with_lock_option(staker, contract_id, value, None);
+}
+
+/// Perform `bond_and_stake_with_lock` with all the accompanied checks including before/after storage comparison.
+pub(crate) fn assert_bond_and_stake_with_lock(
+    staker: AccountId,
+    contract_id: &MockSmartContract<AccountId>,
+    value: Balance,
+    lock_eras: EraIndex,
+) {
+    assert_bond_and_stake_with_lock_option(staker, contract_id, value, Some(lock_eras));
+}
+
+/// Bonds and stakes either without lock or with the stake locked for `lock_eras`.
+fn assert_bond_and_stake_with_lock_option(
+    staker: AccountId,
+    contract_id: &MockSmartContract<AccountId>,
+    value: Balance,
+    lock_eras: Option<EraIndex>,
 ) {
     let current_era = DappsStaking::current_era();
     let init_state = MemorySnapshot::all(current_era, &contract_id, staker);
+    let init_stake_locks = DappsStaking::stake_locks(&staker, contract_id);
 
     // Calculate the expected value that will be staked.
     let available_for_staking = init_state.free_balance
@@ -214,18 +356,65 @@ pub(crate) fn assert_bond_and_stake(
     let staking_value = available_for_staking.min(value);
 
     // Perform op and verify everything is as expected
-    assert_ok!(DappsStaking::bond_and_stake(
-        RuntimeOrigin::signed(staker),
-        contract_id.clone(),
-        value,
-    ));
-    System::assert_last_event(mock::RuntimeEvent::DappsStaking(Event::BondAndStake(
-        staker,
-        contract_id.clone(),
-        staking_value,
-    )));
+    let boost = if let Some(lock_eras) = lock_eras {
+        let (_, boost) = <TestRuntime as Config>::StakeLockOptions::get()
+            .into_iter()
+            .find(|(eras, _)| *eras == lock_eras)
+            .expect("Lock duration must be one of the options.");
+        let unlock_era = current_era + lock_eras;
+        let init_expiring_boost = expiring_boost(contract_id, unlock_era);
+
+        assert_ok!(DappsStaking::bond_and_stake_with_lock(
+            RuntimeOrigin::signed(staker),
+            contract_id.clone(),
+            value,
+            lock_eras,
+        ));
+        System::assert_last_event(mock::RuntimeEvent::DappsStaking(
+            Event::BondAndStakeWithLock(staker, contract_id.clone(), staking_value, unlock_era),
+        ));
+
+        let boost = boost * staking_value;
+        let stake_locks = DappsStaking::stake_locks(&staker, contract_id);
+        assert!(stake_locks.iter().any(|stake_lock| {
+            stake_lock.start_era == current_era && stake_lock.unlock_era == unlock_era
+        }));
+        assert_eq!(
+            locked_stake_amount(&stake_locks, current_era),
+            locked_stake_amount(&init_stake_locks, current_era) + staking_value
+        );
+        assert_eq!(
+            expiring_boost(contract_id, unlock_era),
+            init_expiring_boost + boost
+        );
+        boost
+    } else {
+        assert_ok!(DappsStaking::bond_and_stake(
+            RuntimeOrigin::signed(staker),
+            contract_id.clone(),
+            value,
+        ));
+        System::assert_last_event(mock::RuntimeEvent::DappsStaking(Event::BondAndStake(
+            staker,
+            contract_id.clone(),
+            staking_value,
+        )));
+        assert_eq!(
+            DappsStaking::stake_locks(&staker, contract_id),
+            init_stake_locks
+        );
+        0
+    };
 
     let final_state = MemorySnapshot::all(current_era, &contract_id, staker);
+    assert_eq!(
+        final_state.era_info.boost,
+        init_state.era_info.boost + boost
+    );
+    assert_eq!(
+        final_state.contract_info.boost,
+        init_state.contract_info.boost + boost
+    );
 
     // In case staker hasn't been staking this contract until now
     if init_state.staker_info.latest_staked_value() == 0 {
@@ -277,11 +466,12 @@ pub(crate) fn assert_unbond_and_unstake(
         .staker_info
         .latest_staked_value()
         .saturating_sub(value);
-    let expected_unbond_amount = if remaining_staked < MINIMUM_STAKING_AMOUNT {
-        init_state.staker_info.latest_staked_value()
-    } else {
-        value
-    };
+    let expected_unbond_amount =
+        if remaining_staked < DappsStaking::staking_parameters().minimum_staking_amount {
+            init_state.staker_info.latest_staked_value()
+        } else {
+            value
+        };
     let remaining_staked = init_state.staker_info.latest_staked_value() - expected_unbond_amount;
 
     // Ensure op is successful and event is emitted
@@ -298,7 +488,7 @@ pub(crate) fn assert_unbond_and_unstake(
 
     // Fetch the latest unbonding info so we can compare it to initial unbonding info
     let final_state = MemorySnapshot::all(current_era, &contract_id, staker);
-    let expected_unlock_era = current_era + UNBONDING_PERIOD;
+    let expected_unlock_era = current_era + DappsStaking::staking_parameters().unbonding_period;
     match init_state
         .ledger
         .unbonding_info
@@ -321,10 +511,10 @@ pub(crate) fn assert_unbond_and_unstake(
 
     // Push the unlocking chunk we expect to have at the end and compare two structs
     let mut unbonding_info = init_state.ledger.unbonding_info.clone();
-    unbonding_info.add(UnlockingChunk {
+    assert_ok!(unbonding_info.add(UnlockingChunk {
         amount: expected_unbond_amount,
-        unlock_era: current_era + UNBONDING_PERIOD,
-    });
+        unlock_era: expected_unlock_era,
+    }));
     assert_eq!(unbonding_info, final_state.ledger.unbonding_info);
 
     // Ensure that total locked value for staker hasn't been changed.
@@ -359,6 +549,106 @@ pub(crate) fn assert_unbond_and_unstake(
     assert_eq!(init_state.era_info.locked, final_state.era_info.locked);
 }
 
+/// Used to perform rebond with success and storage assertions.
+pub(crate) fn assert_rebond(
+    staker: AccountId,
+    contract_id: &MockSmartContract<AccountId>,
+    value: Balance,
+) {
+    let current_era = DappsStaking::current_era();
+    let init_state = MemorySnapshot::all(current_era, &contract_id, staker);
+    let expected_rebond_amount = init_state.ledger.unbonding_info.sum().min(value);
+
+    assert_ok!(DappsStaking::rebond(
+        RuntimeOrigin::signed(staker),
+        contract_id.clone(),
+        value
+    ));
+    System::assert_last_event(mock::RuntimeEvent::DappsStaking(Event::Rebonded(
+        staker,
+        contract_id.clone(),
+        expected_rebond_amount,
+    )));
+
+    let final_state = MemorySnapshot::all(current_era, &contract_id, staker);
+
+    // Funds are moved from unbonding into stake, total locked amount remains the same
+    assert_eq!(
+        init_state.ledger.unbonding_info.sum() - expected_rebond_amount,
+        final_state.ledger.unbonding_info.sum()
+    );
+    assert_eq!(init_state.ledger.locked, final_state.ledger.locked);
+    assert_eq!(init_state.era_info.locked, final_state.era_info.locked);
+    assert_eq!(
+        init_state.era_info.staked + expected_rebond_amount,
+        final_state.era_info.staked
+    );
+
+    assert_eq!(
+        init_state.contract_info.total + expected_rebond_amount,
+        final_state.contract_info.total
+    );
+    assert_eq!(
+        init_state.staker_info.latest_staked_value() + expected_rebond_amount,
+        final_state.staker_info.latest_staked_value()
+    );
+    let delta = if init_state.staker_info.latest_staked_value() > 0 {
+        0
+    } else {
+        1
+    };
+    assert_eq!(
+        init_state.contract_info.number_of_stakers + delta,
+        final_state.contract_info.number_of_stakers
+    );
+}
+
+/// Used to set staking parameters and assert success.
+pub(crate) fn assert_set_staking_parameters(parameters: StakingParametersOf<TestRuntime>) {
+    let init_parameters = DappsStaking::staking_parameters();
+
+    assert_ok!(DappsStaking::set_staking_parameters(
+        RuntimeOrigin::root(),
+        parameters.clone(),
+    ));
+    System::assert_last_event(mock::RuntimeEvent::DappsStaking(
+        Event::StakingParametersScheduled(parameters.clone()),
+    ));
+
+    // New parameters are only used from the next era
+    assert_eq!(DappsStaking::staking_parameters(), init_parameters);
+    assert_eq!(DappsStaking::pending_staking_parameters(), Some(parameters));
+}
+
+/// Used to set dApp commission and assert success.
+pub(crate) fn assert_set_dapp_commission(
+    developer: AccountId,
+    contract_id: &MockSmartContract<AccountId>,
+    commission: Perbill,
+) {
+    let current_era = DappsStaking::current_era();
+    let init_commission = DappsStaking::dapp_commission_at(contract_id, current_era);
+
+    assert_ok!(DappsStaking::set_dapp_commission(
+        RuntimeOrigin::signed(developer),
+        contract_id.clone(),
+        commission,
+    ));
+    System::assert_last_event(mock::RuntimeEvent::DappsStaking(
+        Event::DappCommissionChanged(developer, contract_id.clone(), commission, current_era + 1),
+    ));
+
+    // New commission only applies from the next era
+    assert_eq!(
+        DappsStaking::dapp_commission_at(contract_id, current_era),
+        init_commission
+    );
+    assert_eq!(
+        DappsStaking::dapp_commission_at(contract_id, current_era + 1),
+        commission
+    );
+}
+
 /// Used to perform start_unbonding with success and storage assertions.
 pub(crate) fn assert_withdraw_unbonded(staker: AccountId) {
     let current_era = DappsStaking::current_era();
@@ -399,86 +689,325 @@ pub(crate) fn assert_withdraw_unbonded(staker: AccountId) {
     );
 }
 
-/// Used to perform nomination transfer with success and storage assertions.
-pub(crate) fn assert_nomination_transfer(
+/// Used to perform instant unbond & unstake with success assertion.
+pub(crate) fn assert_instant_unbond_and_unstake(
     staker: AccountId,
-    origin_contract_id: &MockSmartContract<AccountId>,
+    contract_id: &MockSmartContract<AccountId>,
     value: Balance,
-    target_contract_id: &MockSmartContract<AccountId>,
 ) {
-    // Get latest staking info
     let current_era = DappsStaking::current_era();
-    let origin_init_state = MemorySnapshot::all(current_era, &origin_contract_id, staker);
-    let target_init_state = MemorySnapshot::all(current_era, &target_contract_id, staker);
-
-    // Calculate value which will actually be transfered
-    let init_staked_value = origin_init_state.staker_info.latest_staked_value();
-    let expected_transfer_amount = if init_staked_value - value >= MINIMUM_STAKING_AMOUNT {
-        value
-    } else {
-        init_staked_value
-    };
+    let init_state = MemorySnapshot::all(current_era, contract_id, staker);
+    let init_treasury_balance = Balances::free_balance(&TREASURY_ACCOUNT);
 
-    // Ensure op is successful and event is emitted
-    assert_ok!(DappsStaking::nomination_transfer(
+    // Calculate the expected resulting unbonding amount
+    let remaining_staked = init_state
+        .staker_info
+        .latest_staked_value()
+        .saturating_sub(value);
+    let expected_unbond_amount =
+        if remaining_staked < DappsStaking::staking_parameters().minimum_staking_amount {
+            init_state.staker_info.latest_staked_value()
+        } else {
+            value
+        };
+    let expected_penalty =
+        DappsStaking::staking_parameters().instant_unbond_penalty * expected_unbond_amount;
+
+    assert_ok!(DappsStaking::instant_unbond_and_unstake(
         RuntimeOrigin::signed(staker),
-        origin_contract_id.clone(),
-        value,
-        target_contract_id.clone()
+        contract_id.clone(),
+        value
+    ));
+    System::assert_last_event(mock::RuntimeEvent::DappsStaking(
+        Event::InstantUnbondAndUnstake(
+            staker,
+            contract_id.clone(),
+            expected_unbond_amount,
+            expected_penalty,
+        ),
     ));
-    System::assert_last_event(mock::RuntimeEvent::DappsStaking(Event::NominationTransfer(
-        staker,
-        origin_contract_id.clone(),
-        expected_transfer_amount,
-        target_contract_id.clone(),
-    )));
 
-    let origin_final_state = MemorySnapshot::all(current_era, &origin_contract_id, staker);
-    let target_final_state = MemorySnapshot::all(current_era, &target_contract_id, staker);
+    let final_state = MemorySnapshot::all(current_era, contract_id, staker);
 
-    // Ensure staker info has increased/decreased staked amount
+    // Funds are unlocked immediately, without any unlocking chunks
     assert_eq!(
-        origin_final_state.staker_info.latest_staked_value(),
-        init_staked_value - expected_transfer_amount
+        init_state.ledger.unbonding_info,
+        final_state.ledger.unbonding_info
     );
     assert_eq!(
-        target_final_state.staker_info.latest_staked_value(),
-        target_init_state.staker_info.latest_staked_value() + expected_transfer_amount
+        init_state.ledger.locked - expected_unbond_amount,
+        final_state.ledger.locked
     );
+    if final_state.ledger.is_empty() {
+        assert!(!Ledger::<TestRuntime>::contains_key(&staker));
+    }
 
-    // Ensure total value staked on contracts has appropriately increased/decreased
+    // Penalty is taken from the staker
     assert_eq!(
-        origin_final_state.contract_info.total,
-        origin_init_state.contract_info.total - expected_transfer_amount
+        init_state.free_balance - expected_penalty,
+        final_state.free_balance
     );
     assert_eq!(
-        target_final_state.contract_info.total,
-        target_init_state.contract_info.total + expected_transfer_amount
+        Balances::free_balance(&TREASURY_ACCOUNT),
+        init_treasury_balance + expected_penalty
     );
 
-    // Ensure number of contracts has been reduced on origin contract if it is fully unstaked
-    let origin_contract_fully_unstaked = init_staked_value == expected_transfer_amount;
-    if origin_contract_fully_unstaked {
-        assert_eq!(
-            origin_final_state.contract_info.number_of_stakers + 1,
-            origin_init_state.contract_info.number_of_stakers
-        );
-    }
+    assert_eq!(
+        init_state.contract_info.total - expected_unbond_amount,
+        final_state.contract_info.total
+    );
+    assert_eq!(
+        init_state.staker_info.latest_staked_value() - expected_unbond_amount,
+        final_state.staker_info.latest_staked_value()
+    );
+    assert_eq!(
+        init_state.era_info.staked - expected_unbond_amount,
+        final_state.era_info.staked
+    );
+    assert_eq!(
+        init_state.era_info.locked - expected_unbond_amount,
+        final_state.era_info.locked
+    );
+}
 
-    // Ensure number of contracts has been increased on target contract it is first stake by the staker
-    let no_init_stake_on_target_contract = target_init_state
-        .staker_info
-        .latest_staked_value()
-        .is_zero();
-    if no_init_stake_on_target_contract {
-        assert_eq!(
-            target_final_state.contract_info.number_of_stakers,
-            target_init_state.contract_info.number_of_stakers + 1
-        );
-    }
+/// Used to perform instant withdraw of all unbonding funds with success assertion.
+pub(crate) fn assert_instant_withdraw_unbonded(staker: AccountId) {
+    let current_era = DappsStaking::current_era();
 
-    // Ensure DB entry has been removed if era stake vector is empty
-    let fully_unstaked_and_nothing_to_claim =
+    let init_era_info = GeneralEraInfo::<TestRuntime>::get(current_era).unwrap();
+    let init_ledger = Ledger::<TestRuntime>::get(&staker);
+    let init_free_balance = Balances::free_balance(&staker);
+    let init_treasury_balance = Balances::free_balance(&TREASURY_ACCOUNT);
+
+    // All chunks are withdrawn, regardless of their unlock era
+    let expected_withdraw_amount = init_ledger.unbonding_info.sum();
+    let expected_penalty =
+        DappsStaking::staking_parameters().instant_unbond_penalty * expected_withdraw_amount;
+
+    assert_ok!(DappsStaking::instant_withdraw_unbonded(
+        RuntimeOrigin::signed(staker)
+    ));
+    System::assert_last_event(mock::RuntimeEvent::DappsStaking(Event::InstantWithdrawn(
+        staker,
+        expected_withdraw_amount,
+        expected_penalty,
+    )));
+
+    let final_ledger = Ledger::<TestRuntime>::get(&staker);
+    assert!(final_ledger.unbonding_info.is_empty());
+    assert_eq!(
+        final_ledger.locked,
+        init_ledger.locked - expected_withdraw_amount
+    );
+    if final_ledger.locked == 0 {
+        assert!(!Ledger::<TestRuntime>::contains_key(&staker));
+    }
+
+    assert_eq!(
+        Balances::free_balance(&staker),
+        init_free_balance - expected_penalty
+    );
+    assert_eq!(
+        Balances::free_balance(&TREASURY_ACCOUNT),
+        init_treasury_balance + expected_penalty
+    );
+
+    let final_era_info = GeneralEraInfo::<TestRuntime>::get(current_era).unwrap();
+    assert_eq!(final_era_info.staked, init_era_info.staked);
+    assert_eq!(
+        final_era_info.locked,
+        init_era_info.locked - expected_withdraw_amount
+    );
+}
+
+/// Used to perform nomination transfer with success and storage assertions.
+pub(crate) fn assert_nomination_transfer(
+    staker: AccountId,
+    origin_contract_id: &MockSmartContract<AccountId>,
+    value: Balance,
+    target_contract_id: &MockSmartContract<AccountId>,
+) {
+    assert_nomination_transfer_by(None, staker, origin_contract_id, value, target_contract_id);
+}
+
+/// Used to perform nomination transfer by the `operator` on behalf of `staker` with success and storage assertions.
+pub(crate) fn assert_operator_nomination_transfer(
+    operator: AccountId,
+    staker: AccountId,
+    origin_contract_id: &MockSmartContract<AccountId>,
+    value: Balance,
+    target_contract_id: &MockSmartContract<AccountId>,
+) {
+    assert_nomination_transfer_by(
+        Some(operator),
+        staker,
+        origin_contract_id,
+        value,
+        target_contract_id,
+    );
+}
+
+/// Used to perform batch nomination transfer with success and storage assertions.
+/// Transfer values are expected to be moved in full.
+pub(crate) fn assert_batch_nomination_transfer(
+    staker: AccountId,
+    transfers: Vec<(
+        MockSmartContract<AccountId>,
+        MockSmartContract<AccountId>,
+        Balance,
+    )>,
+) {
+    let current_era = DappsStaking::current_era();
+
+    let mut contract_ids = Vec::new();
+    for (origin_contract_id, target_contract_id, _) in &transfers {
+        for contract_id in [origin_contract_id, target_contract_id] {
+            if !contract_ids.contains(contract_id) {
+                contract_ids.push(*contract_id);
+            }
+        }
+    }
+    let init_states: Vec<_> = contract_ids
+        .iter()
+        .map(|contract_id| MemorySnapshot::all(current_era, contract_id, staker))
+        .collect();
+
+    assert_ok!(DappsStaking::batch_nomination_transfer(
+        RuntimeOrigin::signed(staker),
+        transfers.clone().try_into().unwrap()
+    ));
+
+    // Each move is reported via its own event
+    let (last_origin_contract_id, last_target_contract_id, last_value) =
+        transfers.last().unwrap().clone();
+    System::assert_last_event(mock::RuntimeEvent::DappsStaking(Event::NominationTransfer(
+        staker,
+        last_origin_contract_id,
+        last_value,
+        last_target_contract_id,
+    )));
+
+    // Ensure staked amounts have been moved between the contracts
+    for (contract_id, init_state) in contract_ids.iter().zip(init_states) {
+        let incoming: Balance = transfers
+            .iter()
+            .filter(|(_, target_contract_id, _)| target_contract_id == contract_id)
+            .map(|(_, _, value)| value)
+            .sum();
+        let outgoing: Balance = transfers
+            .iter()
+            .filter(|(origin_contract_id, _, _)| origin_contract_id == contract_id)
+            .map(|(_, _, value)| value)
+            .sum();
+
+        let final_state = MemorySnapshot::all(current_era, contract_id, staker);
+        assert_eq!(
+            final_state.staker_info.latest_staked_value() + outgoing,
+            init_state.staker_info.latest_staked_value() + incoming
+        );
+        assert_eq!(
+            final_state.contract_info.total + outgoing,
+            init_state.contract_info.total + incoming
+        );
+
+        // Nothing is unbonded or unlocked
+        assert_eq!(final_state.ledger, init_state.ledger);
+        assert_eq!(final_state.era_info, init_state.era_info);
+    }
+}
+
+/// Transfers nomination, either by the staker itself or by the `operator` on staker's behalf.
+fn assert_nomination_transfer_by(
+    operator: Option<AccountId>,
+    staker: AccountId,
+    origin_contract_id: &MockSmartContract<AccountId>,
+    value: Balance,
+    target_contract_id: &MockSmartContract<AccountId>,
+) {
+    // Get latest staking info
+    let current_era = DappsStaking::current_era();
+    let origin_init_state = MemorySnapshot::all(current_era, &origin_contract_id, staker);
+    let target_init_state = MemorySnapshot::all(current_era, &target_contract_id, staker);
+
+    // Calculate value which will actually be transfered
+    let init_staked_value = origin_init_state.staker_info.latest_staked_value();
+    let expected_transfer_amount =
+        if init_staked_value - value >= DappsStaking::staking_parameters().minimum_staking_amount {
+            value
+        } else {
+            init_staked_value
+        };
+
+    // Ensure op is successful and event is emitted
+    if let Some(operator) = operator {
+        assert_ok!(DappsStaking::operator_nomination_transfer(
+            RuntimeOrigin::signed(operator),
+            staker,
+            origin_contract_id.clone(),
+            value,
+            target_contract_id.clone()
+        ));
+    } else {
+        assert_ok!(DappsStaking::nomination_transfer(
+            RuntimeOrigin::signed(staker),
+            origin_contract_id.clone(),
+            value,
+            target_contract_id.clone()
+        ));
+    }
+    System::assert_last_event(mock::RuntimeEvent::DappsStaking(Event::NominationTransfer(
+        staker,
+        origin_contract_id.clone(),
+        expected_transfer_amount,
+        target_contract_id.clone(),
+    )));
+
+    let origin_final_state = MemorySnapshot::all(current_era, &origin_contract_id, staker);
+    let target_final_state = MemorySnapshot::all(current_era, &target_contract_id, staker);
+
+    // Ensure staker info has increased/decreased staked amount
+    assert_eq!(
+        origin_final_state.staker_info.latest_staked_value(),
+        init_staked_value - expected_transfer_amount
+    );
+    assert_eq!(
+        target_final_state.staker_info.latest_staked_value(),
+        target_init_state.staker_info.latest_staked_value() + expected_transfer_amount
+    );
+
+    // Ensure total value staked on contracts has appropriately increased/decreased
+    assert_eq!(
+        origin_final_state.contract_info.total,
+        origin_init_state.contract_info.total - expected_transfer_amount
+    );
+    assert_eq!(
+        target_final_state.contract_info.total,
+        target_init_state.contract_info.total + expected_transfer_amount
+    );
+
+    // Ensure number of contracts has been reduced on origin contract if it is fully unstaked
+    let origin_contract_fully_unstaked = init_staked_value == expected_transfer_amount;
+    if origin_contract_fully_unstaked {
+        assert_eq!(
+            origin_final_state.contract_info.number_of_stakers + 1,
+            origin_init_state.contract_info.number_of_stakers
+        );
+    }
+
+    // Ensure number of contracts has been increased on target contract it is first stake by the staker
+    let no_init_stake_on_target_contract = target_init_state
+        .staker_info
+        .latest_staked_value()
+        .is_zero();
+    if no_init_stake_on_target_contract {
+        assert_eq!(
+            target_final_state.contract_info.number_of_stakers,
+            target_init_state.contract_info.number_of_stakers + 1
+        );
+    }
+
+    // Ensure DB entry has been removed if era stake vector is empty
+    let fully_unstaked_and_nothing_to_claim =
         origin_contract_fully_unstaked && origin_final_state.staker_info.clone().claim() == (0, 0);
     if fully_unstaked_and_nothing_to_claim {
         assert!(!GeneralStakerInfo::<TestRuntime>::contains_key(
@@ -488,9 +1017,52 @@ pub(crate) fn assert_nomination_transfer(
     }
 }
 
+/// Total amount of the stake locks which are still locked in the specified era.
+pub(crate) fn locked_stake_amount(
+    stake_locks: &StakeLocksOf<TestRuntime>,
+    era: EraIndex,
+) -> Balance {
+    stake_locks
+        .iter()
+        .filter(|stake_lock| stake_lock.unlock_era > era)
+        .map(|stake_lock| stake_lock.amount)
+        .sum()
+}
+
+/// Boost of the contract which is scheduled to expire at the start of the specified era.
+pub(crate) fn expiring_boost(contract_id: &MockSmartContract<AccountId>, era: EraIndex) -> Balance {
+    DappsStaking::boost_expiries(era)
+        .iter()
+        .find(|(expiring_contract_id, _)| expiring_contract_id == contract_id)
+        .map_or(0, |(_, boost)| *boost)
+}
+
+/// Portion of the contract stakers reward which belongs to the staker in the specified era,
+/// taking the boost of the time-locked stakes into account.
+fn staker_reward_portion(
+    staker: AccountId,
+    contract_id: &MockSmartContract<AccountId>,
+    era: EraIndex,
+    staked: Balance,
+    contract_info: &ContractStakeInfo<Balance>,
+) -> Perbill {
+    let boost: Balance = DappsStaking::stake_locks(&staker, contract_id)
+        .iter()
+        .filter(|stake_lock| stake_lock.is_locked_in(era))
+        .map(|stake_lock| stake_lock.boost)
+        .sum();
+    Perbill::from_rational(staked + boost, contract_info.total + contract_info.boost)
+}
+
 /// Used to perform claim for stakers with success assertion
 pub(crate) fn assert_claim_staker(claimer: AccountId, contract_id: &MockSmartContract<AccountId>) {
-    let (claim_era, _) = DappsStaking::staker_info(&claimer, contract_id).claim();
+    // Eras in which the contract was unregistered are skipped since they yield no rewards
+    let mut staker_info = DappsStaking::staker_info(&claimer, contract_id);
+    DappsStaking::skip_unregistered_periods(
+        &mut staker_info,
+        &DappsStaking::unregistered_periods(contract_id),
+    );
+    let (claim_era, _) = staker_info.clone().claim();
     let current_era = DappsStaking::current_era();
 
     //clean up possible leftover events
@@ -500,12 +1072,16 @@ pub(crate) fn assert_claim_staker(claimer: AccountId, contract_id: &MockSmartCon
     let init_state_current_era = MemorySnapshot::all(current_era, contract_id, claimer);
 
     // Calculate contract portion of the reward
-    let (_, stakers_joint_reward) = DappsStaking::dev_stakers_split(
+    let (developer_reward, stakers_joint_reward) = DappsStaking::dev_stakers_split(
         &init_state_claim_era.contract_info,
         &init_state_claim_era.era_info,
     );
+    let (_, shared_reward) = DappsStaking::commission_split(
+        developer_reward,
+        DappsStaking::dapp_commission_at(contract_id, claim_era),
+    );
 
-    let (claim_era, staked) = init_state_claim_era.staker_info.clone().claim();
+    let (claim_era, staked) = staker_info.claim();
     assert!(claim_era > 0); // Sanity check - if this fails, method is being used incorrectly
 
     // Cannot claim rewards post unregister era, this indicates a bug!
@@ -513,9 +1089,16 @@ pub(crate) fn assert_claim_staker(claimer: AccountId, contract_id: &MockSmartCon
         assert!(unregistered_era > claim_era);
     }
 
-    let calculated_reward =
-        Perbill::from_rational(staked, init_state_claim_era.contract_info.total)
-            * stakers_joint_reward;
+    let staker_portion = staker_reward_portion(
+        claimer,
+        contract_id,
+        claim_era,
+        staked,
+        &init_state_claim_era.contract_info,
+    );
+    let staking_reward = staker_portion * stakers_joint_reward;
+    let commission_reward = staker_portion * shared_reward;
+    let calculated_reward = staking_reward + commission_reward;
     let issuance_before_claim = <TestRuntime as Config>::Currency::total_issuance();
 
     assert_ok!(DappsStaking::claim_staker(
@@ -532,29 +1115,38 @@ pub(crate) fn assert_claim_staker(claimer: AccountId, contract_id: &MockSmartCon
         calculated_reward,
     );
 
-    // check for stake event if restaking is performed
-    if DappsStaking::should_restake_reward(
-        init_state_current_era.ledger.reward_destination,
-        init_state_current_era.dapp_info.state,
-        init_state_current_era.staker_info.latest_staked_value(),
-    ) {
-        // There should be at least 2 events, Reward and BondAndStake.
-        // if there's less, panic is acceptable
-        let events = dapps_staking_events();
-        let second_last_event = &events[events.len() - 2];
-        assert_eq!(
-            second_last_event.clone(),
-            Event::<TestRuntime>::BondAndStake(claimer, contract_id.clone(), calculated_reward)
-        );
-    }
-
-    // last event should be Reward, regardless of restaking
-    System::assert_last_event(mock::RuntimeEvent::DappsStaking(Event::Reward(
+    // Reward event is expected regardless of restaking, followed by the split if part of it comes from the commission
+    let mut expected_events = vec![Event::<TestRuntime>::Reward(
         claimer,
         contract_id.clone(),
         claim_era,
         calculated_reward,
-    )));
+    )];
+    if commission_reward > 0 {
+        expected_events.push(Event::<TestRuntime>::StakerRewardSplit(
+            claimer,
+            contract_id.clone(),
+            claim_era,
+            staking_reward,
+            commission_reward,
+        ));
+    }
+
+    // check for stake event if restaking is performed
+    let restaked_reward = reward_to_restake(&init_state_current_era, calculated_reward);
+    if restaked_reward > 0 {
+        expected_events.insert(
+            0,
+            Event::<TestRuntime>::BondAndStake(claimer, contract_id.clone(), restaked_reward),
+        );
+    }
+
+    // if there are less events than expected, panic is acceptable
+    let events = dapps_staking_events();
+    assert_eq!(
+        &events[events.len() - expected_events.len()..],
+        &expected_events[..]
+    );
 
     let (new_era, _) = final_state_current_era.staker_info.clone().claim();
     if final_state_current_era.staker_info.is_empty() {
@@ -580,33 +1172,258 @@ pub(crate) fn assert_claim_staker(claimer: AccountId, contract_id: &MockSmartCon
     );
 }
 
-// assert staked and locked states depending on should_restake_reward
-// returns should_restake_reward result so further checks can be made
+/// Used to perform claim for stakers when the oldest unclaimed reward has expired, asserting it was swept to the treasury
+pub(crate) fn assert_claim_expired_staker(
+    claimer: AccountId,
+    contract_id: &MockSmartContract<AccountId>,
+) {
+    let (claim_era, staked) = DappsStaking::staker_info(&claimer, contract_id).claim();
+    assert!(claim_era < DappsStaking::current_era() - STAKER_CLAIM_WINDOW);
+
+    let init_state = MemorySnapshot::all(claim_era, contract_id, claimer);
+    let init_treasury_balance = Balances::free_balance(&TREASURY_ACCOUNT);
+
+    // Expired reward is calculated the same way as the claimed one
+    let (developer_reward, stakers_joint_reward) =
+        DappsStaking::dev_stakers_split(&init_state.contract_info, &init_state.era_info);
+    let (_, shared_reward) = DappsStaking::commission_split(
+        developer_reward,
+        DappsStaking::dapp_commission_at(contract_id, claim_era),
+    );
+    let staker_portion = staker_reward_portion(
+        claimer,
+        contract_id,
+        claim_era,
+        staked,
+        &init_state.contract_info,
+    );
+    let expired_reward = staker_portion * stakers_joint_reward + staker_portion * shared_reward;
+
+    assert_ok!(DappsStaking::claim_staker(
+        RuntimeOrigin::signed(claimer),
+        contract_id.clone(),
+    ));
+    System::assert_last_event(mock::RuntimeEvent::DappsStaking(
+        Event::StakerRewardExpired(claimer, contract_id.clone(), claim_era, expired_reward),
+    ));
+
+    // Staker doesn't receive anything, reward goes to the treasury
+    let final_state = MemorySnapshot::all(claim_era, contract_id, claimer);
+    assert_eq!(init_state.free_balance, final_state.free_balance);
+    assert_eq!(init_state.ledger, final_state.ledger);
+    assert_eq!(
+        Balances::free_balance(&TREASURY_ACCOUNT),
+        init_treasury_balance + expired_reward
+    );
+
+    let (new_era, _) = final_state.staker_info.clone().claim();
+    assert!(new_era.is_zero() || new_era > claim_era);
+}
+
+/// Used to perform batch claim for staker rewards with success assertion
+pub(crate) fn assert_claim_staker_batch(
+    claimer: AccountId,
+    contract_id: &MockSmartContract<AccountId>,
+    number_of_eras: u32,
+) {
+    assert_claim_staker_multiple_eras(None, claimer, contract_id, number_of_eras);
+}
+
+/// Used to perform claim of staker rewards on behalf of `staker` with success assertion
+pub(crate) fn assert_claim_staker_for(
+    caller: AccountId,
+    staker: AccountId,
+    contract_id: &MockSmartContract<AccountId>,
+    number_of_eras: u32,
+) {
+    assert_claim_staker_multiple_eras(Some(caller), staker, contract_id, number_of_eras);
+}
+
+/// Claims staker rewards for multiple eras, either by the staker itself or by the `caller` on staker's behalf.
+fn assert_claim_staker_multiple_eras(
+    caller: Option<AccountId>,
+    claimer: AccountId,
+    contract_id: &MockSmartContract<AccountId>,
+    number_of_eras: u32,
+) {
+    let current_era = DappsStaking::current_era();
+
+    //clean up possible leftover events
+    System::reset_events();
+
+    let init_state_current_era = MemorySnapshot::all(current_era, contract_id, claimer);
+
+    // Eras starting from the unregistration era cannot be claimed
+    let claim_era_limit = match init_state_current_era.dapp_info.state {
+        DAppState::Registered => current_era,
+        DAppState::Unregistered(unregistered_era) => unregistered_era.min(current_era),
+    };
+
+    // Calculate expected reward for each era that should be claimed
+    let unregistered_periods = DappsStaking::unregistered_periods(contract_id);
+    let mut staker_info = init_state_current_era.staker_info.clone();
+    let mut expected_rewards = Vec::new();
+    while (expected_rewards.len() as u32) < number_of_eras {
+        DappsStaking::skip_unregistered_periods(&mut staker_info, &unregistered_periods);
+        let (claim_era, staked) = staker_info.clone().claim();
+        if staked.is_zero() || claim_era >= claim_era_limit {
+            break;
+        }
+        staker_info.claim();
+
+        let claim_era_state = MemorySnapshot::contract(claim_era, contract_id);
+        let (developer_reward, stakers_joint_reward) = DappsStaking::dev_stakers_split(
+            &claim_era_state.contract_info,
+            &claim_era_state.era_info,
+        );
+        let (_, shared_reward) = DappsStaking::commission_split(
+            developer_reward,
+            DappsStaking::dapp_commission_at(contract_id, claim_era),
+        );
+        let staker_portion = staker_reward_portion(
+            claimer,
+            contract_id,
+            claim_era,
+            staked,
+            &claim_era_state.contract_info,
+        );
+        expected_rewards.push((
+            claim_era,
+            staker_portion * stakers_joint_reward,
+            staker_portion * shared_reward,
+        ));
+    }
+    // Sanity check - if this fails, method is being used incorrectly
+    assert!(!expected_rewards.is_empty());
+
+    let total_reward: Balance = expected_rewards
+        .iter()
+        .map(|(_, staking_reward, commission_reward)| staking_reward + commission_reward)
+        .sum();
+    let issuance_before_claim = <TestRuntime as Config>::Currency::total_issuance();
+    let caller_init_balance =
+        caller.map(|caller| <TestRuntime as Config>::Currency::free_balance(&caller));
+
+    if let Some(caller) = caller {
+        assert_ok!(DappsStaking::claim_staker_for(
+            RuntimeOrigin::signed(caller),
+            claimer,
+            contract_id.clone(),
+            number_of_eras,
+        ));
+
+        // Caller mustn't receive any part of the reward
+        assert_eq!(
+            caller_init_balance,
+            Some(<TestRuntime as Config>::Currency::free_balance(&caller))
+        );
+    } else {
+        assert_ok!(DappsStaking::claim_staker_batch(
+            RuntimeOrigin::signed(claimer),
+            contract_id.clone(),
+            number_of_eras,
+        ));
+    }
+
+    let final_state_current_era = MemorySnapshot::all(current_era, contract_id, claimer);
+
+    // assert staked and free balances depending on restake check,
+    assert_restake_reward(
+        &init_state_current_era,
+        &final_state_current_era,
+        total_reward,
+    );
+
+    // Each claimed era should be reported by a `Reward` event (and the split, if commission applies),
+    // followed by the aggregated event
+    let mut expected_events = Vec::new();
+    for (era, staking_reward, commission_reward) in expected_rewards.iter() {
+        expected_events.push(Event::<TestRuntime>::Reward(
+            claimer,
+            contract_id.clone(),
+            *era,
+            staking_reward + commission_reward,
+        ));
+        if *commission_reward > 0 {
+            expected_events.push(Event::<TestRuntime>::StakerRewardSplit(
+                claimer,
+                contract_id.clone(),
+                *era,
+                *staking_reward,
+                *commission_reward,
+            ));
+        }
+    }
+    let events = dapps_staking_events();
+    assert_eq!(
+        &events[events.len() - 1 - expected_events.len()..events.len() - 1],
+        &expected_events[..]
+    );
+    System::assert_last_event(mock::RuntimeEvent::DappsStaking(Event::BatchRewardClaimed(
+        claimer,
+        contract_id.clone(),
+        expected_rewards.len() as u32,
+        total_reward,
+    )));
+
+    let (last_claimed_era, _, _) = *expected_rewards.last().unwrap();
+    let (new_era, _) = final_state_current_era.staker_info.clone().claim();
+    assert!(new_era.is_zero() || new_era > last_claimed_era);
+
+    // Claim shouldn't mint new tokens, instead it should just transfer from the dapps staking pallet account
+    let issuance_after_claim = <TestRuntime as Config>::Currency::total_issuance();
+    assert_eq!(issuance_before_claim, issuance_after_claim);
+}
+
+// returns part of the reward which is expected to be restaked, depending on should_restake_reward and reward destination
+fn reward_to_restake(init_state_current_era: &MemorySnapshot, reward: Balance) -> Balance {
+    if !init_state_current_era.dapp_frozen
+        && DappsStaking::should_restake_reward(
+            init_state_current_era.ledger.reward_destination,
+            init_state_current_era.dapp_info.state,
+            init_state_current_era.staker_info.latest_staked_value(),
+        )
+    {
+        init_state_current_era
+            .ledger
+            .reward_destination
+            .restake_part(reward)
+    } else {
+        0
+    }
+}
+
+// assert staked and locked states depending on the restaked part of the reward
 fn assert_restake_reward(
     init_state_current_era: &MemorySnapshot,
     final_state_current_era: &MemorySnapshot,
     reward: Balance,
 ) {
-    if DappsStaking::should_restake_reward(
-        init_state_current_era.ledger.reward_destination,
-        init_state_current_era.dapp_info.state,
-        init_state_current_era.staker_info.latest_staked_value(),
-    ) {
-        // staked values should increase
+    let restaked_reward = reward_to_restake(init_state_current_era, reward);
+    if restaked_reward > 0 {
+        // whole reward is paid out, but only the restaked part increases staked values
+        assert_eq!(
+            init_state_current_era.free_balance + reward,
+            final_state_current_era.free_balance
+        );
         assert_eq!(
-            init_state_current_era.staker_info.latest_staked_value() + reward,
+            init_state_current_era.ledger.locked + restaked_reward,
+            final_state_current_era.ledger.locked
+        );
+        assert_eq!(
+            init_state_current_era.staker_info.latest_staked_value() + restaked_reward,
             final_state_current_era.staker_info.latest_staked_value()
         );
         assert_eq!(
-            init_state_current_era.era_info.staked + reward,
+            init_state_current_era.era_info.staked + restaked_reward,
             final_state_current_era.era_info.staked
         );
         assert_eq!(
-            init_state_current_era.era_info.locked + reward,
+            init_state_current_era.era_info.locked + restaked_reward,
             final_state_current_era.era_info.locked
         );
         assert_eq!(
-            init_state_current_era.contract_info.total + reward,
+            init_state_current_era.contract_info.total + restaked_reward,
             final_state_current_era.contract_info.total
         );
     } else {
@@ -641,21 +1458,34 @@ pub(crate) fn assert_claim_dapp(contract_id: &MockSmartContract<AccountId>, clai
         assert!(unregistered_era > claim_era);
     }
 
-    // Calculate contract portion of the reward
-    let (calculated_reward, _) =
+    // Calculate contract portion of the reward, reduced by the part shared with stakers
+    let (developer_reward, _) =
         DappsStaking::dev_stakers_split(&init_state.contract_info, &init_state.era_info);
+    let (calculated_reward, shared_reward) = DappsStaking::commission_split(
+        developer_reward,
+        DappsStaking::dapp_commission_at(contract_id, claim_era),
+    );
 
     assert_ok!(DappsStaking::claim_dapp(
         RuntimeOrigin::signed(developer),
         contract_id.clone(),
         claim_era,
     ));
-    System::assert_last_event(mock::RuntimeEvent::DappsStaking(Event::Reward(
-        developer,
-        contract_id.clone(),
-        claim_era,
-        calculated_reward,
-    )));
+    let reward_event =
+        Event::<TestRuntime>::Reward(developer, contract_id.clone(), claim_era, calculated_reward);
+    if shared_reward > 0 {
+        let events = dapps_staking_events();
+        assert_eq!(events[events.len() - 2], reward_event);
+        System::assert_last_event(mock::RuntimeEvent::DappsStaking(Event::DappRewardSplit(
+            developer,
+            contract_id.clone(),
+            claim_era,
+            calculated_reward,
+            shared_reward,
+        )));
+    } else {
+        System::assert_last_event(mock::RuntimeEvent::DappsStaking(reward_event));
+    }
 
     let final_state = MemorySnapshot::all(claim_era, &contract_id, developer);
     assert_eq!(
@@ -670,6 +1500,84 @@ pub(crate) fn assert_claim_dapp(contract_id: &MockSmartContract<AccountId>, clai
     assert_eq!(init_state.ledger, final_state.ledger);
 }
 
+/// Used to propose contract ownership transfer and assert success.
+pub(crate) fn assert_propose_ownership_transfer(
+    developer: AccountId,
+    contract_id: &MockSmartContract<AccountId>,
+    new_developer: AccountId,
+) {
+    assert_ok!(DappsStaking::propose_ownership_transfer(
+        RuntimeOrigin::signed(developer),
+        contract_id.clone(),
+        new_developer,
+    ));
+    System::assert_last_event(mock::RuntimeEvent::DappsStaking(
+        Event::OwnershipTransferProposed(developer, contract_id.clone(), new_developer),
+    ));
+
+    assert_eq!(
+        PendingOwnershipTransfer::<TestRuntime>::get(contract_id),
+        Some(new_developer)
+    );
+}
+
+/// Used to accept contract ownership transfer and assert success, including the deposit move.
+pub(crate) fn assert_accept_ownership_transfer(
+    new_developer: AccountId,
+    contract_id: &MockSmartContract<AccountId>,
+) {
+    let init_dapp_info = RegisteredDapps::<TestRuntime>::get(contract_id).unwrap();
+    let previous_developer = init_dapp_info.developer;
+    let init_previous_reserved =
+        <TestRuntime as Config>::Currency::reserved_balance(&previous_developer);
+    let init_new_reserved = <TestRuntime as Config>::Currency::reserved_balance(&new_developer);
+    let init_new_free = <TestRuntime as Config>::Currency::free_balance(&new_developer);
+    let init_metadata = DappsStaking::dapp_metadata(contract_id);
+    let deposit = <TestRuntime as Config>::RegisterDeposit::get()
+        - DappsStaking::slashed_deposit(contract_id)
+        + init_metadata
+            .as_ref()
+            .map_or(0, |(_, metadata_deposit)| *metadata_deposit);
+
+    assert_ok!(DappsStaking::accept_ownership_transfer(
+        RuntimeOrigin::signed(new_developer),
+        contract_id.clone(),
+    ));
+    System::assert_last_event(mock::RuntimeEvent::DappsStaking(
+        Event::OwnershipTransferred(previous_developer, contract_id.clone(), new_developer),
+    ));
+
+    // Ownership info must be updated
+    let final_dapp_info = RegisteredDapps::<TestRuntime>::get(contract_id).unwrap();
+    assert_eq!(final_dapp_info.developer, new_developer);
+    assert_eq!(final_dapp_info.state, init_dapp_info.state);
+    assert!(!RegisteredDevelopers::<TestRuntime>::contains_key(
+        previous_developer
+    ));
+    assert_eq!(
+        RegisteredDevelopers::<TestRuntime>::get(new_developer),
+        Some(contract_id.clone())
+    );
+    assert!(!PendingOwnershipTransfer::<TestRuntime>::contains_key(
+        contract_id
+    ));
+
+    // Deposits must be moved, remaining reserved, and metadata must be kept
+    assert_eq!(DappsStaking::dapp_metadata(contract_id), init_metadata);
+    assert_eq!(
+        <TestRuntime as Config>::Currency::reserved_balance(&previous_developer),
+        init_previous_reserved - deposit
+    );
+    assert_eq!(
+        <TestRuntime as Config>::Currency::reserved_balance(&new_developer),
+        init_new_reserved + deposit
+    );
+    assert_eq!(
+        <TestRuntime as Config>::Currency::free_balance(&new_developer),
+        init_new_free
+    );
+}
+
 // change reward destination and verify the update
 pub(crate) fn assert_set_reward_destination(
     account_id: AccountId,
@@ -690,6 +1598,28 @@ pub(crate) fn assert_set_reward_destination(
     assert_eq!(ledger.reward_destination, reward_destination);
 }
 
+// change reward destination of the staker via its operator and verify the update
+pub(crate) fn assert_operator_set_reward_destination(
+    operator: AccountId,
+    staker: AccountId,
+    reward_destination: RewardDestination,
+) {
+    assert_ok!(DappsStaking::operator_set_reward_destination(
+        RuntimeOrigin::signed(operator),
+        staker,
+        reward_destination
+    ));
+
+    System::assert_last_event(mock::RuntimeEvent::DappsStaking(Event::RewardDestination(
+        staker,
+        reward_destination,
+    )));
+
+    let ledger = Ledger::<TestRuntime>::get(&staker);
+
+    assert_eq!(ledger.reward_destination, reward_destination);
+}
+
 /// Used to burn stale rewards with success assertions
 pub(crate) fn assert_burn_stale_reward(
     contract_id: &MockSmartContract<AccountId>,
@@ -701,9 +1631,13 @@ pub(crate) fn assert_burn_stale_reward(
 
     assert!(!init_state.contract_info.contract_reward_claimed);
 
-    // Calculate contract portion of the reward
-    let (calculated_reward, _) =
+    // Calculate contract portion of the reward, part shared with stakers isn't burned
+    let (developer_reward, _) =
         DappsStaking::dev_stakers_split(&init_state.contract_info, &init_state.era_info);
+    let (calculated_reward, _) = DappsStaking::commission_split(
+        developer_reward,
+        DappsStaking::dapp_commission_at(contract_id, claim_era),
+    );
 
     assert_ok!(DappsStaking::burn_stale_reward(
         RuntimeOrigin::root(),
@@ -726,3 +1660,275 @@ pub(crate) fn assert_burn_stale_reward(
         issuance_after_claim
     );
 }
+
+/// Used to slash a dApp with success assertions.
+pub(crate) fn assert_slash_dapp(
+    contract_id: &MockSmartContract<AccountId>,
+    from_era: EraIndex,
+    number_of_eras: u32,
+    reward_slash: Perbill,
+    slash_deposit: bool,
+    freeze: bool,
+) {
+    let developer = DappsStaking::dapp_info(contract_id).unwrap().developer;
+    let init_free_balance = <TestRuntime as Config>::Currency::free_balance(&developer);
+    let init_reserved_balance = <TestRuntime as Config>::Currency::reserved_balance(&developer);
+    let init_treasury_balance = <TestRuntime as Config>::Currency::free_balance(&TREASURY_ACCOUNT);
+    let init_slashed_deposit = DappsStaking::slashed_deposit(contract_id);
+    let init_frozen_era = DappsStaking::frozen_dapps(contract_id);
+
+    // Only the eras with unclaimed developer reward are slashed
+    let mut slashed_eras = Vec::new();
+    if !reward_slash.is_zero() {
+        for era in from_era..from_era + number_of_eras {
+            let init_state = MemorySnapshot::all(era, contract_id, developer);
+            let is_operated = match init_state.dapp_info.state {
+                DAppState::Unregistered(unregistered_era) => era < unregistered_era,
+                DAppState::Registered => true,
+            };
+            if init_state.contract_info.contract_reward_claimed
+                || init_state.contract_info.total.is_zero()
+                || !is_operated
+            {
+                continue;
+            }
+
+            let (developer_reward, _) =
+                DappsStaking::dev_stakers_split(&init_state.contract_info, &init_state.era_info);
+            let (calculated_reward, _) = DappsStaking::commission_split(
+                developer_reward,
+                DappsStaking::dapp_commission_at(contract_id, era),
+            );
+            slashed_eras.push((era, reward_slash * calculated_reward, calculated_reward));
+        }
+    }
+    let expected_deposit_slash = if slash_deposit {
+        <TestRuntime as Config>::RegisterDeposit::get() - init_slashed_deposit
+    } else {
+        0
+    };
+
+    assert_ok!(DappsStaking::slash_dapp(
+        RuntimeOrigin::root(),
+        contract_id.clone(),
+        from_era,
+        number_of_eras,
+        reward_slash,
+        slash_deposit,
+        freeze,
+    ));
+
+    let events = dapps_staking_events();
+    for (era, slashed_reward, _) in slashed_eras.iter() {
+        assert!(events.contains(&Event::DappRewardSlashed(
+            developer,
+            contract_id.clone(),
+            *era,
+            *slashed_reward,
+        )));
+    }
+    if slash_deposit {
+        assert!(events.contains(&Event::DappDepositSlashed(
+            developer,
+            contract_id.clone(),
+            expected_deposit_slash,
+        )));
+    }
+
+    // Slashed funds go to the handler, rest of the reward to the developer
+    let total_reward_slash = slashed_eras
+        .iter()
+        .map(|(_, slashed_reward, _)| slashed_reward)
+        .sum::<Balance>();
+    let total_reward = slashed_eras
+        .iter()
+        .map(|(_, _, reward)| reward)
+        .sum::<Balance>();
+    assert_eq!(
+        <TestRuntime as Config>::Currency::free_balance(&TREASURY_ACCOUNT),
+        init_treasury_balance + total_reward_slash + expected_deposit_slash
+    );
+    assert_eq!(
+        <TestRuntime as Config>::Currency::free_balance(&developer),
+        init_free_balance + total_reward - total_reward_slash
+    );
+    assert_eq!(
+        <TestRuntime as Config>::Currency::reserved_balance(&developer),
+        init_reserved_balance - expected_deposit_slash
+    );
+    assert_eq!(
+        DappsStaking::slashed_deposit(contract_id),
+        init_slashed_deposit + expected_deposit_slash
+    );
+
+    for (era, _, _) in slashed_eras {
+        assert!(
+            DappsStaking::contract_stake_info(contract_id, era)
+                .unwrap()
+                .contract_reward_claimed
+        );
+    }
+
+    if freeze {
+        let expected_frozen_era = init_frozen_era.unwrap_or(DappsStaking::current_era());
+        assert_eq!(
+            DappsStaking::frozen_dapps(contract_id),
+            Some(expected_frozen_era)
+        );
+    } else {
+        assert_eq!(DappsStaking::frozen_dapps(contract_id), init_frozen_era);
+    }
+}
+
+/// Used to run era history pruning and assert that data older than `prune_before` era was removed.
+pub(crate) fn assert_prune_era_history(prune_before: EraIndex) {
+    let init_pruning_info = DappsStaking::era_pruning();
+    let current_era = DappsStaking::current_era();
+    let contracts: Vec<_> = RegisteredDapps::<TestRuntime>::iter_keys().collect();
+
+    let pruned_era_info = (init_pruning_info.pruned_before..prune_before)
+        .filter(|era| GeneralEraInfo::<TestRuntime>::contains_key(era))
+        .count() as u32;
+    let contract_stake_entries = || {
+        contracts
+            .iter()
+            .map(|contract_id| ContractEraStake::<TestRuntime>::iter_prefix(contract_id).count())
+            .sum::<usize>() as u32
+    };
+    let init_contract_stake_entries = contract_stake_entries();
+    let contract_stake_info = || {
+        contracts
+            .iter()
+            .map(|contract_id| {
+                (prune_before..=current_era)
+                    .map(|era| DappsStaking::contract_stake_info(contract_id, era))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>()
+    };
+    let init_contract_stake_info = contract_stake_info();
+
+    DappsStaking::on_idle(System::block_number(), Weight::MAX);
+
+    let pruned_contract_stakes = init_contract_stake_entries - contract_stake_entries();
+    System::assert_last_event(mock::RuntimeEvent::DappsStaking(Event::EraHistoryPruned(
+        prune_before,
+        pruned_era_info,
+        pruned_contract_stakes,
+    )));
+
+    let final_pruning_info = DappsStaking::era_pruning();
+    assert_eq!(final_pruning_info.pruned_before, prune_before);
+    assert_eq!(final_pruning_info.pass_era, current_era);
+    assert_eq!(final_pruning_info.stage, EraPruningStage::Idle);
+
+    // Pruned eras have no era info and each contract keeps at most one older stake entry
+    assert!((0..prune_before).all(|era| DappsStaking::general_era_info(era).is_none()));
+    for contract_id in &contracts {
+        assert!(
+            ContractEraStake::<TestRuntime>::iter_key_prefix(contract_id)
+                .filter(|era| *era < prune_before)
+                .count()
+                <= 1
+        );
+    }
+
+    // Stake info of the remaining eras isn't affected
+    assert_eq!(init_contract_stake_info, contract_stake_info());
+}
+
+/// Used to create dApp metadata, with text fields of the given lengths.
+pub(crate) fn dapp_metadata(
+    field_lengths: [usize; 4],
+    category: DAppCategory,
+) -> DAppMetadataOf<TestRuntime> {
+    let [name, description, website, logo_uri] =
+        field_lengths.map(|len| BoundedVec::try_from(vec![b'a'; len]).unwrap());
+
+    DAppMetadata {
+        name,
+        description,
+        website,
+        logo_uri,
+        category,
+    }
+}
+
+/// Used to set dApp metadata and assert success, including the deposit adjustment.
+pub(crate) fn assert_set_dapp_metadata(
+    contract_id: &MockSmartContract<AccountId>,
+    metadata: DAppMetadataOf<TestRuntime>,
+) {
+    let developer = DappsStaking::dapp_info(contract_id).unwrap().developer;
+    let init_reserved_balance = <TestRuntime as Config>::Currency::reserved_balance(&developer);
+    let init_deposit = DappsStaking::dapp_metadata(contract_id)
+        .map_or(0, |(_, metadata_deposit)| metadata_deposit);
+
+    assert_ok!(DappsStaking::set_dapp_metadata(
+        RuntimeOrigin::signed(developer),
+        contract_id.clone(),
+        metadata.clone(),
+    ));
+
+    let deposit = METADATA_DEPOSIT_PER_BYTE * metadata.byte_size() as Balance;
+    System::assert_last_event(mock::RuntimeEvent::DappsStaking(Event::DappMetadataSet(
+        developer,
+        contract_id.clone(),
+        deposit,
+    )));
+
+    assert_eq!(
+        DappsStaking::dapp_metadata(contract_id),
+        Some((metadata, deposit))
+    );
+    assert_eq!(
+        <TestRuntime as Config>::Currency::reserved_balance(&developer),
+        init_reserved_balance - init_deposit + deposit
+    );
+}
+
+/// Used to clear dApp metadata and assert success, including the deposit return.
+pub(crate) fn assert_clear_dapp_metadata(contract_id: &MockSmartContract<AccountId>) {
+    let developer = DappsStaking::dapp_info(contract_id).unwrap().developer;
+    let init_reserved_balance = <TestRuntime as Config>::Currency::reserved_balance(&developer);
+    let (_, deposit) = DappsStaking::dapp_metadata(contract_id).unwrap();
+
+    assert_ok!(DappsStaking::clear_dapp_metadata(
+        RuntimeOrigin::root(),
+        contract_id.clone(),
+    ));
+    System::assert_last_event(mock::RuntimeEvent::DappsStaking(
+        Event::DappMetadataCleared(contract_id.clone()),
+    ));
+
+    assert!(!DappMetadata::<TestRuntime>::contains_key(contract_id));
+    assert_eq!(
+        <TestRuntime as Config>::Currency::reserved_balance(&developer),
+        init_reserved_balance - deposit
+    );
+}
+
+/// Used to set the operator of the staker with success assertion.
+pub(crate) fn assert_set_operator(staker: AccountId, operator: AccountId) {
+    assert_ok!(DappsStaking::set_operator(
+        RuntimeOrigin::signed(staker),
+        operator
+    ));
+    System::assert_last_event(mock::RuntimeEvent::DappsStaking(Event::OperatorSet(
+        staker, operator,
+    )));
+
+    assert_eq!(DappsStaking::operator(&staker), Some(operator));
+}
+
+/// Used to remove the operator of the staker with success assertion.
+pub(crate) fn assert_remove_operator(staker: AccountId) {
+    let operator = DappsStaking::operator(&staker).unwrap();
+
+    assert_ok!(DappsStaking::remove_operator(RuntimeOrigin::signed(staker)));
+    System::assert_last_event(mock::RuntimeEvent::DappsStaking(Event::OperatorRemoved(
+        staker, operator,
+    )));
+
+    assert!(!Operators::<TestRuntime>::contains_key(&staker));
+}
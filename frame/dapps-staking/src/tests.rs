@@ -7,7 +7,11 @@
 // If not, see <https://polyformproject.org/licenses/noncommercial/1.0.0//>.
 
 use super::{pallet::pallet::Error, pallet::pallet::Event, *};
-use frame_support::{assert_noop, assert_ok, traits::OnInitialize, weights::Weight};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{OnIdle, OnInitialize, OnRuntimeUpgrade},
+    weights::Weight,
+};
 use mock::{Balances, MockSmartContract, *};
 use sp_core::H160;
 use sp_runtime::{
@@ -348,6 +352,559 @@ fn general_staker_info_is_ok() {
     })
 }
 
+#[test]
+fn contract_stake_info_is_lazy() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let (staker_1, staker_2) = (2, 3);
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        assert_register(developer, &contract_id);
+
+        let first_era = DappsStaking::current_era();
+        assert_bond_and_stake(staker_1, &contract_id, 100);
+
+        let second_era = first_era + 3;
+        advance_to_era(second_era);
+        assert_bond_and_stake(staker_2, &contract_id, 50);
+
+        let final_era = second_era + 3;
+        advance_to_era(final_era);
+
+        // Entries are only written for eras in which contract stake changed
+        for era in first_era..=final_era {
+            assert_eq!(
+                ContractEraStake::<TestRuntime>::contains_key(&contract_id, era),
+                era == first_era || era == second_era
+            );
+        }
+        assert_eq!(
+            DappsStaking::contract_stake_history(&contract_id).into_inner(),
+            vec![first_era, second_era]
+        );
+
+        // Info for eras without an entry is derived from the previous change
+        for era in first_era..second_era {
+            let contract_info = DappsStaking::contract_stake_info(&contract_id, era).unwrap();
+            assert_eq!(contract_info.total, 100);
+            assert_eq!(contract_info.number_of_stakers, 1);
+        }
+        for era in second_era..=final_era {
+            let contract_info = DappsStaking::contract_stake_info(&contract_id, era).unwrap();
+            assert_eq!(contract_info.total, 150);
+            assert_eq!(contract_info.number_of_stakers, 2);
+        }
+        assert!(DappsStaking::contract_stake_info(&contract_id, first_era - 1).is_none());
+
+        // Claiming an era without an entry doesn't affect the neighbouring eras
+        assert_claim_dapp(&contract_id, first_era + 1);
+        assert!(
+            !DappsStaking::contract_stake_info(&contract_id, first_era)
+                .unwrap()
+                .contract_reward_claimed
+        );
+        assert!(
+            !DappsStaking::contract_stake_info(&contract_id, first_era + 2)
+                .unwrap()
+                .contract_reward_claimed
+        );
+        assert_claim_dapp(&contract_id, first_era + 2);
+    })
+}
+
+#[test]
+fn contract_stake_history_is_bounded() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        assert_register(1, &contract_id);
+        let first_era = DappsStaking::current_era();
+        assert_bond_and_stake(2, &contract_id, 100);
+        advance_to_era(first_era + 2 * MAX_CONTRACT_STAKE_HISTORY);
+
+        // Simulate a stake change in every other era, until the history is full
+        let contract_info = DappsStaking::contract_stake_info(&contract_id, first_era).unwrap();
+        let stake_eras: Vec<EraIndex> = (0..MAX_CONTRACT_STAKE_HISTORY)
+            .map(|index| first_era + 2 * index)
+            .collect();
+        for era in stake_eras.iter() {
+            ContractEraStake::<TestRuntime>::insert(&contract_id, era, contract_info.clone());
+        }
+        ContractStakeHistory::<TestRuntime>::insert(
+            &contract_id,
+            BoundedVec::try_from(stake_eras.clone()).unwrap(),
+        );
+
+        // Stake can't change while history is full
+        assert_noop!(
+            DappsStaking::bond_and_stake(RuntimeOrigin::signed(3), contract_id.clone(), 50),
+            Error::<TestRuntime>::ContractStakeHistoryFull
+        );
+        assert_eq!(
+            DappsStaking::contract_stake_history(&contract_id).to_vec(),
+            stake_eras
+        );
+
+        // Info of the oldest era without a stake change is kept, so its reward can still be claimed
+        assert_eq!(
+            DappsStaking::contract_stake_info(&contract_id, first_era + 1),
+            Some(contract_info)
+        );
+        assert_claim_dapp(&contract_id, first_era + 1);
+    })
+}
+
+#[test]
+fn new_era_weight_does_not_depend_on_number_of_dapps() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let new_era_weight = || {
+            assert_ok!(DappsStaking::force_new_era(RuntimeOrigin::root()));
+            System::set_block_number(System::block_number() + 1);
+            DappsStaking::on_initialize(System::block_number())
+        };
+        let init_weight = new_era_weight();
+
+        for index in 1..=5_u8 {
+            let contract_id = MockSmartContract::Evm(H160::repeat_byte(index));
+            assert_register(index.into(), &contract_id);
+            assert_bond_and_stake(10, &contract_id, 20);
+        }
+
+        assert_eq!(init_weight, new_era_weight());
+    })
+}
+
+#[test]
+fn lazy_contract_stake_migration_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let (staked_contract, not_staked_contract, unregistered_contract) = (
+            MockSmartContract::Evm(H160::repeat_byte(0x01)),
+            MockSmartContract::Evm(H160::repeat_byte(0x02)),
+            MockSmartContract::Evm(H160::repeat_byte(0x03)),
+        );
+        assert_register(1, &staked_contract);
+        assert_register(2, &not_staked_contract);
+        assert_register(3, &unregistered_contract);
+
+        let first_era = DappsStaking::current_era();
+        assert_bond_and_stake(10, &staked_contract, 100);
+        assert_bond_and_stake(10, &unregistered_contract, 100);
+        assert_unregister(3, &unregistered_contract);
+        advance_to_era(first_era + 3);
+        let current_era = DappsStaking::current_era();
+
+        // Simulate the old storage layout, where info was copied over to each next era
+        let contract_info = DappsStaking::contract_stake_info(&staked_contract, first_era).unwrap();
+        for era in first_era..=current_era {
+            ContractEraStake::<TestRuntime>::insert(&staked_contract, era, contract_info.clone());
+        }
+        let _ = ContractStakeHistory::<TestRuntime>::clear(u32::MAX, None);
+        assert_eq!(DappsStaking::storage_version(), Version::V4_0_0);
+
+        migrations::LazyContractStakeMigration::<TestRuntime>::on_runtime_upgrade();
+
+        assert_eq!(DappsStaking::storage_version(), Version::V5_0_0);
+        assert_eq!(
+            DappsStaking::contract_stake_history(&staked_contract).into_inner(),
+            vec![current_era]
+        );
+        assert!(DappsStaking::contract_stake_history(&not_staked_contract).is_empty());
+        assert_eq!(
+            DappsStaking::contract_stake_history(&unregistered_contract).into_inner(),
+            vec![first_era]
+        );
+
+        // Future changes are linked to the migrated data
+        advance_to_era(current_era + 2);
+        assert_bond_and_stake(11, &staked_contract, 50);
+        for era in first_era..current_era + 2 {
+            assert_eq!(
+                DappsStaking::contract_stake_info(&staked_contract, era),
+                Some(contract_info.clone())
+            );
+        }
+        assert_eq!(
+            DappsStaking::contract_stake_info(&staked_contract, current_era + 2)
+                .unwrap()
+                .total,
+            contract_info.total + 50
+        );
+
+        // Running the migration again has no effect
+        migrations::LazyContractStakeMigration::<TestRuntime>::on_runtime_upgrade();
+        assert_eq!(
+            DappsStaking::contract_stake_history(&staked_contract).into_inner(),
+            vec![current_era, current_era + 2]
+        );
+    })
+}
+
+#[test]
+fn lazy_contract_stake_migration_keeps_unregistered_stake() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        assert_register(1, &contract_id);
+
+        let first_era = DappsStaking::current_era();
+        assert_bond_and_stake(10, &contract_id, 100);
+        advance_to_era(first_era + 2);
+        let unregistered_era = DappsStaking::current_era();
+        assert_unregister(1, &contract_id);
+        advance_to_era(unregistered_era + 2);
+
+        // Simulate the old storage layout, where info was copied over to each next era until unregistration
+        let contract_info = DappsStaking::contract_stake_info(&contract_id, first_era).unwrap();
+        for era in first_era..=unregistered_era {
+            ContractEraStake::<TestRuntime>::insert(&contract_id, era, contract_info.clone());
+        }
+        let _ = ContractStakeHistory::<TestRuntime>::clear(u32::MAX, None);
+
+        migrations::LazyContractStakeMigration::<TestRuntime>::on_runtime_upgrade();
+        assert_eq!(
+            DappsStaking::contract_stake_history(&contract_id).into_inner(),
+            vec![unregistered_era]
+        );
+
+        // Stake which wasn't withdrawn remains accessible after the contract is registered again
+        assert_reregister(&contract_id);
+        let current_era = DappsStaking::current_era();
+        assert_eq!(
+            DappsStaking::contract_stake_info(&contract_id, current_era),
+            Some(contract_info.clone())
+        );
+
+        assert_bond_and_stake(11, &contract_id, 50);
+        assert_eq!(
+            DappsStaking::contract_stake_info(&contract_id, current_era)
+                .unwrap()
+                .total,
+            contract_info.total + 50
+        );
+        assert_eq!(
+            DappsStaking::contract_stake_history(&contract_id).into_inner(),
+            vec![unregistered_era, current_era]
+        );
+    })
+}
+
+#[test]
+fn bounded_storage_migration_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        assert_register(10, &contract_id);
+        let first_era = DappsStaking::current_era();
+        assert_bond_and_stake(1, &contract_id, 100);
+        let regular_ledger = DappsStaking::ledger(&1);
+        let regular_staker_info = DappsStaking::staker_info(&1, &contract_id);
+
+        // Simulate the old storage layout, where values could exceed the configured limits
+        let (staker, number_of_chunks, number_of_stakes) =
+            (2, MAX_UNLOCKING_CHUNKS + 2, MAX_ERA_STAKE_VALUES + 2);
+        advance_to_era(first_era + number_of_stakes);
+        let old_chunks: Vec<UnlockingChunk<Balance>> = (1..=number_of_chunks)
+            .map(|era| UnlockingChunk {
+                amount: 10,
+                unlock_era: era,
+            })
+            .collect();
+        let old_ledger = (
+            codec::Compact(500 as Balance),
+            old_chunks,
+            RewardDestination::FreeBalance,
+        );
+        frame_support::storage::unhashed::put(
+            &Ledger::<TestRuntime>::hashed_key_for(&staker),
+            &old_ledger,
+        );
+        let old_stakes: Vec<EraStake<Balance>> = (0..number_of_stakes)
+            .map(|index| EraStake::new((index + 1) as Balance * 10, first_era + index))
+            .collect();
+        frame_support::storage::unhashed::put(
+            &GeneralStakerInfo::<TestRuntime>::hashed_key_for(&staker, &contract_id),
+            &old_stakes,
+        );
+        StorageVersion::<TestRuntime>::put(Version::V5_0_0);
+
+        // Staker rewards of the eras covered by the excess stakes are paid out during the migration
+        let excess_stakes = number_of_stakes - MAX_ERA_STAKE_VALUES;
+        let era_rewards: Vec<(EraIndex, Balance)> = (0..excess_stakes)
+            .map(|index| {
+                let era = first_era + index;
+                let staking_info = DappsStaking::contract_stake_info(&contract_id, era).unwrap();
+                let era_info = DappsStaking::general_era_info(era).unwrap();
+                let (_, stakers_joint_reward) =
+                    DappsStaking::dev_stakers_split(&staking_info, &era_info);
+                let staked = old_stakes[index as usize].staked;
+                (
+                    era,
+                    Perbill::from_rational(staked, staking_info.total) * stakers_joint_reward,
+                )
+            })
+            .collect();
+        let free_balance = Balances::free_balance(&staker);
+
+        // First step is done as part of the runtime upgrade, pallet is disabled until migration is finished
+        migrations::BoundedStorageMigration::<TestRuntime>::on_runtime_upgrade();
+        assert!(DappsStaking::pallet_disabled());
+        assert_ne!(
+            DappsStaking::migration_state(),
+            migrations::MigrationState::NotInProgress
+        );
+
+        // Stake boost migration follows, pallet is only enabled once storage is fully migrated
+        let mut number_of_calls = 0;
+        while DappsStaking::storage_version() != Version::V7_0_0 {
+            assert!(DappsStaking::pallet_disabled());
+            assert_ok!(DappsStaking::migrate(
+                RuntimeOrigin::signed(3),
+                Some(Weight::zero())
+            ));
+            number_of_calls += 1;
+            assert!(
+                number_of_calls < 20,
+                "Migration must progress with each call."
+            );
+        }
+        assert!(!DappsStaking::pallet_disabled());
+        assert_eq!(
+            DappsStaking::migration_state(),
+            migrations::MigrationState::NotInProgress
+        );
+
+        // Values within the bounds are unchanged
+        assert_eq!(DappsStaking::ledger(&1), regular_ledger);
+        assert_eq!(
+            DappsStaking::staker_info(&1, &contract_id),
+            regular_staker_info
+        );
+
+        // Excess unlocking chunks are merged into the latest one
+        let ledger = DappsStaking::ledger(&staker);
+        assert_eq!(ledger.locked, 500);
+        assert_eq!(ledger.reward_destination(), RewardDestination::FreeBalance);
+        assert_eq!(ledger.unbonding_info.len(), MAX_UNLOCKING_CHUNKS);
+        assert_eq!(
+            ledger.unbonding_info.sum(),
+            10 * number_of_chunks as Balance
+        );
+        let (_, future_chunks) = ledger
+            .unbonding_info
+            .clone()
+            .partition(MAX_UNLOCKING_CHUNKS - 1);
+        assert_eq!(future_chunks.len(), 1);
+        assert_eq!(future_chunks.sum(), 10 * 3);
+
+        // Rewards of the oldest eras are claimed, so their era stakes are removed
+        let mut staker_info = DappsStaking::staker_info(&staker, &contract_id);
+        assert_eq!(staker_info.len(), MAX_ERA_STAKE_VALUES);
+        assert_eq!(
+            staker_info.latest_staked_value(),
+            number_of_stakes as Balance * 10
+        );
+        for index in excess_stakes..number_of_stakes {
+            assert_eq!(
+                staker_info.claim(),
+                (first_era + index, (index + 1) as Balance * 10)
+            );
+        }
+        let total_reward: Balance = era_rewards.iter().map(|(_, reward)| reward).sum();
+        assert!(total_reward > 0);
+        assert_eq!(Balances::free_balance(&staker), free_balance + total_reward);
+        for (era, reward) in era_rewards {
+            System::assert_has_event(mock::RuntimeEvent::DappsStaking(Event::Reward(
+                staker,
+                contract_id.clone(),
+                era,
+                reward,
+            )));
+        }
+
+        // Running the migration again has no effect
+        migrations::BoundedStorageMigration::<TestRuntime>::on_runtime_upgrade();
+        assert!(!DappsStaking::pallet_disabled());
+        assert_eq!(DappsStaking::ledger(&staker), ledger);
+    })
+}
+
+#[test]
+fn stake_boost_migration_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let first_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        let second_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x02));
+        assert_register(10, &first_contract_id);
+        assert_register(11, &second_contract_id);
+
+        let start_era = DappsStaking::current_era();
+        for _ in 0..3 {
+            assert_bond_and_stake(1, &first_contract_id, 100);
+            assert_bond_and_stake(2, &second_contract_id, 50);
+            advance_to_era(DappsStaking::current_era() + 1);
+        }
+        let end_era = DappsStaking::current_era();
+
+        // Simulate the old storage layout, without the boost
+        let mut era_infos = vec![];
+        let mut contract_infos = vec![];
+        for era in start_era..end_era {
+            let era_info = DappsStaking::general_era_info(era).unwrap();
+            let old_era_info = (
+                era_info.rewards.clone(),
+                codec::Compact(era_info.staked),
+                codec::Compact(era_info.locked),
+            );
+            frame_support::storage::unhashed::put(
+                &GeneralEraInfo::<TestRuntime>::hashed_key_for(era),
+                &old_era_info,
+            );
+            assert!(DappsStaking::general_era_info(era).is_none());
+            era_infos.push((era, era_info));
+
+            for contract_id in [&first_contract_id, &second_contract_id] {
+                let contract_info = DappsStaking::contract_stake_info(contract_id, era).unwrap();
+                let old_contract_info = (
+                    codec::Compact(contract_info.total),
+                    codec::Compact(contract_info.number_of_stakers),
+                    contract_info.contract_reward_claimed,
+                );
+                frame_support::storage::unhashed::put(
+                    &ContractEraStake::<TestRuntime>::hashed_key_for(contract_id, era),
+                    &old_contract_info,
+                );
+                contract_infos.push((contract_id.clone(), era, contract_info));
+            }
+        }
+        StorageVersion::<TestRuntime>::put(Version::V6_0_0);
+
+        // Upgrade only starts the migration and puts the pallet into maintenance mode
+        migrations::StakeBoostMigration::<TestRuntime>::on_runtime_upgrade();
+        assert!(DappsStaking::pallet_disabled());
+        assert_eq!(DappsStaking::storage_version(), Version::V6_0_0);
+
+        // Migration continues over multiple calls, with minimal weight limit
+        let mut number_of_calls = 0;
+        while DappsStaking::storage_version() == Version::V6_0_0 {
+            assert_ok!(DappsStaking::migrate(
+                RuntimeOrigin::signed(3),
+                Some(Weight::zero())
+            ));
+            number_of_calls += 1;
+            assert!(number_of_calls < 20, "Migration must finish.");
+        }
+        assert!(number_of_calls > 1);
+
+        assert_eq!(DappsStaking::storage_version(), Version::V7_0_0);
+        assert!(!DappsStaking::pallet_disabled());
+        assert_eq!(
+            DappsStaking::migration_state(),
+            migrations::MigrationState::NotInProgress
+        );
+        for (era, era_info) in era_infos {
+            assert_eq!(DappsStaking::general_era_info(era), Some(era_info));
+        }
+        for (contract_id, era, contract_info) in contract_infos {
+            assert_eq!(
+                ContractEraStake::<TestRuntime>::get(&contract_id, era),
+                Some(contract_info)
+            );
+        }
+    })
+}
+
+#[test]
+fn bounded_storage_and_stake_boost_migrations_in_single_upgrade() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        assert_register(10, &contract_id);
+        let era = DappsStaking::current_era();
+        assert_bond_and_stake(1, &contract_id, 100);
+        assert_bond_and_stake(2, &contract_id, 50);
+        advance_to_era(era + 1);
+
+        let ledger = DappsStaking::ledger(&1);
+        let staker_info = DappsStaking::staker_info(&2, &contract_id);
+        let era_info = DappsStaking::general_era_info(era).unwrap();
+        let contract_info = DappsStaking::contract_stake_info(&contract_id, era).unwrap();
+
+        // Simulate the V5 storage layout, with excess era stakes and without the boost
+        let old_stakes: Vec<EraStake<Balance>> = (1..=MAX_ERA_STAKE_VALUES + 1)
+            .map(|era| EraStake::new(10, era))
+            .collect();
+        frame_support::storage::unhashed::put(
+            &GeneralStakerInfo::<TestRuntime>::hashed_key_for(&3, &contract_id),
+            &old_stakes,
+        );
+        frame_support::storage::unhashed::put(
+            &GeneralEraInfo::<TestRuntime>::hashed_key_for(era),
+            &(
+                era_info.rewards.clone(),
+                codec::Compact(era_info.staked),
+                codec::Compact(era_info.locked),
+            ),
+        );
+        frame_support::storage::unhashed::put(
+            &ContractEraStake::<TestRuntime>::hashed_key_for(&contract_id, era),
+            &(
+                codec::Compact(contract_info.total),
+                codec::Compact(contract_info.number_of_stakers),
+                contract_info.contract_reward_claimed,
+            ),
+        );
+        StorageVersion::<TestRuntime>::put(Version::V5_0_0);
+
+        // Both migrations are part of the same runtime upgrade
+        <(
+            migrations::BoundedStorageMigration<TestRuntime>,
+            migrations::StakeBoostMigration<TestRuntime>,
+        ) as OnRuntimeUpgrade>::on_runtime_upgrade();
+
+        // Pallet stays disabled until storage is fully migrated, even once values are bounded
+        let mut bounded = false;
+        let mut number_of_calls = 0;
+        while DappsStaking::storage_version() != Version::V7_0_0 {
+            assert!(DappsStaking::pallet_disabled());
+            bounded |= DappsStaking::storage_version() == Version::V6_0_0;
+            assert_ok!(DappsStaking::migrate(
+                RuntimeOrigin::signed(4),
+                Some(Weight::zero())
+            ));
+            number_of_calls += 1;
+            assert!(number_of_calls < 20, "Migration must finish.");
+        }
+        assert!(bounded);
+        assert!(!DappsStaking::pallet_disabled());
+        assert_eq!(
+            DappsStaking::migration_state(),
+            migrations::MigrationState::NotInProgress
+        );
+
+        assert_eq!(DappsStaking::ledger(&1), ledger);
+        assert_eq!(DappsStaking::staker_info(&2, &contract_id), staker_info);
+        assert_eq!(
+            DappsStaking::staker_info(&3, &contract_id).len(),
+            MAX_ERA_STAKE_VALUES
+        );
+        assert_eq!(DappsStaking::general_era_info(era), Some(era_info));
+        assert_eq!(
+            ContractEraStake::<TestRuntime>::get(&contract_id, era),
+            Some(contract_info)
+        );
+    })
+}
+
 #[test]
 fn register_is_ok() {
     ExternalityBuilder::build().execute_with(|| {
@@ -470,71 +1027,324 @@ fn unregister_with_non_root() {
 }
 
 #[test]
-fn unregister_stake_and_unstake_is_not_ok() {
+fn self_register_is_ok() {
     ExternalityBuilder::build().execute_with(|| {
         initialize_first_block();
 
         let developer = 1;
-        let staker = 2;
         let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
 
-        // Register contract, stake it, unstake a bit
-        assert_register(developer, &contract_id);
-        assert_bond_and_stake(staker, &contract_id, 100);
-        assert_unbond_and_unstake(staker, &contract_id, 10);
-
-        // Unregister contract and verify that stake & unstake no longer work
-        assert_unregister(developer, &contract_id);
+        assert_self_register(developer, &contract_id);
+        assert_eq!(
+            RegisterDeposit::get(),
+            <TestRuntime as Config>::Currency::reserved_balance(&developer)
+        );
 
+        // Same rules as for privileged registration apply
         assert_noop!(
-            DappsStaking::bond_and_stake(RuntimeOrigin::signed(staker), contract_id.clone(), 100),
-            Error::<TestRuntime>::NotOperatedContract
+            DappsStaking::self_register(
+                RuntimeOrigin::signed(developer),
+                MockSmartContract::Evm(H160::repeat_byte(0x02))
+            ),
+            Error::<TestRuntime>::AlreadyUsedDeveloperAccount
         );
         assert_noop!(
-            DappsStaking::unbond_and_unstake(
-                RuntimeOrigin::signed(staker),
-                contract_id.clone(),
-                100
-            ),
-            Error::<TestRuntime>::NotOperatedContract
+            DappsStaking::self_register(RuntimeOrigin::signed(2), contract_id),
+            Error::<TestRuntime>::AlreadyRegisteredContract
         );
     })
 }
 
 #[test]
-fn withdraw_from_unregistered_is_ok() {
+fn self_register_with_insufficient_funds_fails() {
     ExternalityBuilder::build().execute_with(|| {
         initialize_first_block();
 
-        let developer = 1;
-        let dummy_developer = 2;
-        let staker_1 = 3;
-        let staker_2 = 4;
-        let staked_value_1 = 150;
-        let staked_value_2 = 330;
+        // Account 540 can't cover the register deposit
+        let developer = 540;
         let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
-        let dummy_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x05));
-
-        // Register both contracts and stake them
-        assert_register(developer, &contract_id);
-        assert_register(dummy_developer, &dummy_contract_id);
-        assert_bond_and_stake(staker_1, &contract_id, staked_value_1);
-        assert_bond_and_stake(staker_2, &contract_id, staked_value_2);
 
-        // This contract will just exist so it helps us with testing ledger content
-        assert_bond_and_stake(staker_1, &dummy_contract_id, staked_value_1);
+        assert!(
+            <TestRuntime as Config>::Currency::free_balance(&developer) < RegisterDeposit::get()
+        );
+        assert_noop!(
+            DappsStaking::self_register(RuntimeOrigin::signed(developer), contract_id),
+            pallet_balances::Error::<TestRuntime>::InsufficientBalance
+        );
+    })
+}
 
-        // Advance eras. This will accumulate some rewards.
-        advance_to_era(5);
+#[test]
+fn self_unregister_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
 
-        assert_unregister(developer, &contract_id);
+        let developer = 1;
+        let staker = 2;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
 
-        // Claim all past rewards
-        for era in 1..DappsStaking::current_era() {
-            assert_claim_staker(staker_1, &contract_id);
-            assert_claim_staker(staker_2, &contract_id);
-            assert_claim_dapp(&contract_id, era);
-        }
+        assert_self_register(developer, &contract_id);
+        assert_bond_and_stake(staker, &contract_id, 100);
+        assert_self_unregister(developer, &contract_id);
+        assert!(<TestRuntime as Config>::Currency::reserved_balance(&developer).is_zero());
+
+        // Not possible to unregister a contract twice
+        assert_noop!(
+            DappsStaking::self_unregister(RuntimeOrigin::signed(developer), contract_id),
+            Error::<TestRuntime>::NotOperatedContract
+        );
+        assert_withdraw_from_unregistered(staker, &contract_id);
+    })
+}
+
+#[test]
+fn self_unregister_by_non_owner_fails() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let other_account = 2;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+        assert_noop!(
+            DappsStaking::self_unregister(RuntimeOrigin::signed(developer), contract_id),
+            Error::<TestRuntime>::NotOperatedContract
+        );
+
+        assert_register(developer, &contract_id);
+        assert_noop!(
+            DappsStaking::self_unregister(RuntimeOrigin::signed(other_account), contract_id),
+            Error::<TestRuntime>::NotOwnedContract
+        );
+        assert_noop!(
+            DappsStaking::self_unregister(RuntimeOrigin::root(), contract_id),
+            BadOrigin
+        );
+    })
+}
+
+#[test]
+fn ownership_transfer_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let new_developer = 2;
+        let staker = 3;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+        let start_era = DappsStaking::current_era();
+        assert_register(developer, &contract_id);
+        assert_bond_and_stake(staker, &contract_id, 100);
+        advance_to_era(start_era + 1);
+
+        assert_propose_ownership_transfer(developer, &contract_id, new_developer);
+        assert_accept_ownership_transfer(new_developer, &contract_id);
+
+        // Rewards, including the ones from before the transfer, are paid to the new owner
+        advance_to_era(start_era + 2);
+        assert_claim_dapp(&contract_id, start_era);
+        assert_claim_dapp(&contract_id, start_era + 1);
+
+        // Previous owner is free to register another contract
+        assert_register(developer, &MockSmartContract::Evm(H160::repeat_byte(0x02)));
+
+        // New owner can manage the contract
+        assert_self_unregister(new_developer, &contract_id);
+    })
+}
+
+#[test]
+fn propose_ownership_transfer_is_not_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let other_developer = 2;
+        let new_developer = 3;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+        assert_noop!(
+            DappsStaking::propose_ownership_transfer(
+                RuntimeOrigin::signed(developer),
+                contract_id,
+                new_developer
+            ),
+            Error::<TestRuntime>::NotOperatedContract
+        );
+
+        assert_register(developer, &contract_id);
+        assert_register(
+            other_developer,
+            &MockSmartContract::Evm(H160::repeat_byte(0x02)),
+        );
+
+        // Only the owner can propose transfer
+        assert_noop!(
+            DappsStaking::propose_ownership_transfer(
+                RuntimeOrigin::signed(new_developer),
+                contract_id,
+                new_developer
+            ),
+            Error::<TestRuntime>::NotOwnedContract
+        );
+        // Account which already owns a contract cannot become the owner
+        assert_noop!(
+            DappsStaking::propose_ownership_transfer(
+                RuntimeOrigin::signed(developer),
+                contract_id,
+                other_developer
+            ),
+            Error::<TestRuntime>::AlreadyUsedDeveloperAccount
+        );
+
+        // Unregistered contracts cannot be transferred
+        assert_unregister(developer, &contract_id);
+        assert_noop!(
+            DappsStaking::propose_ownership_transfer(
+                RuntimeOrigin::signed(developer),
+                contract_id,
+                new_developer
+            ),
+            Error::<TestRuntime>::NotOperatedContract
+        );
+    })
+}
+
+#[test]
+fn accept_ownership_transfer_is_not_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let new_developer = 2;
+        let other_account = 3;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+        assert_register(developer, &contract_id);
+        assert_noop!(
+            DappsStaking::accept_ownership_transfer(
+                RuntimeOrigin::signed(new_developer),
+                contract_id
+            ),
+            Error::<TestRuntime>::NoPendingOwnershipTransfer
+        );
+
+        // Only the proposed account can accept the transfer
+        assert_propose_ownership_transfer(developer, &contract_id, new_developer);
+        assert_noop!(
+            DappsStaking::accept_ownership_transfer(
+                RuntimeOrigin::signed(other_account),
+                contract_id
+            ),
+            Error::<TestRuntime>::NoPendingOwnershipTransfer
+        );
+
+        // New proposal overrides the previous one
+        assert_propose_ownership_transfer(developer, &contract_id, other_account);
+        assert_noop!(
+            DappsStaking::accept_ownership_transfer(
+                RuntimeOrigin::signed(new_developer),
+                contract_id
+            ),
+            Error::<TestRuntime>::NoPendingOwnershipTransfer
+        );
+
+        // Proposed account registered its own contract in the meantime
+        assert_register(
+            other_account,
+            &MockSmartContract::Evm(H160::repeat_byte(0x02)),
+        );
+        assert_noop!(
+            DappsStaking::accept_ownership_transfer(
+                RuntimeOrigin::signed(other_account),
+                contract_id
+            ),
+            Error::<TestRuntime>::AlreadyUsedDeveloperAccount
+        );
+
+        // Unregistering the contract removes the pending transfer
+        assert_propose_ownership_transfer(developer, &contract_id, new_developer);
+        assert_unregister(developer, &contract_id);
+        assert!(!PendingOwnershipTransfer::<TestRuntime>::contains_key(
+            &contract_id
+        ));
+        assert_noop!(
+            DappsStaking::accept_ownership_transfer(
+                RuntimeOrigin::signed(new_developer),
+                contract_id
+            ),
+            Error::<TestRuntime>::NoPendingOwnershipTransfer
+        );
+    })
+}
+
+#[test]
+fn unregister_stake_and_unstake_is_not_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let staker = 2;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+        // Register contract, stake it, unstake a bit
+        assert_register(developer, &contract_id);
+        assert_bond_and_stake(staker, &contract_id, 100);
+        assert_unbond_and_unstake(staker, &contract_id, 10);
+
+        // Unregister contract and verify that stake & unstake no longer work
+        assert_unregister(developer, &contract_id);
+
+        assert_noop!(
+            DappsStaking::bond_and_stake(RuntimeOrigin::signed(staker), contract_id.clone(), 100),
+            Error::<TestRuntime>::NotOperatedContract
+        );
+        assert_noop!(
+            DappsStaking::unbond_and_unstake(
+                RuntimeOrigin::signed(staker),
+                contract_id.clone(),
+                100
+            ),
+            Error::<TestRuntime>::NotOperatedContract
+        );
+    })
+}
+
+#[test]
+fn withdraw_from_unregistered_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let dummy_developer = 2;
+        let staker_1 = 3;
+        let staker_2 = 4;
+        let staked_value_1 = 150;
+        let staked_value_2 = 330;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        let dummy_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x05));
+
+        // Register both contracts and stake them
+        assert_register(developer, &contract_id);
+        assert_register(dummy_developer, &dummy_contract_id);
+        assert_bond_and_stake(staker_1, &contract_id, staked_value_1);
+        assert_bond_and_stake(staker_2, &contract_id, staked_value_2);
+
+        // This contract will just exist so it helps us with testing ledger content
+        assert_bond_and_stake(staker_1, &dummy_contract_id, staked_value_1);
+
+        // Advance eras. This will accumulate some rewards.
+        advance_to_era(5);
+
+        assert_unregister(developer, &contract_id);
+
+        // Claim all past rewards
+        for era in 1..DappsStaking::current_era() {
+            assert_claim_staker(staker_1, &contract_id);
+            assert_claim_staker(staker_2, &contract_id);
+            assert_claim_dapp(&contract_id, era);
+        }
 
         // Unbond everything from the contract.
         assert_withdraw_from_unregistered(staker_1, &contract_id);
@@ -1061,68 +1871,426 @@ fn unbond_and_unstake_too_many_era_stakes() {
 
 #[ignore]
 #[test]
-fn unbond_and_unstake_with_no_chunks_allowed() {
-    // UT can be used to verify situation when MaxUnlockingChunks = 0. Requires mock modification.
+fn instant_unbond_and_unstake_is_ok() {
     ExternalityBuilder::build().execute_with(|| {
         initialize_first_block();
 
-        // Sanity check
-        assert_eq!(<TestRuntime as Config>::MaxUnlockingChunks::get(), 0);
-
+        let staker = 1;
         let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
         assert_register(10, &contract_id);
+        assert_bond_and_stake(staker, &contract_id, 100);
 
-        let staker_id = 1;
-        assert_bond_and_stake(staker_id, &contract_id, 100);
+        assert_instant_unbond_and_unstake(staker, &contract_id, 30);
+
+        // Remaining stake would be below the minimum, so everything is unstaked
+        advance_to_era(DappsStaking::current_era() + 1);
+        assert_instant_unbond_and_unstake(staker, &contract_id, 65);
+        assert!(!Ledger::<TestRuntime>::contains_key(&staker));
+    })
+}
+
+#[test]
+fn instant_unbond_and_unstake_is_not_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let staker = 1;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
 
         assert_noop!(
-            DappsStaking::unbond_and_unstake(
-                RuntimeOrigin::signed(staker_id),
-                contract_id.clone(),
-                20
+            DappsStaking::instant_unbond_and_unstake(
+                RuntimeOrigin::signed(staker),
+                contract_id,
+                100
             ),
-            Error::<TestRuntime>::TooManyUnlockingChunks,
+            Error::<TestRuntime>::NotOperatedContract
+        );
+
+        assert_register(10, &contract_id);
+        assert_noop!(
+            DappsStaking::instant_unbond_and_unstake(RuntimeOrigin::signed(staker), contract_id, 0),
+            Error::<TestRuntime>::UnstakingWithNoValue
+        );
+        assert_noop!(
+            DappsStaking::instant_unbond_and_unstake(
+                RuntimeOrigin::signed(staker),
+                contract_id,
+                100
+            ),
+            Error::<TestRuntime>::NotStakedContract
         );
     })
 }
 
 #[test]
-fn withdraw_unbonded_is_ok() {
+fn instant_withdraw_unbonded_is_ok() {
     ExternalityBuilder::build().execute_with(|| {
         initialize_first_block();
 
+        let staker = 1;
         let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
         assert_register(10, &contract_id);
+        assert_bond_and_stake(staker, &contract_id, 1000);
 
-        let staker_id = 1;
-        assert_bond_and_stake(staker_id, &contract_id, 1000);
-
-        let first_unbond_value = 75;
-        let second_unbond_value = 39;
-        let initial_era = DappsStaking::current_era();
-
-        // Unbond some amount in the initial era
-        assert_unbond_and_unstake(staker_id, &contract_id, first_unbond_value);
-
-        // Advance one era and then unbond some more
-        advance_to_era(initial_era + 1);
-        assert_unbond_and_unstake(staker_id, &contract_id, second_unbond_value);
+        // Chunks are withdrawn regardless of their unbonding period
+        assert_unbond_and_unstake(staker, &contract_id, 200);
+        advance_to_era(DappsStaking::current_era() + 1);
+        assert_unbond_and_unstake(staker, &contract_id, 300);
+        assert_instant_withdraw_unbonded(staker);
 
-        // Now advance one era before first chunks finishes the unbonding process
-        advance_to_era(initial_era + UNBONDING_PERIOD - 1);
         assert_noop!(
-            DappsStaking::withdraw_unbonded(RuntimeOrigin::signed(staker_id)),
+            DappsStaking::instant_withdraw_unbonded(RuntimeOrigin::signed(staker)),
             Error::<TestRuntime>::NothingToWithdraw
         );
 
-        // Advance one additional era and expect that the first chunk can be withdrawn
-        advance_to_era(DappsStaking::current_era() + 1);
-        assert_ok!(DappsStaking::withdraw_unbonded(RuntimeOrigin::signed(
-            staker_id
-        ),));
-        System::assert_last_event(mock::RuntimeEvent::DappsStaking(Event::Withdrawn(
-            staker_id,
-            first_unbond_value,
+        // Remaining stake is not affected
+        assert_eq!(
+            DappsStaking::staker_info(&staker, &contract_id).latest_staked_value(),
+            500
+        );
+    })
+}
+
+#[test]
+fn bond_and_stake_with_lock_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let staker = 1;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        assert_register(10, &contract_id);
+
+        // Locks for the same duration, made in the same era, are merged
+        assert_bond_and_stake_with_lock(staker, &contract_id, 100, SHORT_STAKE_LOCK.0);
+        assert_bond_and_stake_with_lock(staker, &contract_id, 200, LONG_STAKE_LOCK.0);
+        assert_bond_and_stake_with_lock(staker, &contract_id, 300, SHORT_STAKE_LOCK.0);
+        assert_eq!(DappsStaking::stake_locks(&staker, &contract_id).len(), 2);
+
+        let start_era = DappsStaking::current_era();
+        let short_boost = SHORT_STAKE_LOCK.1 * 400 as Balance;
+        let long_boost = LONG_STAKE_LOCK.1 * 200 as Balance;
+        assert_eq!(
+            DappsStaking::general_era_info(start_era).unwrap().boost,
+            short_boost + long_boost
+        );
+
+        // Boost expires at the start of the unlock era
+        let short_unlock_era = start_era + SHORT_STAKE_LOCK.0;
+        advance_to_era(short_unlock_era);
+        assert_eq!(
+            DappsStaking::general_era_info(short_unlock_era - 1)
+                .unwrap()
+                .boost,
+            short_boost + long_boost
+        );
+        assert_eq!(
+            DappsStaking::general_era_info(short_unlock_era)
+                .unwrap()
+                .boost,
+            long_boost
+        );
+        assert_eq!(
+            DappsStaking::contract_stake_info(&contract_id, short_unlock_era - 1)
+                .unwrap()
+                .boost,
+            short_boost + long_boost
+        );
+        assert_eq!(
+            DappsStaking::contract_stake_info(&contract_id, short_unlock_era)
+                .unwrap()
+                .boost,
+            long_boost
+        );
+        assert!(DappsStaking::boost_expiries(short_unlock_era).is_empty());
+
+        let long_unlock_era = start_era + LONG_STAKE_LOCK.0;
+        advance_to_era(long_unlock_era);
+        assert!(DappsStaking::general_era_info(long_unlock_era)
+            .unwrap()
+            .boost
+            .is_zero());
+    })
+}
+
+#[test]
+fn bond_and_stake_with_lock_is_not_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let staker = 3;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        assert_register(10, &contract_id);
+
+        assert_noop!(
+            DappsStaking::bond_and_stake_with_lock(
+                RuntimeOrigin::signed(staker),
+                contract_id,
+                100,
+                SHORT_STAKE_LOCK.0 + 1
+            ),
+            Error::<TestRuntime>::InvalidStakeLockDuration
+        );
+
+        // Each era, a new lock is added until the limit is reached
+        for _ in 0..MAX_STAKE_LOCKS {
+            assert_bond_and_stake_with_lock(staker, &contract_id, 100, LONG_STAKE_LOCK.0);
+            advance_to_era(DappsStaking::current_era() + 1);
+        }
+        assert_noop!(
+            DappsStaking::bond_and_stake_with_lock(
+                RuntimeOrigin::signed(staker),
+                contract_id,
+                100,
+                LONG_STAKE_LOCK.0
+            ),
+            Error::<TestRuntime>::TooManyStakeLocks
+        );
+
+        // Boost of a limited number of contracts can expire in the same era
+        let developers = [11, 7];
+        assert_eq!(developers.len() as u32, MAX_BOOST_EXPIRIES_PER_ERA);
+        for (index, developer) in developers.into_iter().enumerate() {
+            let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x02 + index as u8));
+            assert_register(developer, &contract_id);
+            assert_bond_and_stake_with_lock(staker, &contract_id, 100, LONG_STAKE_LOCK.0);
+        }
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0xFF));
+        assert_register(8, &contract_id);
+        assert_noop!(
+            DappsStaking::bond_and_stake_with_lock(
+                RuntimeOrigin::signed(staker),
+                contract_id,
+                100,
+                LONG_STAKE_LOCK.0
+            ),
+            Error::<TestRuntime>::TooManyBoostExpiries
+        );
+    })
+}
+
+#[test]
+fn locked_stake_cannot_be_unstaked() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let staker = 1;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        let other_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x02));
+        assert_register(10, &contract_id);
+        assert_register(11, &other_contract_id);
+
+        assert_bond_and_stake(staker, &contract_id, 100);
+        assert_bond_and_stake_with_lock(staker, &contract_id, 200, SHORT_STAKE_LOCK.0);
+
+        // Only the part of the stake which isn't locked can be unstaked
+        assert_unbond_and_unstake(staker, &contract_id, 50);
+        assert_noop!(
+            DappsStaking::unbond_and_unstake(RuntimeOrigin::signed(staker), contract_id, 51),
+            Error::<TestRuntime>::StakeLocked
+        );
+        assert_noop!(
+            DappsStaking::instant_unbond_and_unstake(
+                RuntimeOrigin::signed(staker),
+                contract_id,
+                51
+            ),
+            Error::<TestRuntime>::StakeLocked
+        );
+        assert_noop!(
+            DappsStaking::nomination_transfer(
+                RuntimeOrigin::signed(staker),
+                contract_id,
+                51,
+                other_contract_id
+            ),
+            Error::<TestRuntime>::StakeLocked
+        );
+        assert_nomination_transfer(staker, &contract_id, 50, &other_contract_id);
+
+        // Once the lock expires, entire stake can be unstaked
+        advance_to_era(DappsStaking::current_era() + SHORT_STAKE_LOCK.0);
+        assert_unbond_and_unstake(staker, &contract_id, 200);
+    })
+}
+
+#[test]
+fn boosted_stake_rewards_are_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let (locked_staker, regular_staker) = (1, 3);
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        assert_register(10, &contract_id);
+
+        assert_bond_and_stake_with_lock(locked_staker, &contract_id, 1000, LONG_STAKE_LOCK.0);
+        assert_bond_and_stake(regular_staker, &contract_id, 1000);
+        assert_set_reward_destination(locked_staker, RewardDestination::FreeBalance);
+        assert_set_reward_destination(regular_staker, RewardDestination::FreeBalance);
+        let start_era = DappsStaking::current_era();
+        advance_to_era(start_era + 1);
+
+        // Locked stake has a higher reward weight
+        let (_, stakers_joint_reward) = DappsStaking::dev_stakers_split(
+            &DappsStaking::contract_stake_info(&contract_id, start_era).unwrap(),
+            &DappsStaking::general_era_info(start_era).unwrap(),
+        );
+        let locked_stake_weight = 1000 + LONG_STAKE_LOCK.1 * 1000 as Balance;
+        let total_weight = locked_stake_weight + 1000;
+        assert_eq!(
+            DappsStaking::staker_claimable_rewards(&locked_staker, &contract_id),
+            vec![(
+                start_era,
+                Perbill::from_rational(locked_stake_weight, total_weight) * stakers_joint_reward
+            )]
+        );
+        assert_eq!(
+            DappsStaking::staker_claimable_rewards(&regular_staker, &contract_id),
+            vec![(
+                start_era,
+                Perbill::from_rational(1000, total_weight) * stakers_joint_reward
+            )]
+        );
+        assert_claim_staker(locked_staker, &contract_id);
+        assert_claim_staker(regular_staker, &contract_id);
+
+        // Stake lock is kept until rewards for all of the locked eras have been claimed
+        let unlock_era = start_era + LONG_STAKE_LOCK.0;
+        advance_to_era(unlock_era + 1);
+        assert_claim_staker_batch(locked_staker, &contract_id, LONG_STAKE_LOCK.0 - 2);
+        assert_eq!(
+            DappsStaking::stake_locks(&locked_staker, &contract_id).len(),
+            1
+        );
+        assert_claim_staker(locked_staker, &contract_id);
+        assert!(!StakeLocks::<TestRuntime>::contains_key(
+            &locked_staker,
+            &contract_id
+        ));
+
+        // Reward weight is no longer boosted from the unlock era
+        let (last_era, regular_reward) =
+            *DappsStaking::staker_claimable_rewards(&regular_staker, &contract_id)
+                .last()
+                .unwrap();
+        assert_eq!(last_era, unlock_era);
+        assert_eq!(
+            DappsStaking::staker_claimable_rewards(&locked_staker, &contract_id),
+            vec![(unlock_era, regular_reward)]
+        );
+    })
+}
+
+#[test]
+fn withdraw_from_unregistered_releases_stake_locks() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let staker = 1;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        let other_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x02));
+        assert_register(10, &contract_id);
+        assert_register(11, &other_contract_id);
+
+        assert_bond_and_stake_with_lock(staker, &contract_id, 100, SHORT_STAKE_LOCK.0);
+        assert_bond_and_stake_with_lock(3, &other_contract_id, 100, SHORT_STAKE_LOCK.0);
+        let unlock_era = DappsStaking::current_era() + SHORT_STAKE_LOCK.0;
+        let other_boost = SHORT_STAKE_LOCK.1 * 100 as Balance;
+
+        advance_to_era(DappsStaking::current_era() + 1);
+        assert_unregister(10, &contract_id);
+        assert_claim_staker(staker, &contract_id);
+        assert_withdraw_from_unregistered(staker, &contract_id);
+
+        // Boost of the withdrawn stake is removed immediately, and won't expire again
+        let current_era = DappsStaking::current_era();
+        assert!(!StakeLocks::<TestRuntime>::contains_key(
+            &staker,
+            &contract_id
+        ));
+        assert!(expiring_boost(&contract_id, unlock_era).is_zero());
+        assert_eq!(
+            DappsStaking::general_era_info(current_era).unwrap().boost,
+            other_boost
+        );
+
+        advance_to_era(unlock_era - 1);
+        assert_eq!(
+            DappsStaking::general_era_info(unlock_era - 1)
+                .unwrap()
+                .boost,
+            other_boost
+        );
+        advance_to_era(unlock_era);
+        assert!(DappsStaking::general_era_info(unlock_era)
+            .unwrap()
+            .boost
+            .is_zero());
+    })
+}
+
+#[test]
+fn unbond_and_unstake_with_no_chunks_allowed() {
+    // UT can be used to verify situation when MaxUnlockingChunks = 0. Requires mock modification.
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        // Sanity check
+        assert_eq!(<TestRuntime as Config>::MaxUnlockingChunks::get(), 0);
+
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        assert_register(10, &contract_id);
+
+        let staker_id = 1;
+        assert_bond_and_stake(staker_id, &contract_id, 100);
+
+        assert_noop!(
+            DappsStaking::unbond_and_unstake(
+                RuntimeOrigin::signed(staker_id),
+                contract_id.clone(),
+                20
+            ),
+            Error::<TestRuntime>::TooManyUnlockingChunks,
+        );
+    })
+}
+
+#[test]
+fn withdraw_unbonded_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        assert_register(10, &contract_id);
+
+        let staker_id = 1;
+        assert_bond_and_stake(staker_id, &contract_id, 1000);
+
+        let first_unbond_value = 75;
+        let second_unbond_value = 39;
+        let initial_era = DappsStaking::current_era();
+
+        // Unbond some amount in the initial era
+        assert_unbond_and_unstake(staker_id, &contract_id, first_unbond_value);
+
+        // Advance one era and then unbond some more
+        advance_to_era(initial_era + 1);
+        assert_unbond_and_unstake(staker_id, &contract_id, second_unbond_value);
+
+        // Now advance one era before first chunks finishes the unbonding process
+        advance_to_era(initial_era + UNBONDING_PERIOD - 1);
+        assert_noop!(
+            DappsStaking::withdraw_unbonded(RuntimeOrigin::signed(staker_id)),
+            Error::<TestRuntime>::NothingToWithdraw
+        );
+
+        // Advance one additional era and expect that the first chunk can be withdrawn
+        advance_to_era(DappsStaking::current_era() + 1);
+        assert_ok!(DappsStaking::withdraw_unbonded(RuntimeOrigin::signed(
+            staker_id
+        ),));
+        System::assert_last_event(mock::RuntimeEvent::DappsStaking(Event::Withdrawn(
+            staker_id,
+            first_unbond_value,
         )));
 
         // Advance one additional era and expect that the first chunk can be withdrawn
@@ -1192,15 +2360,16 @@ fn withdraw_unbonded_no_value_is_not_ok() {
     })
 }
 
-#[ignore]
 #[test]
 fn withdraw_unbonded_no_unbonding_period() {
-    // UT can be used to verify situation when UnbondingPeriod = 0. Requires mock modification.
     ExternalityBuilder::build().execute_with(|| {
         initialize_first_block();
 
-        // Sanity check
-        assert_eq!(<TestRuntime as Config>::UnbondingPeriod::get(), 0);
+        // Disable the unbonding period, starting from the next era
+        let mut parameters = DappsStaking::staking_parameters();
+        parameters.unbonding_period = 0;
+        assert_set_staking_parameters(parameters);
+        advance_to_era(DappsStaking::current_era() + 1);
 
         let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
         assert_register(10, &contract_id);
@@ -1209,1112 +2378,3108 @@ fn withdraw_unbonded_no_unbonding_period() {
         assert_bond_and_stake(staker_id, &contract_id, 100);
         assert_unbond_and_unstake(staker_id, &contract_id, 20);
 
-        // Try to withdraw but expect an error since current era hasn't passed yet
-        assert_noop!(
-            DappsStaking::withdraw_unbonded(RuntimeOrigin::signed(staker_id)),
-            Error::<TestRuntime>::NothingToWithdraw,
-        );
-
-        // Advance an era and expect successful withdrawal
-        advance_to_era(DappsStaking::current_era() + 1);
+        // Unbonded funds can be withdrawn immediately
         assert_withdraw_unbonded(staker_id);
     })
 }
 
 #[test]
-fn nomination_transfer_is_ok() {
+fn set_staking_parameters_is_ok() {
     ExternalityBuilder::build().execute_with(|| {
         initialize_first_block();
 
-        let origin_developer = 1;
-        let target_developer = 2;
-        let staker = 3;
-        let origin_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
-        let target_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x02));
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        assert_register(10, &contract_id);
 
-        assert_register(origin_developer, &origin_contract_id);
-        assert_register(target_developer, &target_contract_id);
-        assert_bond_and_stake(staker, &origin_contract_id, MINIMUM_STAKING_AMOUNT * 2);
+        let mut parameters = DappsStaking::staking_parameters();
+        parameters.block_per_era = BLOCKS_PER_ERA * 2;
+        parameters.max_number_of_stakers_per_contract = 1;
+        parameters.minimum_staking_amount = MINIMUM_STAKING_AMOUNT * 2;
+        assert_set_staking_parameters(parameters.clone());
 
-        // The first transfer will ensure that both contracts are staked after operation is complete
-        assert_nomination_transfer(
-            staker,
-            &origin_contract_id,
-            MINIMUM_STAKING_AMOUNT,
-            &target_contract_id,
-        );
-        assert!(
-            !GeneralStakerInfo::<TestRuntime>::get(&staker, &origin_contract_id)
-                .latest_staked_value()
-                .is_zero()
-        );
+        // Start of the next era isn't affected by the new era length
+        let next_era_starting_block = DappsStaking::next_era_starting_block();
+        let start_era = DappsStaking::current_era();
+        run_to_block(next_era_starting_block);
+        assert_eq!(DappsStaking::current_era(), start_era + 1);
+        assert_eq!(DappsStaking::staking_parameters(), parameters);
+        assert!(DappsStaking::pending_staking_parameters().is_none());
 
-        // The second operation should fully unstake origin contract since it takes it below minimum staking amount
-        assert_nomination_transfer(
-            staker,
-            &origin_contract_id,
-            MINIMUM_STAKING_AMOUNT,
-            &target_contract_id,
-        );
-        assert!(
-            GeneralStakerInfo::<TestRuntime>::get(&staker, &origin_contract_id)
-                .latest_staked_value()
-                .is_zero()
+        // New era lasts for the new number of blocks
+        assert_eq!(
+            DappsStaking::next_era_starting_block(),
+            next_era_starting_block + BLOCKS_PER_ERA * 2
         );
-    })
-}
-
-#[test]
-fn nomination_transfer_to_same_contract_is_not_ok() {
-    ExternalityBuilder::build().execute_with(|| {
-        initialize_first_block();
-        let developer = 1;
-        let staker = 2;
-        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
-
-        assert_register(developer, &contract_id);
 
+        // New staking limits apply
         assert_noop!(
-            DappsStaking::nomination_transfer(
-                RuntimeOrigin::signed(staker),
+            DappsStaking::bond_and_stake(
+                RuntimeOrigin::signed(1),
                 contract_id,
-                100,
+                MINIMUM_STAKING_AMOUNT
+            ),
+            Error::<TestRuntime>::InsufficientValue
+        );
+        assert_bond_and_stake(1, &contract_id, MINIMUM_STAKING_AMOUNT * 2);
+        assert_noop!(
+            DappsStaking::bond_and_stake(
+                RuntimeOrigin::signed(2),
                 contract_id,
+                MINIMUM_STAKING_AMOUNT * 2
             ),
-            Error::<TestRuntime>::NominationTransferToSameContract
+            Error::<TestRuntime>::MaxNumberOfStakersExceeded
         );
     })
 }
 
 #[test]
-fn nomination_transfer_to_inactive_contracts_is_not_ok() {
+fn shorter_unbonding_period_does_not_shorten_existing_chunks() {
     ExternalityBuilder::build().execute_with(|| {
         initialize_first_block();
 
-        let origin_developer = 1;
-        let target_developer = 2;
-        let staker = 3;
-        let origin_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
-        let target_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x02));
-
-        // 1. Neither contract is registered
-        assert_noop!(
-            DappsStaking::nomination_transfer(
-                RuntimeOrigin::signed(staker),
-                origin_contract_id,
-                100,
-                target_contract_id,
-            ),
-            Error::<TestRuntime>::NotOperatedContract
-        );
+        let staker = 1;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        assert_register(10, &contract_id);
+        assert_bond_and_stake(staker, &contract_id, 100);
 
-        // 2. Only first contract is registered
-        assert_register(origin_developer, &origin_contract_id);
-        assert_noop!(
-            DappsStaking::nomination_transfer(
-                RuntimeOrigin::signed(staker),
-                origin_contract_id,
-                100,
-                target_contract_id,
-            ),
-            Error::<TestRuntime>::NotOperatedContract
-        );
+        let start_era = DappsStaking::current_era();
+        assert_unbond_and_unstake(staker, &contract_id, 20);
 
-        // 3. Both are registered but then target contract gets unregistered
-        assert_register(target_developer, &target_contract_id);
-        assert_bond_and_stake(staker, &origin_contract_id, 100);
-        assert_nomination_transfer(staker, &origin_contract_id, 100, &target_contract_id);
+        let mut parameters = DappsStaking::staking_parameters();
+        parameters.unbonding_period = 1;
+        assert_set_staking_parameters(parameters);
+        advance_to_era(start_era + 1);
 
-        assert_unregister(target_developer, &target_contract_id);
-        assert_noop!(
-            DappsStaking::nomination_transfer(
-                RuntimeOrigin::signed(staker),
-                origin_contract_id,
-                100,
-                target_contract_id,
-            ),
-            Error::<TestRuntime>::NotOperatedContract
+        // New chunk unlocks before the existing one
+        assert_unbond_and_unstake(staker, &contract_id, 30);
+        assert_eq!(
+            DappsStaking::unlocking_chunks(&staker),
+            vec![(start_era + 2, 30), (start_era + UNBONDING_PERIOD, 20)]
         );
 
-        // 4. Origin contract is unregistered
-        assert_unregister(origin_developer, &origin_contract_id);
-        assert_noop!(
-            DappsStaking::nomination_transfer(
-                RuntimeOrigin::signed(staker),
-                origin_contract_id,
-                100,
-                target_contract_id,
-            ),
-            Error::<TestRuntime>::NotOperatedContract
+        // Only the new chunk can be withdrawn once its unbonding period passes
+        advance_to_era(start_era + 2);
+        assert_withdraw_unbonded(staker);
+        assert_eq!(
+            DappsStaking::unlocking_chunks(&staker),
+            vec![(start_era + UNBONDING_PERIOD, 20)]
         );
     })
 }
 
 #[test]
-fn nomination_transfer_from_not_staked_contract() {
+fn set_staking_parameters_is_not_ok() {
     ExternalityBuilder::build().execute_with(|| {
         initialize_first_block();
 
-        let origin_developer = 1;
-        let target_developer = 2;
-        let staker = 3;
-        let origin_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
-        let target_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x02));
-
-        assert_register(origin_developer, &origin_contract_id);
-        assert_register(target_developer, &target_contract_id);
-
+        let parameters = DappsStaking::staking_parameters();
         assert_noop!(
-            DappsStaking::nomination_transfer(
-                RuntimeOrigin::signed(staker),
-                origin_contract_id.clone(),
-                20,
-                target_contract_id.clone()
-            ),
-            Error::<TestRuntime>::NotStakedContract
+            DappsStaking::set_staking_parameters(RuntimeOrigin::signed(1), parameters.clone()),
+            BadOrigin
         );
-    })
-}
-
-#[test]
-fn nomination_transfer_with_no_value() {
-    ExternalityBuilder::build().execute_with(|| {
-        initialize_first_block();
 
-        let origin_developer = 1;
-        let target_developer = 2;
-        let staker = 3;
-        let origin_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
-        let target_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x02));
+        let mut invalid_parameters = parameters.clone();
+        invalid_parameters.block_per_era = 0;
+        assert_noop!(
+            DappsStaking::set_staking_parameters(RuntimeOrigin::root(), invalid_parameters),
+            Error::<TestRuntime>::InvalidStakingParameters
+        );
 
-        assert_register(origin_developer, &origin_contract_id);
-        assert_register(target_developer, &target_contract_id);
-        assert_bond_and_stake(staker, &origin_contract_id, 100);
+        let mut invalid_parameters = parameters.clone();
+        invalid_parameters.minimum_staking_amount = 0;
+        assert_noop!(
+            DappsStaking::set_staking_parameters(RuntimeOrigin::root(), invalid_parameters),
+            Error::<TestRuntime>::InvalidStakingParameters
+        );
 
+        let mut invalid_parameters = parameters;
+        invalid_parameters.max_number_of_stakers_per_contract = 0;
         assert_noop!(
-            DappsStaking::nomination_transfer(
-                RuntimeOrigin::signed(staker),
-                origin_contract_id.clone(),
-                Zero::zero(),
-                target_contract_id.clone()
-            ),
-            Error::<TestRuntime>::UnstakingWithNoValue
+            DappsStaking::set_staking_parameters(RuntimeOrigin::root(), invalid_parameters),
+            Error::<TestRuntime>::InvalidStakingParameters
         );
     })
 }
 
 #[test]
-fn nomination_transfer_with_insufficient_value() {
+fn rebond_is_ok() {
     ExternalityBuilder::build().execute_with(|| {
         initialize_first_block();
 
-        let origin_developer = 1;
-        let target_developer = 2;
-        let staker = 3;
-        let origin_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
-        let target_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x02));
+        let staker = 1;
+        let (first_contract_id, second_contract_id) = (
+            MockSmartContract::Evm(H160::repeat_byte(0x01)),
+            MockSmartContract::Evm(H160::repeat_byte(0x02)),
+        );
+        assert_register(10, &first_contract_id);
+        assert_register(11, &second_contract_id);
+        assert_bond_and_stake(staker, &first_contract_id, 1000);
 
-        assert_register(origin_developer, &origin_contract_id);
-        assert_register(target_developer, &target_contract_id);
-        assert_bond_and_stake(staker, &origin_contract_id, 100);
+        let first_era = DappsStaking::current_era();
+        assert_unbond_and_unstake(staker, &first_contract_id, 100);
+        advance_to_era(first_era + 1);
+        assert_unbond_and_unstake(staker, &first_contract_id, 200);
 
-        assert_noop!(
-            DappsStaking::nomination_transfer(
-                RuntimeOrigin::signed(staker),
-                origin_contract_id.clone(),
-                MINIMUM_STAKING_AMOUNT - 1,
-                target_contract_id.clone()
-            ),
-            Error::<TestRuntime>::InsufficientValue
+        // Newest chunk is rebonded first
+        assert_rebond(staker, &first_contract_id, 150);
+        assert_eq!(
+            DappsStaking::unlocking_chunks(&staker),
+            vec![
+                (first_era + UNBONDING_PERIOD, 100),
+                (first_era + 1 + UNBONDING_PERIOD, 50)
+            ]
+        );
+
+        // Rebond onto a different contract, spanning multiple chunks
+        assert_rebond(staker, &second_contract_id, 120);
+        assert_eq!(
+            DappsStaking::unlocking_chunks(&staker),
+            vec![(first_era + UNBONDING_PERIOD, 30)]
         );
+
+        // Rebonding more than what's unbonding rebonds everything
+        advance_to_era(first_era + 2);
+        assert_rebond(staker, &second_contract_id, 1000);
+        assert!(DappsStaking::unlocking_chunks(&staker).is_empty());
+        assert_eq!(DappsStaking::ledger(&staker).locked, 1000);
     })
 }
 
 #[test]
-fn nomination_transfer_contracts_have_too_many_era_stake_values() {
+fn rebond_is_not_ok() {
     ExternalityBuilder::build().execute_with(|| {
         initialize_first_block();
 
-        let origin_developer = 1;
-        let target_developer = 2;
-        let staker = 3;
-        let origin_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
-        let target_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x02));
+        let staker = 1;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        let not_registered_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x02));
+        assert_register(10, &contract_id);
+        assert_bond_and_stake(staker, &contract_id, 100);
 
-        assert_register(origin_developer, &origin_contract_id);
-        assert_register(target_developer, &target_contract_id);
+        // Nothing is unbonding
+        assert_noop!(
+            DappsStaking::rebond(RuntimeOrigin::signed(staker), contract_id, 100),
+            Error::<TestRuntime>::NothingToRebond
+        );
 
-        // Ensure we fill up era stakes vector
-        for _ in 1..MAX_ERA_STAKE_VALUES {
-            // We use bond&stake since its only limiting factor is max era stake values
-            assert_bond_and_stake(staker, &origin_contract_id, 15);
-            advance_to_era(DappsStaking::current_era() + 1);
-        }
+        assert_unbond_and_unstake(staker, &contract_id, 100);
         assert_noop!(
-            DappsStaking::bond_and_stake(
-                RuntimeOrigin::signed(staker),
-                origin_contract_id.clone(),
-                15
-            ),
-            Error::<TestRuntime>::TooManyEraStakeValues
+            DappsStaking::rebond(RuntimeOrigin::signed(staker), contract_id, 0),
+            Error::<TestRuntime>::NothingToRebond
         );
 
-        // Ensure it's not possible to transfer from origin contract since it's era stake values are maxed
+        // Contract must be registered
         assert_noop!(
-            DappsStaking::nomination_transfer(
+            DappsStaking::rebond(
                 RuntimeOrigin::signed(staker),
-                origin_contract_id.clone(),
-                15,
-                target_contract_id.clone()
+                not_registered_contract_id,
+                100
             ),
-            Error::<TestRuntime>::TooManyEraStakeValues
+            Error::<TestRuntime>::NotOperatedContract
         );
 
-        // Swap origin and target to verify that same is true if target contract era stake values is maxed out
-        let (origin_contract_id, target_contract_id) = (target_contract_id, origin_contract_id);
-        assert_bond_and_stake(staker, &origin_contract_id, 15);
+        // Rebonded value must satisfy the minimum staking amount
         assert_noop!(
-            DappsStaking::nomination_transfer(
+            DappsStaking::rebond(
                 RuntimeOrigin::signed(staker),
-                origin_contract_id.clone(),
-                15,
-                target_contract_id.clone()
+                contract_id,
+                MINIMUM_STAKING_AMOUNT - 1
             ),
-            Error::<TestRuntime>::TooManyEraStakeValues
+            Error::<TestRuntime>::InsufficientValue
         );
-    })
-}
 
-#[test]
-fn nomination_transfer_max_number_of_stakers_exceeded() {
-    ExternalityBuilder::build().execute_with(|| {
-        initialize_first_block();
-
-        let origin_developer = 1;
-        let target_developer = 2;
-        // This one will only stake on origin contract
-        let first_staker = 3;
-        // This one will stake on both origin and target contracts
-        let second_staker = 4;
-        let origin_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
-        let target_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x02));
-
-        // Register contracts and bond&stake them with both stakers
-        assert_register(origin_developer, &origin_contract_id);
-        assert_register(target_developer, &target_contract_id);
-
-        assert_bond_and_stake(first_staker, &origin_contract_id, 23);
-        assert_bond_and_stake(second_staker, &target_contract_id, 37);
-        assert_bond_and_stake(second_staker, &target_contract_id, 41);
-
-        // Fill up the second contract with stakers until max number of stakers limit has been reached
-        for temp_staker in (second_staker + 1)..(MAX_NUMBER_OF_STAKERS as u64 + second_staker) {
-            Balances::resolve_creating(&temp_staker, Balances::issue(100));
-            assert_bond_and_stake(temp_staker, &target_contract_id, 13);
+        // Number of stakers per contract is limited
+        for other_staker in 2..(MAX_NUMBER_OF_STAKERS as u64 + 2) {
+            assert_bond_and_stake(other_staker, &contract_id, MINIMUM_STAKING_AMOUNT);
         }
-        // Sanity check + assurance that first_staker isn't staking on target contract
         assert_noop!(
-            DappsStaking::bond_and_stake(
-                RuntimeOrigin::signed(first_staker),
-                target_contract_id.clone(),
-                19
-            ),
+            DappsStaking::rebond(RuntimeOrigin::signed(staker), contract_id, 100),
             Error::<TestRuntime>::MaxNumberOfStakersExceeded
         );
 
-        // Now attempt transfer nomination and expect an error
+        // Contract mustn't be unregistered
+        assert_unregister(10, &contract_id);
         assert_noop!(
-            DappsStaking::nomination_transfer(
-                RuntimeOrigin::signed(first_staker),
-                origin_contract_id.clone(),
-                19,
-                target_contract_id.clone(),
-            ),
-            Error::<TestRuntime>::MaxNumberOfStakersExceeded
+            DappsStaking::rebond(RuntimeOrigin::signed(staker), contract_id, 100),
+            Error::<TestRuntime>::NotOperatedContract
         );
     })
 }
 
 #[test]
-fn claim_not_staked_contract() {
+fn rebond_with_too_many_era_stake_values_is_not_ok() {
     ExternalityBuilder::build().execute_with(|| {
         initialize_first_block();
 
-        let developer = 1;
-        let staker = 2;
+        let staker = 1;
         let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        assert_register(10, &contract_id);
 
-        assert_register(developer, &contract_id);
-
-        assert_noop!(
-            DappsStaking::claim_staker(RuntimeOrigin::signed(staker), contract_id),
-            Error::<TestRuntime>::NotStakedContract
-        );
-
+        // Fill up the staker info so only one spot remains, for the reward claim
+        let start_era = DappsStaking::current_era();
+        for offset in 1..MAX_ERA_STAKE_VALUES - 1 {
+            assert_bond_and_stake(staker, &contract_id, 100);
+            advance_to_era(start_era + offset);
+        }
+        assert_unbond_and_unstake(staker, &contract_id, 100);
         advance_to_era(DappsStaking::current_era() + 1);
+
         assert_noop!(
-            DappsStaking::claim_dapp(RuntimeOrigin::signed(developer), contract_id, 1),
-            Error::<TestRuntime>::NotStakedContract
+            DappsStaking::rebond(RuntimeOrigin::signed(staker), contract_id, 100),
+            Error::<TestRuntime>::TooManyEraStakeValues
         );
     })
 }
 
 #[test]
-fn claim_not_operated_contract() {
+fn set_dapp_commission_is_ok() {
     ExternalityBuilder::build().execute_with(|| {
         initialize_first_block();
 
         let developer = 1;
-        let staker = 2;
         let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
-
         assert_register(developer, &contract_id);
-        assert_bond_and_stake(staker, &contract_id, 100);
 
-        // Advance one era and unregister the contract
-        advance_to_era(DappsStaking::current_era() + 1);
-        assert_unregister(developer, &contract_id);
+        // No commission by default
+        let start_era = DappsStaking::current_era();
+        assert!(DappsStaking::dapp_commission_at(&contract_id, start_era).is_zero());
+
+        // Setting it again in the same era overwrites the pending value
+        assert_set_dapp_commission(developer, &contract_id, Perbill::from_percent(10));
+        assert_set_dapp_commission(developer, &contract_id, Perbill::from_percent(20));
+        assert!(!DappCommissionHistory::<TestRuntime>::contains_key(
+            &contract_id,
+            start_era + 1
+        ));
 
-        // First claim should pass but second should fail because contract was unregistered
-        assert_claim_staker(staker, &contract_id);
-        assert_noop!(
-            DappsStaking::claim_staker(RuntimeOrigin::signed(staker), contract_id),
-            Error::<TestRuntime>::NotOperatedContract
-        );
+        // Change it in later eras and ensure the past values are preserved
+        advance_to_era(start_era + 3);
+        assert_set_dapp_commission(developer, &contract_id, Perbill::from_percent(50));
+        advance_to_era(start_era + 5);
+        assert_set_dapp_commission(developer, &contract_id, Perbill::zero());
 
-        assert_claim_dapp(&contract_id, 1);
-        assert_noop!(
-            DappsStaking::claim_dapp(RuntimeOrigin::signed(developer), contract_id, 2),
-            Error::<TestRuntime>::NotOperatedContract
-        );
+        let expected_commissions = [0, 20, 20, 20, 50, 50, 0];
+        for (offset, commission) in expected_commissions.iter().enumerate() {
+            assert_eq!(
+                DappsStaking::dapp_commission_at(&contract_id, start_era + offset as EraIndex),
+                Perbill::from_percent(*commission)
+            );
+        }
     })
 }
 
 #[test]
-fn claim_invalid_era() {
+fn set_dapp_commission_is_not_ok() {
     ExternalityBuilder::build().execute_with(|| {
         initialize_first_block();
 
         let developer = 1;
-        let staker = 2;
         let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
 
-        let start_era = DappsStaking::current_era();
-        assert_register(developer, &contract_id);
-        assert_bond_and_stake(staker, &contract_id, 100);
-        advance_to_era(start_era + 5);
-
-        for era in start_era..DappsStaking::current_era() {
-            assert_claim_staker(staker, &contract_id);
-            assert_claim_dapp(&contract_id, era);
-        }
-
         assert_noop!(
-            DappsStaking::claim_staker(RuntimeOrigin::signed(staker), contract_id),
-            Error::<TestRuntime>::EraOutOfBounds
-        );
-        assert_noop!(
-            DappsStaking::claim_dapp(
+            DappsStaking::set_dapp_commission(
                 RuntimeOrigin::signed(developer),
                 contract_id,
-                DappsStaking::current_era()
+                Perbill::from_percent(10)
             ),
-            Error::<TestRuntime>::EraOutOfBounds
+            Error::<TestRuntime>::NotOperatedContract
         );
-    })
-}
-
-#[test]
-fn claim_dapp_same_era_twice() {
-    ExternalityBuilder::build().execute_with(|| {
-        initialize_first_block();
-
-        let developer = 1;
-        let staker = 2;
-        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
 
-        let start_era = DappsStaking::current_era();
         assert_register(developer, &contract_id);
-        assert_bond_and_stake(staker, &contract_id, 100);
-        advance_to_era(start_era + 1);
+        assert_noop!(
+            DappsStaking::set_dapp_commission(
+                RuntimeOrigin::signed(2),
+                contract_id,
+                Perbill::from_percent(10)
+            ),
+            Error::<TestRuntime>::NotOwnedContract
+        );
 
-        assert_claim_dapp(&contract_id, start_era);
+        assert_unregister(developer, &contract_id);
         assert_noop!(
-            DappsStaking::claim_dapp(RuntimeOrigin::signed(developer), contract_id, start_era),
-            Error::<TestRuntime>::AlreadyClaimedInThisEra
+            DappsStaking::set_dapp_commission(
+                RuntimeOrigin::signed(developer),
+                contract_id,
+                Perbill::from_percent(10)
+            ),
+            Error::<TestRuntime>::NotOperatedContract
         );
     })
 }
 
 #[test]
-fn claim_is_ok() {
+fn claim_with_dapp_commission_is_ok() {
     ExternalityBuilder::build().execute_with(|| {
         initialize_first_block();
 
-        let first_developer = 1;
-        let second_developer = 2;
+        let developer = 1;
         let first_staker = 3;
         let second_staker = 4;
-        let first_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
-        let second_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x02));
-
-        let start_era = DappsStaking::current_era();
-
-        // Prepare a scenario with different stakes
-
-        assert_register(first_developer, &first_contract_id);
-        assert_register(second_developer, &second_contract_id);
-        assert_bond_and_stake(first_staker, &first_contract_id, 100);
-        assert_bond_and_stake(second_staker, &first_contract_id, 45);
-
-        // Just so ratio isn't 100% in favor of the first contract
-        assert_bond_and_stake(first_staker, &second_contract_id, 33);
-        assert_bond_and_stake(second_staker, &second_contract_id, 22);
-
-        let eras_advanced = 3;
-        advance_to_era(start_era + eras_advanced);
-
-        for x in 0..eras_advanced.into() {
-            assert_bond_and_stake(first_staker, &first_contract_id, 20 + x * 3);
-            assert_bond_and_stake(second_staker, &first_contract_id, 5 + x * 5);
-            advance_to_era(DappsStaking::current_era() + 1);
-        }
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
 
-        // Ensure that all past eras can be claimed
-        let current_era = DappsStaking::current_era();
-        for era in start_era..current_era {
-            assert_claim_staker(first_staker, &first_contract_id);
-            assert_claim_dapp(&first_contract_id, era);
-            assert_claim_staker(second_staker, &first_contract_id);
-        }
+        assert_register(developer, &contract_id);
+        assert_bond_and_stake(first_staker, &contract_id, 100);
+        assert_bond_and_stake(second_staker, &contract_id, 300);
 
-        // Shouldn't be possible to claim current era.
-        // Also, previous claim calls should have claimed everything prior to current era.
-        assert_noop!(
-            DappsStaking::claim_staker(
-                RuntimeOrigin::signed(first_staker),
-                first_contract_id.clone()
-            ),
-            Error::<TestRuntime>::EraOutOfBounds
-        );
-        assert_noop!(
-            DappsStaking::claim_dapp(
-                RuntimeOrigin::signed(first_developer),
-                first_contract_id,
-                current_era
-            ),
-            Error::<TestRuntime>::EraOutOfBounds
+        // Commission applies only from the next era
+        let start_era = DappsStaking::current_era();
+        assert_set_dapp_commission(developer, &contract_id, Perbill::from_percent(40));
+        advance_to_era(start_era + 3);
+
+        // Developer keeps the remaining part of the reward, and nothing is paid out on top of the contract reward
+        let era = start_era + 1;
+        let (developer_reward, stakers_joint_reward) = DappsStaking::dev_stakers_split(
+            &DappsStaking::contract_stake_info(&contract_id, era).unwrap(),
+            &DappsStaking::general_era_info(era).unwrap(),
         );
+        let dapp_reward = DappsStaking::dapp_claimable_rewards(&contract_id)[1].1;
+        assert_eq!(dapp_reward, Perbill::from_percent(60) * developer_reward);
+
+        let stakers_reward: Balance = [first_staker, second_staker]
+            .iter()
+            .map(|staker| DappsStaking::staker_claimable_rewards(staker, &contract_id)[1].1)
+            .sum();
+        assert!(stakers_reward > stakers_joint_reward);
+        assert!(dapp_reward + stakers_reward <= developer_reward + stakers_joint_reward);
+
+        // First era is claimed without the commission, the rest with it
+        for era in start_era..start_era + 3 {
+            assert_claim_dapp(&contract_id, era);
+        }
+        assert_claim_staker(first_staker, &contract_id);
+        assert_claim_staker_batch(first_staker, &contract_id, 2);
+        assert_claim_staker_batch(second_staker, &contract_id, 3);
     })
 }
 
 #[test]
-fn claim_after_unregister_is_ok() {
+fn burn_stale_reward_with_dapp_commission_is_ok() {
     ExternalityBuilder::build().execute_with(|| {
         initialize_first_block();
 
         let developer = 1;
-        let staker = 2;
+        let staker = 3;
         let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
 
-        let start_era = DappsStaking::current_era();
         assert_register(developer, &contract_id);
-        let stake_value = 100;
-        assert_bond_and_stake(staker, &contract_id, stake_value);
-
-        // Advance few eras, then unstake everything
-        advance_to_era(start_era + 5);
-        assert_unbond_and_unstake(staker, &contract_id, stake_value);
-        let full_unstake_era = DappsStaking::current_era();
-        let number_of_staking_eras = full_unstake_era - start_era;
-
-        // Few eras pass, then staker stakes again
-        advance_to_era(DappsStaking::current_era() + 3);
-        let stake_value = 75;
-        let restake_era = DappsStaking::current_era();
-        assert_bond_and_stake(staker, &contract_id, stake_value);
+        assert_bond_and_stake(staker, &contract_id, 100);
+        assert_set_dapp_commission(developer, &contract_id, Perbill::from_percent(30));
 
-        // Again, few eras pass then contract is unregistered
-        advance_to_era(DappsStaking::current_era() + 3);
+        let start_era = DappsStaking::current_era();
+        advance_to_era(start_era + REWARD_RETENTION_PERIOD + 2);
         assert_unregister(developer, &contract_id);
-        let unregister_era = DappsStaking::current_era();
-        let number_of_staking_eras = number_of_staking_eras + unregister_era - restake_era;
-        advance_to_era(DappsStaking::current_era() + 2);
-
-        // Ensure that staker can claim all the eras that he had an active stake
-        for _ in 0..number_of_staking_eras {
-            assert_claim_staker(staker, &contract_id);
-        }
-        assert_noop!(
-            DappsStaking::claim_staker(RuntimeOrigin::signed(staker), contract_id.clone()),
-            Error::<TestRuntime>::NotOperatedContract
-        );
 
-        // Ensure the same for dapp reward
-        for era in start_era..unregister_era {
-            if era >= full_unstake_era && era < restake_era {
-                assert_noop!(
-                    DappsStaking::claim_dapp(
-                        RuntimeOrigin::signed(developer),
-                        contract_id.clone(),
-                        era
-                    ),
-                    Error::<TestRuntime>::NotStakedContract
-                );
-            } else {
-                assert_claim_dapp(&contract_id, era);
-            }
-        }
+        // Only developer part is burned, staker can still claim the shared part
+        assert_burn_stale_reward(&contract_id, start_era + 1);
+        assert_claim_staker_batch(staker, &contract_id, 2);
     })
 }
 
 #[test]
-fn claim_only_payout_is_ok() {
+fn nomination_transfer_is_ok() {
     ExternalityBuilder::build().execute_with(|| {
         initialize_first_block();
 
-        let developer = 1;
-        let staker = 2;
-        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        let origin_developer = 1;
+        let target_developer = 2;
+        let staker = 3;
+        let origin_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        let target_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x02));
 
-        // stake some tokens
-        let start_era = DappsStaking::current_era();
-        assert_register(developer, &contract_id);
-        let stake_value = 100;
-        assert_bond_and_stake(staker, &contract_id, stake_value);
+        assert_register(origin_developer, &origin_contract_id);
+        assert_register(target_developer, &target_contract_id);
+        assert_bond_and_stake(staker, &origin_contract_id, MINIMUM_STAKING_AMOUNT * 2);
 
-        // disable reward restaking
-        advance_to_era(start_era + 1);
-        assert_set_reward_destination(staker, RewardDestination::FreeBalance);
+        // The first transfer will ensure that both contracts are staked after operation is complete
+        assert_nomination_transfer(
+            staker,
+            &origin_contract_id,
+            MINIMUM_STAKING_AMOUNT,
+            &target_contract_id,
+        );
+        assert!(
+            !GeneralStakerInfo::<TestRuntime>::get(&staker, &origin_contract_id)
+                .latest_staked_value()
+                .is_zero()
+        );
 
-        // ensure it's claimed correctly
-        assert_claim_staker(staker, &contract_id);
+        // The second operation should fully unstake origin contract since it takes it below minimum staking amount
+        assert_nomination_transfer(
+            staker,
+            &origin_contract_id,
+            MINIMUM_STAKING_AMOUNT,
+            &target_contract_id,
+        );
+        assert!(
+            GeneralStakerInfo::<TestRuntime>::get(&staker, &origin_contract_id)
+                .latest_staked_value()
+                .is_zero()
+        );
     })
 }
 
 #[test]
-fn claim_with_zero_staked_is_ok() {
+fn nomination_transfer_to_same_contract_is_not_ok() {
     ExternalityBuilder::build().execute_with(|| {
         initialize_first_block();
-
         let developer = 1;
         let staker = 2;
         let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
-        let start_era = DappsStaking::current_era();
-        assert_register(developer, &contract_id);
-
-        // stake some tokens and wait for an era
-        let stake_value = 100;
-        assert_bond_and_stake(staker, &contract_id, stake_value);
-        advance_to_era(start_era + 1);
 
-        // ensure reward_destination is set to StakeBalance
-        assert_set_reward_destination(staker, RewardDestination::StakeBalance);
-
-        // unstake all the tokens
-        assert_unbond_and_unstake(staker, &contract_id, stake_value);
+        assert_register(developer, &contract_id);
 
-        // ensure claimed value goes to claimer's free balance
-        assert_claim_staker(staker, &contract_id);
+        assert_noop!(
+            DappsStaking::nomination_transfer(
+                RuntimeOrigin::signed(staker),
+                contract_id,
+                100,
+                contract_id,
+            ),
+            Error::<TestRuntime>::NominationTransferToSameContract
+        );
     })
 }
 
 #[test]
-fn claims_with_different_reward_destination_is_ok() {
+fn nomination_transfer_to_inactive_contracts_is_not_ok() {
     ExternalityBuilder::build().execute_with(|| {
         initialize_first_block();
 
-        let developer = 1;
-        let staker = 2;
-        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        let origin_developer = 1;
+        let target_developer = 2;
+        let staker = 3;
+        let origin_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        let target_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x02));
 
-        // stake some tokens
+        // 1. Neither contract is registered
+        assert_noop!(
+            DappsStaking::nomination_transfer(
+                RuntimeOrigin::signed(staker),
+                origin_contract_id,
+                100,
+                target_contract_id,
+            ),
+            Error::<TestRuntime>::NotOperatedContract
+        );
+
+        // 2. Only first contract is registered
+        assert_register(origin_developer, &origin_contract_id);
+        assert_noop!(
+            DappsStaking::nomination_transfer(
+                RuntimeOrigin::signed(staker),
+                origin_contract_id,
+                100,
+                target_contract_id,
+            ),
+            Error::<TestRuntime>::NotOperatedContract
+        );
+
+        // 3. Both are registered but then target contract gets unregistered
+        assert_register(target_developer, &target_contract_id);
+        assert_bond_and_stake(staker, &origin_contract_id, 100);
+        assert_nomination_transfer(staker, &origin_contract_id, 100, &target_contract_id);
+
+        assert_unregister(target_developer, &target_contract_id);
+        assert_noop!(
+            DappsStaking::nomination_transfer(
+                RuntimeOrigin::signed(staker),
+                origin_contract_id,
+                100,
+                target_contract_id,
+            ),
+            Error::<TestRuntime>::NotOperatedContract
+        );
+
+        // 4. Origin contract is unregistered
+        assert_unregister(origin_developer, &origin_contract_id);
+        assert_noop!(
+            DappsStaking::nomination_transfer(
+                RuntimeOrigin::signed(staker),
+                origin_contract_id,
+                100,
+                target_contract_id,
+            ),
+            Error::<TestRuntime>::NotOperatedContract
+        );
+    })
+}
+
+#[test]
+fn nomination_transfer_from_not_staked_contract() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let origin_developer = 1;
+        let target_developer = 2;
+        let staker = 3;
+        let origin_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        let target_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x02));
+
+        assert_register(origin_developer, &origin_contract_id);
+        assert_register(target_developer, &target_contract_id);
+
+        assert_noop!(
+            DappsStaking::nomination_transfer(
+                RuntimeOrigin::signed(staker),
+                origin_contract_id.clone(),
+                20,
+                target_contract_id.clone()
+            ),
+            Error::<TestRuntime>::NotStakedContract
+        );
+    })
+}
+
+#[test]
+fn nomination_transfer_with_no_value() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let origin_developer = 1;
+        let target_developer = 2;
+        let staker = 3;
+        let origin_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        let target_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x02));
+
+        assert_register(origin_developer, &origin_contract_id);
+        assert_register(target_developer, &target_contract_id);
+        assert_bond_and_stake(staker, &origin_contract_id, 100);
+
+        assert_noop!(
+            DappsStaking::nomination_transfer(
+                RuntimeOrigin::signed(staker),
+                origin_contract_id.clone(),
+                Zero::zero(),
+                target_contract_id.clone()
+            ),
+            Error::<TestRuntime>::UnstakingWithNoValue
+        );
+    })
+}
+
+#[test]
+fn nomination_transfer_with_insufficient_value() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let origin_developer = 1;
+        let target_developer = 2;
+        let staker = 3;
+        let origin_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        let target_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x02));
+
+        assert_register(origin_developer, &origin_contract_id);
+        assert_register(target_developer, &target_contract_id);
+        assert_bond_and_stake(staker, &origin_contract_id, 100);
+
+        assert_noop!(
+            DappsStaking::nomination_transfer(
+                RuntimeOrigin::signed(staker),
+                origin_contract_id.clone(),
+                MINIMUM_STAKING_AMOUNT - 1,
+                target_contract_id.clone()
+            ),
+            Error::<TestRuntime>::InsufficientValue
+        );
+    })
+}
+
+#[test]
+fn nomination_transfer_contracts_have_too_many_era_stake_values() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let origin_developer = 1;
+        let target_developer = 2;
+        let staker = 3;
+        let origin_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        let target_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x02));
+
+        assert_register(origin_developer, &origin_contract_id);
+        assert_register(target_developer, &target_contract_id);
+
+        // Ensure we fill up era stakes vector
+        for _ in 1..MAX_ERA_STAKE_VALUES {
+            // We use bond&stake since its only limiting factor is max era stake values
+            assert_bond_and_stake(staker, &origin_contract_id, 15);
+            advance_to_era(DappsStaking::current_era() + 1);
+        }
+        assert_noop!(
+            DappsStaking::bond_and_stake(
+                RuntimeOrigin::signed(staker),
+                origin_contract_id.clone(),
+                15
+            ),
+            Error::<TestRuntime>::TooManyEraStakeValues
+        );
+
+        // Ensure it's not possible to transfer from origin contract since it's era stake values are maxed
+        assert_noop!(
+            DappsStaking::nomination_transfer(
+                RuntimeOrigin::signed(staker),
+                origin_contract_id.clone(),
+                15,
+                target_contract_id.clone()
+            ),
+            Error::<TestRuntime>::TooManyEraStakeValues
+        );
+
+        // Swap origin and target to verify that same is true if target contract era stake values is maxed out
+        let (origin_contract_id, target_contract_id) = (target_contract_id, origin_contract_id);
+        assert_bond_and_stake(staker, &origin_contract_id, 15);
+        assert_noop!(
+            DappsStaking::nomination_transfer(
+                RuntimeOrigin::signed(staker),
+                origin_contract_id.clone(),
+                15,
+                target_contract_id.clone()
+            ),
+            Error::<TestRuntime>::TooManyEraStakeValues
+        );
+    })
+}
+
+#[test]
+fn nomination_transfer_max_number_of_stakers_exceeded() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let origin_developer = 1;
+        let target_developer = 2;
+        // This one will only stake on origin contract
+        let first_staker = 3;
+        // This one will stake on both origin and target contracts
+        let second_staker = 4;
+        let origin_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        let target_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x02));
+
+        // Register contracts and bond&stake them with both stakers
+        assert_register(origin_developer, &origin_contract_id);
+        assert_register(target_developer, &target_contract_id);
+
+        assert_bond_and_stake(first_staker, &origin_contract_id, 23);
+        assert_bond_and_stake(second_staker, &target_contract_id, 37);
+        assert_bond_and_stake(second_staker, &target_contract_id, 41);
+
+        // Fill up the second contract with stakers until max number of stakers limit has been reached
+        for temp_staker in (second_staker + 1)..(MAX_NUMBER_OF_STAKERS as u64 + second_staker) {
+            Balances::resolve_creating(&temp_staker, Balances::issue(100));
+            assert_bond_and_stake(temp_staker, &target_contract_id, 13);
+        }
+        // Sanity check + assurance that first_staker isn't staking on target contract
+        assert_noop!(
+            DappsStaking::bond_and_stake(
+                RuntimeOrigin::signed(first_staker),
+                target_contract_id.clone(),
+                19
+            ),
+            Error::<TestRuntime>::MaxNumberOfStakersExceeded
+        );
+
+        // Now attempt transfer nomination and expect an error
+        assert_noop!(
+            DappsStaking::nomination_transfer(
+                RuntimeOrigin::signed(first_staker),
+                origin_contract_id.clone(),
+                19,
+                target_contract_id.clone(),
+            ),
+            Error::<TestRuntime>::MaxNumberOfStakersExceeded
+        );
+    })
+}
+
+#[test]
+fn claim_not_staked_contract() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let staker = 2;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+        assert_register(developer, &contract_id);
+
+        assert_noop!(
+            DappsStaking::claim_staker(RuntimeOrigin::signed(staker), contract_id),
+            Error::<TestRuntime>::NotStakedContract
+        );
+
+        advance_to_era(DappsStaking::current_era() + 1);
+        assert_noop!(
+            DappsStaking::claim_dapp(RuntimeOrigin::signed(developer), contract_id, 1),
+            Error::<TestRuntime>::NotStakedContract
+        );
+    })
+}
+
+#[test]
+fn claim_not_operated_contract() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let staker = 2;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+        assert_register(developer, &contract_id);
+        assert_bond_and_stake(staker, &contract_id, 100);
+
+        // Advance one era and unregister the contract
+        advance_to_era(DappsStaking::current_era() + 1);
+        assert_unregister(developer, &contract_id);
+
+        // First claim should pass but second should fail because contract was unregistered
+        assert_claim_staker(staker, &contract_id);
+        assert_noop!(
+            DappsStaking::claim_staker(RuntimeOrigin::signed(staker), contract_id),
+            Error::<TestRuntime>::NotOperatedContract
+        );
+
+        assert_claim_dapp(&contract_id, 1);
+        assert_noop!(
+            DappsStaking::claim_dapp(RuntimeOrigin::signed(developer), contract_id, 2),
+            Error::<TestRuntime>::NotOperatedContract
+        );
+    })
+}
+
+#[test]
+fn claim_invalid_era() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let staker = 2;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+        let start_era = DappsStaking::current_era();
+        assert_register(developer, &contract_id);
+        assert_bond_and_stake(staker, &contract_id, 100);
+        advance_to_era(start_era + 5);
+
+        for era in start_era..DappsStaking::current_era() {
+            assert_claim_staker(staker, &contract_id);
+            assert_claim_dapp(&contract_id, era);
+        }
+
+        assert_noop!(
+            DappsStaking::claim_staker(RuntimeOrigin::signed(staker), contract_id),
+            Error::<TestRuntime>::EraOutOfBounds
+        );
+        assert_noop!(
+            DappsStaking::claim_dapp(
+                RuntimeOrigin::signed(developer),
+                contract_id,
+                DappsStaking::current_era()
+            ),
+            Error::<TestRuntime>::EraOutOfBounds
+        );
+    })
+}
+
+#[test]
+fn claim_dapp_same_era_twice() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let staker = 2;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+        let start_era = DappsStaking::current_era();
+        assert_register(developer, &contract_id);
+        assert_bond_and_stake(staker, &contract_id, 100);
+        advance_to_era(start_era + 1);
+
+        assert_claim_dapp(&contract_id, start_era);
+        assert_noop!(
+            DappsStaking::claim_dapp(RuntimeOrigin::signed(developer), contract_id, start_era),
+            Error::<TestRuntime>::AlreadyClaimedInThisEra
+        );
+    })
+}
+
+#[test]
+fn claim_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let first_developer = 1;
+        let second_developer = 2;
+        let first_staker = 3;
+        let second_staker = 4;
+        let first_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        let second_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x02));
+
+        let start_era = DappsStaking::current_era();
+
+        // Prepare a scenario with different stakes
+
+        assert_register(first_developer, &first_contract_id);
+        assert_register(second_developer, &second_contract_id);
+        assert_bond_and_stake(first_staker, &first_contract_id, 100);
+        assert_bond_and_stake(second_staker, &first_contract_id, 45);
+
+        // Just so ratio isn't 100% in favor of the first contract
+        assert_bond_and_stake(first_staker, &second_contract_id, 33);
+        assert_bond_and_stake(second_staker, &second_contract_id, 22);
+
+        let eras_advanced = 3;
+        advance_to_era(start_era + eras_advanced);
+
+        for x in 0..eras_advanced.into() {
+            assert_bond_and_stake(first_staker, &first_contract_id, 20 + x * 3);
+            assert_bond_and_stake(second_staker, &first_contract_id, 5 + x * 5);
+            advance_to_era(DappsStaking::current_era() + 1);
+        }
+
+        // Ensure that all past eras can be claimed
+        let current_era = DappsStaking::current_era();
+        for era in start_era..current_era {
+            assert_claim_staker(first_staker, &first_contract_id);
+            assert_claim_dapp(&first_contract_id, era);
+            assert_claim_staker(second_staker, &first_contract_id);
+        }
+
+        // Shouldn't be possible to claim current era.
+        // Also, previous claim calls should have claimed everything prior to current era.
+        assert_noop!(
+            DappsStaking::claim_staker(
+                RuntimeOrigin::signed(first_staker),
+                first_contract_id.clone()
+            ),
+            Error::<TestRuntime>::EraOutOfBounds
+        );
+        assert_noop!(
+            DappsStaking::claim_dapp(
+                RuntimeOrigin::signed(first_developer),
+                first_contract_id,
+                current_era
+            ),
+            Error::<TestRuntime>::EraOutOfBounds
+        );
+    })
+}
+
+#[test]
+fn claim_after_unregister_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let staker = 2;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+        let start_era = DappsStaking::current_era();
+        assert_register(developer, &contract_id);
+        let stake_value = 100;
+        assert_bond_and_stake(staker, &contract_id, stake_value);
+
+        // Advance few eras, then unstake everything
+        advance_to_era(start_era + 5);
+        assert_unbond_and_unstake(staker, &contract_id, stake_value);
+        let full_unstake_era = DappsStaking::current_era();
+        let number_of_staking_eras = full_unstake_era - start_era;
+
+        // Few eras pass, then staker stakes again
+        advance_to_era(DappsStaking::current_era() + 3);
+        let stake_value = 75;
+        let restake_era = DappsStaking::current_era();
+        assert_bond_and_stake(staker, &contract_id, stake_value);
+
+        // Again, few eras pass then contract is unregistered
+        advance_to_era(DappsStaking::current_era() + 3);
+        assert_unregister(developer, &contract_id);
+        let unregister_era = DappsStaking::current_era();
+        let number_of_staking_eras = number_of_staking_eras + unregister_era - restake_era;
+        advance_to_era(DappsStaking::current_era() + 2);
+
+        // Ensure that staker can claim all the eras that he had an active stake
+        for _ in 0..number_of_staking_eras {
+            assert_claim_staker(staker, &contract_id);
+        }
+        assert_noop!(
+            DappsStaking::claim_staker(RuntimeOrigin::signed(staker), contract_id.clone()),
+            Error::<TestRuntime>::NotOperatedContract
+        );
+
+        // Ensure the same for dapp reward
+        for era in start_era..unregister_era {
+            if era >= full_unstake_era && era < restake_era {
+                assert_noop!(
+                    DappsStaking::claim_dapp(
+                        RuntimeOrigin::signed(developer),
+                        contract_id.clone(),
+                        era
+                    ),
+                    Error::<TestRuntime>::NotStakedContract
+                );
+            } else {
+                assert_claim_dapp(&contract_id, era);
+            }
+        }
+    })
+}
+
+#[test]
+fn claim_only_payout_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let staker = 2;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+        // stake some tokens
+        let start_era = DappsStaking::current_era();
+        assert_register(developer, &contract_id);
+        let stake_value = 100;
+        assert_bond_and_stake(staker, &contract_id, stake_value);
+
+        // disable reward restaking
+        advance_to_era(start_era + 1);
+        assert_set_reward_destination(staker, RewardDestination::FreeBalance);
+
+        // ensure it's claimed correctly
+        assert_claim_staker(staker, &contract_id);
+    })
+}
+
+#[test]
+fn claim_with_zero_staked_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let staker = 2;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        let start_era = DappsStaking::current_era();
+        assert_register(developer, &contract_id);
+
+        // stake some tokens and wait for an era
+        let stake_value = 100;
+        assert_bond_and_stake(staker, &contract_id, stake_value);
+        advance_to_era(start_era + 1);
+
+        // ensure reward_destination is set to StakeBalance
+        assert_set_reward_destination(staker, RewardDestination::StakeBalance);
+
+        // unstake all the tokens
+        assert_unbond_and_unstake(staker, &contract_id, stake_value);
+
+        // ensure claimed value goes to claimer's free balance
+        assert_claim_staker(staker, &contract_id);
+    })
+}
+
+#[test]
+fn claims_with_different_reward_destination_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let staker = 2;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+        // stake some tokens
+        let start_era = DappsStaking::current_era();
+        assert_register(developer, &contract_id);
+        let stake_value = 100;
+        assert_bond_and_stake(staker, &contract_id, stake_value);
+
+        // disable compounding mode, wait 3 eras
+        assert_set_reward_destination(staker, RewardDestination::FreeBalance);
+        advance_to_era(start_era + 1);
+        // ensure staker can claim rewards to wallet
+        assert_claim_staker(staker, &contract_id);
+
+        // enable compounding mode, wait 3 eras
+        assert_set_reward_destination(staker, RewardDestination::StakeBalance);
+        advance_to_era(start_era + 2);
+        // ensure staker can claim with compounding
+        assert_claim_staker(staker, &contract_id);
+
+        // enable partial compounding mode
+        assert_set_reward_destination(staker, RewardDestination::Split(Perbill::from_percent(40)));
+        advance_to_era(start_era + 5);
+        // ensure staker can claim with partial compounding, both for a single and multiple eras
+        assert_claim_staker(staker, &contract_id);
+        assert_claim_staker_batch(staker, &contract_id, 2);
+
+        // split without any restaking behaves like free balance destination
+        assert_set_reward_destination(staker, RewardDestination::Split(Perbill::zero()));
+        advance_to_era(start_era + 6);
+        let ledger = DappsStaking::ledger(&staker);
+        assert_claim_staker(staker, &contract_id);
+        assert_eq!(ledger.locked, DappsStaking::ledger(&staker).locked);
+    })
+}
+
+#[test]
+fn claiming_when_stakes_full_without_compounding_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let staker_id = 1;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        // Insert a contract under registered contracts.
+        assert_register(10, &contract_id);
+
+        // Stake with MAX_ERA_STAKE_VALUES - 1 on the same contract. It must work.
+        let start_era = DappsStaking::current_era();
+        for offset in 1..MAX_ERA_STAKE_VALUES {
+            assert_bond_and_stake(staker_id, &contract_id, 100);
+            advance_to_era(start_era + offset * 5);
+        }
+
+        // Make sure reward_destination is set to StakeBalance
+        assert_set_reward_destination(staker_id, RewardDestination::StakeBalance);
+
+        // claim and restake once, so there's a claim record for the for the current era in the stakes vec
+        assert_claim_staker(staker_id, &contract_id);
+
+        // making another gap in eras and trying to claim and restake would exceed MAX_ERA_STAKE_VALUES
+        advance_to_era(DappsStaking::current_era() + 1);
+        assert_noop!(
+            DappsStaking::claim_staker(RuntimeOrigin::signed(staker_id), contract_id),
+            Error::<TestRuntime>::TooManyEraStakeValues
+        );
+
+        // set reward_destination to FreeBalance (disable restaking)
+        assert_set_reward_destination(staker_id, RewardDestination::FreeBalance);
+
+        // claiming should work again
+        assert_claim_staker(staker_id, &contract_id);
+    })
+}
+
+#[test]
+fn claim_staker_batch_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let first_staker = 2;
+        let second_staker = 3;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+        let start_era = DappsStaking::current_era();
+        assert_register(developer, &contract_id);
+        assert_bond_and_stake(first_staker, &contract_id, 100);
+        assert_bond_and_stake(second_staker, &contract_id, 50);
+
+        advance_to_era(start_era + 2);
+        assert_bond_and_stake(first_staker, &contract_id, 30);
+        advance_to_era(start_era + MAX_BATCH_CLAIM_ERAS + 3);
+
+        // Claim with restaking, followed by a claim without restaking
+        assert_claim_staker_batch(first_staker, &contract_id, 3);
+        assert_set_reward_destination(first_staker, RewardDestination::FreeBalance);
+        assert_claim_staker_batch(first_staker, &contract_id, MAX_BATCH_CLAIM_ERAS);
+
+        // Claiming should stop at the current era
+        assert_claim_staker_batch(second_staker, &contract_id, MAX_BATCH_CLAIM_ERAS);
+        assert_claim_staker_batch(second_staker, &contract_id, MAX_BATCH_CLAIM_ERAS);
+        assert_noop!(
+            DappsStaking::claim_staker_batch(
+                RuntimeOrigin::signed(second_staker),
+                contract_id,
+                MAX_BATCH_CLAIM_ERAS
+            ),
+            Error::<TestRuntime>::EraOutOfBounds
+        );
+    })
+}
+
+#[test]
+fn claim_staker_batch_equals_consecutive_claims() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let first_staker = 2;
+        let second_staker = 3;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+        let start_era = DappsStaking::current_era();
+        assert_register(developer, &contract_id);
+        assert_bond_and_stake(first_staker, &contract_id, 100);
+        assert_bond_and_stake(second_staker, &contract_id, 100);
+        advance_to_era(start_era + MAX_BATCH_CLAIM_ERAS);
+
+        let first_staker_init_ledger = DappsStaking::ledger(&first_staker);
+        let second_staker_init_ledger = DappsStaking::ledger(&second_staker);
+
+        // Both stakers have the same stake so their rewards must match
+        for _ in 0..MAX_BATCH_CLAIM_ERAS {
+            assert_claim_staker(first_staker, &contract_id);
+        }
+        assert_claim_staker_batch(second_staker, &contract_id, MAX_BATCH_CLAIM_ERAS);
+
+        assert_eq!(
+            DappsStaking::ledger(&first_staker).locked - first_staker_init_ledger.locked,
+            DappsStaking::ledger(&second_staker).locked - second_staker_init_ledger.locked,
+        );
+        assert_eq!(
+            DappsStaking::staker_info(&first_staker, &contract_id),
+            DappsStaking::staker_info(&second_staker, &contract_id),
+        );
+    })
+}
+
+#[test]
+fn claim_staker_batch_after_unregister_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let staker = 2;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+        let start_era = DappsStaking::current_era();
+        assert_register(developer, &contract_id);
+        assert_bond_and_stake(staker, &contract_id, 100);
+
+        advance_to_era(start_era + 2);
+        assert_unregister(developer, &contract_id);
+        advance_to_era(start_era + 4);
+
+        // Only eras before the unregistration era can be claimed
+        assert_claim_staker_batch(staker, &contract_id, MAX_BATCH_CLAIM_ERAS);
+        assert_noop!(
+            DappsStaking::claim_staker_batch(
+                RuntimeOrigin::signed(staker),
+                contract_id,
+                MAX_BATCH_CLAIM_ERAS
+            ),
+            Error::<TestRuntime>::NotOperatedContract
+        );
+        assert_withdraw_from_unregistered(staker, &contract_id);
+    })
+}
+
+#[test]
+fn claim_staker_batch_invalid_number_of_eras() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let staker = 2;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+        let start_era = DappsStaking::current_era();
+        assert_register(developer, &contract_id);
+        assert_bond_and_stake(staker, &contract_id, 100);
+        advance_to_era(start_era + 1);
+
+        assert_noop!(
+            DappsStaking::claim_staker_batch(RuntimeOrigin::signed(staker), contract_id, 0),
+            Error::<TestRuntime>::InvalidNumberOfEras
+        );
+        assert_noop!(
+            DappsStaking::claim_staker_batch(
+                RuntimeOrigin::signed(staker),
+                contract_id,
+                MAX_BATCH_CLAIM_ERAS + 1
+            ),
+            Error::<TestRuntime>::InvalidNumberOfEras
+        );
+    })
+}
+
+#[test]
+fn claim_staker_for_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let first_staker = 2;
+        let second_staker = 3;
+        let claimer = 4;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+        let start_era = DappsStaking::current_era();
+        assert_register(developer, &contract_id);
+        assert_bond_and_stake(first_staker, &contract_id, 100);
+        assert_bond_and_stake(second_staker, &contract_id, 50);
+        advance_to_era(start_era + 4);
+
+        // Reward destination of the staker must be respected
+        assert_set_reward_destination(second_staker, RewardDestination::FreeBalance);
+        assert_claim_staker_for(claimer, first_staker, &contract_id, 2);
+        assert_claim_staker_for(claimer, second_staker, &contract_id, 2);
+
+        // Staker can claim for itself via the same call
+        assert_claim_staker_for(first_staker, first_staker, &contract_id, 1);
+        assert_claim_staker_for(claimer, first_staker, &contract_id, MAX_BATCH_CLAIM_ERAS);
+        assert_noop!(
+            DappsStaking::claim_staker_for(
+                RuntimeOrigin::signed(claimer),
+                first_staker,
+                contract_id,
+                1
+            ),
+            Error::<TestRuntime>::EraOutOfBounds
+        );
+    })
+}
+
+#[test]
+fn claim_staker_for_is_not_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let staker = 2;
+        let claimer = 4;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+        let start_era = DappsStaking::current_era();
+        assert_register(developer, &contract_id);
+        assert_bond_and_stake(staker, &contract_id, 100);
+        advance_to_era(start_era + 1);
+
+        // Claimer has nothing staked on the contract
+        assert_noop!(
+            DappsStaking::claim_staker_for(RuntimeOrigin::signed(staker), claimer, contract_id, 1),
+            Error::<TestRuntime>::NotStakedContract
+        );
+        assert_noop!(
+            DappsStaking::claim_staker_for(RuntimeOrigin::signed(claimer), staker, contract_id, 0),
+            Error::<TestRuntime>::InvalidNumberOfEras
+        );
+        assert_noop!(
+            DappsStaking::claim_staker_for(
+                RuntimeOrigin::signed(claimer),
+                staker,
+                contract_id,
+                MAX_BATCH_CLAIM_ERAS + 1
+            ),
+            Error::<TestRuntime>::InvalidNumberOfEras
+        );
+        assert_noop!(
+            DappsStaking::claim_staker_for(RuntimeOrigin::none(), staker, contract_id, 1),
+            BadOrigin
+        );
+    })
+}
+
+#[test]
+fn staker_claimable_rewards_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let staker = 2;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        assert!(DappsStaking::staker_claimable_rewards(&staker, &contract_id).is_empty());
+
+        let start_era = DappsStaking::current_era();
+        assert_register(developer, &contract_id);
+        assert_bond_and_stake(staker, &contract_id, 100);
+        assert_bond_and_stake(3, &contract_id, 50);
+        assert!(DappsStaking::staker_claimable_rewards(&staker, &contract_id).is_empty());
+
+        advance_to_era(start_era + 2);
+        assert_bond_and_stake(staker, &contract_id, 30);
+        advance_to_era(start_era + 4);
+
+        let claimable_rewards = DappsStaking::staker_claimable_rewards(&staker, &contract_id);
+        assert_eq!(
+            claimable_rewards
+                .iter()
+                .map(|(era, _)| *era)
+                .collect::<Vec<_>>(),
+            (start_era..start_era + 4).collect::<Vec<_>>()
+        );
+
+        // Claimed rewards must match the claimable ones
+        for (era, reward) in claimable_rewards.clone() {
+            assert_claim_staker(staker, &contract_id);
+            System::assert_last_event(mock::RuntimeEvent::DappsStaking(Event::Reward(
+                staker,
+                contract_id,
+                era,
+                reward,
+            )));
+        }
+        assert!(DappsStaking::staker_claimable_rewards(&staker, &contract_id).is_empty());
+
+        // Eras after unregistration aren't claimable
+        advance_to_era(start_era + 6);
+        assert_unregister(developer, &contract_id);
+        advance_to_era(start_era + 8);
+        assert_eq!(
+            DappsStaking::staker_claimable_rewards(&staker, &contract_id)
+                .iter()
+                .map(|(era, _)| *era)
+                .collect::<Vec<_>>(),
+            vec![start_era + 4, start_era + 5]
+        );
+    })
+}
+
+#[test]
+fn dapp_claimable_rewards_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        assert_register(developer, &contract_id);
+        assert!(DappsStaking::dapp_claimable_rewards(&contract_id).is_empty());
+
+        // Contract isn't staked in the first era so there's nothing to claim
+        advance_to_era(DappsStaking::current_era() + 1);
+        let start_era = DappsStaking::current_era();
+        assert_bond_and_stake(2, &contract_id, 100);
+        advance_to_era(start_era + 2);
+        assert_bond_and_stake(3, &contract_id, 50);
+        advance_to_era(start_era + 4);
+
+        let claimable_rewards = DappsStaking::dapp_claimable_rewards(&contract_id);
+        assert_eq!(
+            claimable_rewards
+                .iter()
+                .map(|(era, _)| *era)
+                .collect::<Vec<_>>(),
+            (start_era..start_era + 4).collect::<Vec<_>>()
+        );
+
+        // Already claimed eras aren't included
+        assert_claim_dapp(&contract_id, start_era + 1);
+        assert_eq!(
+            DappsStaking::dapp_claimable_rewards(&contract_id),
+            claimable_rewards
+                .iter()
+                .filter(|(era, _)| *era != start_era + 1)
+                .cloned()
+                .collect::<Vec<_>>()
+        );
+
+        // Claimed rewards must match the claimable ones
+        for (era, reward) in DappsStaking::dapp_claimable_rewards(&contract_id) {
+            assert_claim_dapp(&contract_id, era);
+            System::assert_last_event(mock::RuntimeEvent::DappsStaking(Event::Reward(
+                developer,
+                contract_id,
+                era,
+                reward,
+            )));
+        }
+        assert!(DappsStaking::dapp_claimable_rewards(&contract_id).is_empty());
+
+        // Eras after unregistration aren't claimable
+        advance_to_era(start_era + 5);
+        assert_unregister(developer, &contract_id);
+        advance_to_era(start_era + 7);
+        assert_eq!(
+            DappsStaking::dapp_claimable_rewards(&contract_id)
+                .iter()
+                .map(|(era, _)| *era)
+                .collect::<Vec<_>>(),
+            vec![start_era + 4]
+        );
+    })
+}
+
+#[test]
+fn unlocking_chunks_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let staker = 1;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        assert_register(10, &contract_id);
+        assert_bond_and_stake(staker, &contract_id, 1000);
+        assert!(DappsStaking::unlocking_chunks(&staker).is_empty());
+
+        let first_era = DappsStaking::current_era();
+        assert_unbond_and_unstake(staker, &contract_id, 100);
+        assert_unbond_and_unstake(staker, &contract_id, 50);
+        advance_to_era(first_era + 1);
+        assert_unbond_and_unstake(staker, &contract_id, 200);
+
+        assert_eq!(
+            DappsStaking::unlocking_chunks(&staker),
+            vec![
+                (first_era + UNBONDING_PERIOD, 150),
+                (first_era + 1 + UNBONDING_PERIOD, 200)
+            ]
+        );
+
+        advance_to_era(first_era + UNBONDING_PERIOD);
+        assert_withdraw_unbonded(staker);
+        assert_eq!(
+            DappsStaking::unlocking_chunks(&staker),
+            vec![(first_era + 1 + UNBONDING_PERIOD, 200)]
+        );
+    })
+}
+
+#[test]
+fn changing_reward_destination_for_empty_ledger_is_not_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+        let staker = 1;
+        assert_noop!(
+            DappsStaking::set_reward_destination(
+                RuntimeOrigin::signed(staker),
+                RewardDestination::FreeBalance
+            ),
+            Error::<TestRuntime>::NotActiveStaker
+        );
+    });
+}
+
+#[test]
+fn claim_dapp_with_zero_stake_periods_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let staker = 2;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+        // Prepare scenario: <staked eras><not staked eras><staked eras><not staked eras>
+
+        let start_era = DappsStaking::current_era();
+        assert_register(developer, &contract_id);
+        let stake_value = 100;
+        assert_bond_and_stake(staker, &contract_id, stake_value);
+
+        advance_to_era(start_era + 5);
+        let first_full_unstake_era = DappsStaking::current_era();
+        assert_unbond_and_unstake(staker, &contract_id, stake_value);
+
+        advance_to_era(DappsStaking::current_era() + 7);
+        let restake_era = DappsStaking::current_era();
+        assert_bond_and_stake(staker, &contract_id, stake_value);
+
+        advance_to_era(DappsStaking::current_era() + 4);
+        let second_full_unstake_era = DappsStaking::current_era();
+        assert_unbond_and_unstake(staker, &contract_id, stake_value);
+        advance_to_era(DappsStaking::current_era() + 10);
+
+        // Ensure that first interval can be claimed
+        for era in start_era..first_full_unstake_era {
+            assert_claim_dapp(&contract_id, era);
+        }
+
+        // Ensure that the empty interval cannot be claimed
+        for era in first_full_unstake_era..restake_era {
+            assert_noop!(
+                DappsStaking::claim_dapp(
+                    RuntimeOrigin::signed(developer),
+                    contract_id.clone(),
+                    era
+                ),
+                Error::<TestRuntime>::NotStakedContract
+            );
+        }
+
+        // Ensure that second interval can be claimed
+        for era in restake_era..second_full_unstake_era {
+            assert_claim_dapp(&contract_id, era);
+        }
+
+        // Ensure no more claims are possible since contract was fully unstaked
+        assert_noop!(
+            DappsStaking::claim_dapp(
+                RuntimeOrigin::signed(developer),
+                contract_id.clone(),
+                second_full_unstake_era
+            ),
+            Error::<TestRuntime>::NotStakedContract
+        );
+
+        // Now stake again and ensure contract can once again be claimed
+        let last_claim_era = DappsStaking::current_era();
+        assert_bond_and_stake(staker, &contract_id, stake_value);
+        advance_to_era(last_claim_era + 1);
+        assert_claim_dapp(&contract_id, last_claim_era);
+    })
+}
+
+#[test]
+fn maintenance_mode_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        assert_ok!(DappsStaking::ensure_pallet_enabled());
+        assert!(!PalletDisabled::<TestRuntime>::exists());
+
+        assert_ok!(DappsStaking::maintenance_mode(RuntimeOrigin::root(), true));
+        assert!(PalletDisabled::<TestRuntime>::exists());
+        System::assert_last_event(mock::RuntimeEvent::DappsStaking(Event::MaintenanceMode(
+            true,
+        )));
+
+        let account = 1;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+        //
+        // 1
+        assert_noop!(
+            DappsStaking::register(RuntimeOrigin::root(), account, contract_id),
+            Error::<TestRuntime>::Disabled
+        );
+        assert_noop!(
+            DappsStaking::unregister(RuntimeOrigin::root(), contract_id),
+            Error::<TestRuntime>::Disabled
+        );
+        assert_noop!(
+            DappsStaking::self_register(RuntimeOrigin::signed(account), contract_id),
+            Error::<TestRuntime>::Disabled
+        );
+        assert_noop!(
+            DappsStaking::self_unregister(RuntimeOrigin::signed(account), contract_id),
+            Error::<TestRuntime>::Disabled
+        );
+        assert_noop!(
+            DappsStaking::slash_dapp(
+                RuntimeOrigin::root(),
+                contract_id,
+                1,
+                1,
+                Perbill::one(),
+                true,
+                true
+            ),
+            Error::<TestRuntime>::Disabled
+        );
+        assert_noop!(
+            DappsStaking::reregister(RuntimeOrigin::root(), contract_id),
+            Error::<TestRuntime>::Disabled
+        );
+        assert_noop!(
+            DappsStaking::propose_ownership_transfer(
+                RuntimeOrigin::signed(account),
+                contract_id,
+                account
+            ),
+            Error::<TestRuntime>::Disabled
+        );
+        assert_noop!(
+            DappsStaking::accept_ownership_transfer(RuntimeOrigin::signed(account), contract_id),
+            Error::<TestRuntime>::Disabled
+        );
+        assert_noop!(
+            DappsStaking::set_dapp_metadata(
+                RuntimeOrigin::signed(account),
+                contract_id,
+                Default::default()
+            ),
+            Error::<TestRuntime>::Disabled
+        );
+        assert_noop!(
+            DappsStaking::clear_dapp_metadata(RuntimeOrigin::root(), contract_id),
+            Error::<TestRuntime>::Disabled
+        );
+        assert_noop!(
+            DappsStaking::batch_nomination_transfer(
+                RuntimeOrigin::signed(account),
+                BoundedVec::truncate_from(vec![(contract_id, contract_id, 100)])
+            ),
+            Error::<TestRuntime>::Disabled
+        );
+        assert_noop!(
+            DappsStaking::set_operator(RuntimeOrigin::signed(account), 2),
+            Error::<TestRuntime>::Disabled
+        );
+        assert_noop!(
+            DappsStaking::remove_operator(RuntimeOrigin::signed(account)),
+            Error::<TestRuntime>::Disabled
+        );
+        assert_noop!(
+            DappsStaking::operator_nomination_transfer(
+                RuntimeOrigin::signed(account),
+                2,
+                contract_id,
+                100,
+                contract_id,
+            ),
+            Error::<TestRuntime>::Disabled
+        );
+        assert_noop!(
+            DappsStaking::operator_set_reward_destination(
+                RuntimeOrigin::signed(account),
+                2,
+                RewardDestination::FreeBalance
+            ),
+            Error::<TestRuntime>::Disabled
+        );
+        assert_noop!(
+            DappsStaking::withdraw_from_unregistered(RuntimeOrigin::signed(account), contract_id),
+            Error::<TestRuntime>::Disabled
+        );
+
+        //
+        // 2
+        assert_noop!(
+            DappsStaking::bond_and_stake(RuntimeOrigin::signed(account), contract_id, 100),
+            Error::<TestRuntime>::Disabled
+        );
+        assert_noop!(
+            DappsStaking::unbond_and_unstake(RuntimeOrigin::signed(account), contract_id, 100),
+            Error::<TestRuntime>::Disabled
+        );
+        assert_noop!(
+            DappsStaking::claim_dapp(RuntimeOrigin::signed(account), contract_id, 5),
+            Error::<TestRuntime>::Disabled
+        );
+        assert_noop!(
+            DappsStaking::claim_staker(RuntimeOrigin::signed(account), contract_id),
+            Error::<TestRuntime>::Disabled
+        );
+        assert_noop!(
+            DappsStaking::claim_staker_batch(RuntimeOrigin::signed(account), contract_id, 2),
+            Error::<TestRuntime>::Disabled
+        );
+        assert_noop!(
+            DappsStaking::claim_staker_for(RuntimeOrigin::signed(account), account, contract_id, 2),
+            Error::<TestRuntime>::Disabled
+        );
+        assert_noop!(
+            DappsStaking::withdraw_unbonded(RuntimeOrigin::signed(account)),
+            Error::<TestRuntime>::Disabled
+        );
+        assert_noop!(
+            DappsStaking::rebond(RuntimeOrigin::signed(account), contract_id, 100),
+            Error::<TestRuntime>::Disabled
+        );
+        assert_noop!(
+            DappsStaking::set_dapp_commission(
+                RuntimeOrigin::signed(account),
+                contract_id,
+                Perbill::from_percent(10)
+            ),
+            Error::<TestRuntime>::Disabled
+        );
+        assert_noop!(
+            DappsStaking::set_staking_parameters(
+                RuntimeOrigin::root(),
+                DappsStaking::staking_parameters()
+            ),
+            Error::<TestRuntime>::Disabled
+        );
+        assert_noop!(
+            DappsStaking::instant_unbond_and_unstake(
+                RuntimeOrigin::signed(account),
+                contract_id,
+                100
+            ),
+            Error::<TestRuntime>::Disabled
+        );
+        assert_noop!(
+            DappsStaking::instant_withdraw_unbonded(RuntimeOrigin::signed(account)),
+            Error::<TestRuntime>::Disabled
+        );
+        assert_noop!(
+            DappsStaking::bond_and_stake_with_lock(
+                RuntimeOrigin::signed(account),
+                contract_id,
+                100,
+                SHORT_STAKE_LOCK.0
+            ),
+            Error::<TestRuntime>::Disabled
+        );
+        assert_noop!(
+            DappsStaking::nomination_transfer(
+                RuntimeOrigin::signed(account),
+                contract_id,
+                100,
+                contract_id,
+            ),
+            Error::<TestRuntime>::Disabled
+        );
+
+        //
+        // 3
+        assert_noop!(
+            DappsStaking::force_new_era(RuntimeOrigin::root()),
+            Error::<TestRuntime>::Disabled
+        );
+        // shouldn't do anything since we're in maintenance mode
+        assert_eq!(DappsStaking::on_initialize(3), Weight::zero());
+
+        //
+        // 4
+        assert_ok!(DappsStaking::maintenance_mode(RuntimeOrigin::root(), false));
+        System::assert_last_event(mock::RuntimeEvent::DappsStaking(Event::MaintenanceMode(
+            false,
+        )));
+        assert_register(account, &contract_id);
+    })
+}
+
+#[test]
+fn maintenance_mode_no_change() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        // Expect an error since maintenance mode is already disabled
+        assert_ok!(DappsStaking::ensure_pallet_enabled());
+        assert_noop!(
+            DappsStaking::maintenance_mode(RuntimeOrigin::root(), false),
+            Error::<TestRuntime>::NoMaintenanceModeChange
+        );
+
+        // Same for the case when maintenance mode is already enabled
+        assert_ok!(DappsStaking::maintenance_mode(RuntimeOrigin::root(), true));
+        assert_noop!(
+            DappsStaking::maintenance_mode(RuntimeOrigin::root(), true),
+            Error::<TestRuntime>::NoMaintenanceModeChange
+        );
+    })
+}
+
+#[test]
+fn dev_stakers_split_util() {
+    let base_stakers_reward = 7 * 11 * 13 * 17;
+    let base_dapps_reward = 19 * 23 * 31;
+    let staked_on_contract = 123456;
+    let total_staked = staked_on_contract * 3;
+
+    // Prepare structs
+    let staking_points = ContractStakeInfo::<Balance> {
+        total: staked_on_contract,
+        number_of_stakers: 10,
+        contract_reward_claimed: false,
+        boost: 0,
+    };
+    let era_info = EraInfo::<Balance> {
+        rewards: RewardInfo {
+            dapps: base_dapps_reward,
+            stakers: base_stakers_reward,
+        },
+        staked: total_staked,
+        locked: total_staked,
+        boost: 0,
+    };
+
+    let (dev_reward, stakers_reward) = DappsStaking::dev_stakers_split(&staking_points, &era_info);
+
+    let contract_stake_ratio = Perbill::from_rational(staked_on_contract, total_staked);
+    let calculated_stakers_reward = contract_stake_ratio * base_stakers_reward;
+    let calculated_dev_reward = contract_stake_ratio * base_dapps_reward;
+    assert_eq!(calculated_dev_reward, dev_reward);
+    assert_eq!(calculated_stakers_reward, stakers_reward);
+
+    assert_eq!(
+        calculated_stakers_reward + calculated_dev_reward,
+        dev_reward + stakers_reward
+    );
+
+    // Boost of the time-locked stakes only affects the stakers reward
+    let boosted_staking_points = ContractStakeInfo::<Balance> {
+        boost: staked_on_contract,
+        ..staking_points
+    };
+    let boosted_era_info = EraInfo::<Balance> {
+        boost: staked_on_contract,
+        ..era_info
+    };
+
+    let (dev_reward, stakers_reward) =
+        DappsStaking::dev_stakers_split(&boosted_staking_points, &boosted_era_info);
+
+    assert_eq!(calculated_dev_reward, dev_reward);
+    assert_eq!(
+        Perbill::from_percent(50) * base_stakers_reward,
+        stakers_reward
+    );
+}
+
+#[test]
+fn commission_split_util() {
+    let developer_reward = 19 * 23 * 31;
+
+    let (dev_reward, shared_reward) =
+        DappsStaking::commission_split(developer_reward, Perbill::zero());
+    assert_eq!(dev_reward, developer_reward);
+    assert!(shared_reward.is_zero());
+
+    let commission = Perbill::from_percent(35);
+    let (dev_reward, shared_reward) = DappsStaking::commission_split(developer_reward, commission);
+    assert_eq!(shared_reward, commission * developer_reward);
+    assert_eq!(dev_reward + shared_reward, developer_reward);
+
+    let (dev_reward, shared_reward) =
+        DappsStaking::commission_split(developer_reward, Perbill::one());
+    assert!(dev_reward.is_zero());
+    assert_eq!(shared_reward, developer_reward);
+}
+
+#[test]
+pub fn tvl_util_test() {
+    ExternalityBuilder::build().execute_with(|| {
+        // Ensure TVL is zero before first block and also after first block
+        assert!(DappsStaking::tvl().is_zero());
+        initialize_first_block();
+        assert!(DappsStaking::tvl().is_zero());
+
+        let developer = 1;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        assert_register(developer, &contract_id);
+
+        // Expect TVL to change as we bond&stake more
+        let iterations = 10;
+        let stake_value = 100;
+        for x in 1..=iterations {
+            assert_bond_and_stake(developer, &contract_id, stake_value);
+            assert_eq!(DappsStaking::tvl(), stake_value * x);
+        }
+
+        // Era advancement should have no effect on TVL
+        advance_to_era(5);
+        assert_eq!(DappsStaking::tvl(), stake_value * iterations);
+    })
+}
+
+#[test]
+pub fn set_contract_stake_info() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        assert_register(10, &contract_id);
+
+        let staker_id = 1;
+        assert_bond_and_stake(staker_id, &contract_id, 1000);
+
+        // Read current contract stake info, then overwrite it with different value
+        let original_contract_stake_info =
+            DappsStaking::contract_stake_info(&contract_id, 1).unwrap();
+        let mut modified_info = original_contract_stake_info.clone();
+        modified_info.total = modified_info.total + 17;
+        ContractEraStake::<TestRuntime>::insert(&contract_id, 1, modified_info);
+
+        // Ensure only root can call it
+        assert_noop!(
+            DappsStaking::set_contract_stake_info(
+                RuntimeOrigin::signed(1),
+                contract_id.clone(),
+                1,
+                original_contract_stake_info.clone()
+            ),
+            BadOrigin
+        );
+
+        // Verify we can fix the corrupted stroage
+        assert_ne!(
+            ContractEraStake::<TestRuntime>::get(&contract_id, 1).unwrap(),
+            original_contract_stake_info
+        );
+        assert_ok!(DappsStaking::set_contract_stake_info(
+            RuntimeOrigin::root(),
+            contract_id.clone(),
+            1,
+            original_contract_stake_info.clone()
+        ));
+        assert_eq!(
+            ContractEraStake::<TestRuntime>::get(&contract_id, 1).unwrap(),
+            original_contract_stake_info
+        );
+    })
+}
+
+#[test]
+fn custom_max_encoded_len() {
+    let max_unbonding_info_len = MAX_UNLOCKING_CHUNKS * (4 + 16) + 1;
+    assert_eq!(
+        UnbondingInfo::<u128, MaxUnlockingChunks>::max_encoded_len(),
+        max_unbonding_info_len as usize
+    );
+
+    let max_staker_info_len = MAX_ERA_STAKE_VALUES * (4 + 16) + 1;
+    assert_eq!(
+        StakerInfo::<u128, MaxEraStakeValues>::max_encoded_len(),
+        max_staker_info_len as usize
+    );
+}
+
+#[test]
+fn burn_stale_reward_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let staker = 3;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+        let start_era = DappsStaking::current_era();
+
+        // Register & stake on contract
+        assert_register(developer, &contract_id);
+        assert_bond_and_stake(staker, &contract_id, 100);
+
+        // Advance enough eras so stale rewards become burnable
+        let eras_advanced = REWARD_RETENTION_PERIOD + 1;
+        advance_to_era(start_era + eras_advanced);
+        assert_unregister(developer, &contract_id);
+
+        assert_burn_stale_reward(&contract_id, start_era);
+    })
+}
+
+#[test]
+fn burn_stale_reward_from_registered_dapp_fails() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let staker = 3;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+        let start_era = DappsStaking::current_era();
+
+        // Register & stake on contract
+        assert_register(developer, &contract_id);
+        assert_bond_and_stake(staker, &contract_id, 100);
+
+        // Advance enough eras so stale rewards would become burnable, in case dapp was unregistered
+        let eras_advanced = REWARD_RETENTION_PERIOD;
+        advance_to_era(start_era + eras_advanced);
+
+        // Rewards shouldn't be burnable since retention period hasn't expired yet
+        assert_noop!(
+            DappsStaking::burn_stale_reward(RuntimeOrigin::root(), contract_id, start_era,),
+            Error::<TestRuntime>::NotUnregisteredContract
+        );
+    })
+}
+
+#[test]
+fn burn_stale_reward_before_retention_period_finished_fails() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let staker = 3;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+        let start_era = DappsStaking::current_era();
+
+        // Register & stake on contract
+        assert_register(developer, &contract_id);
+        assert_bond_and_stake(staker, &contract_id, 100);
+
+        // Advance enough eras so stale rewards become burnable
+        let eras_advanced = REWARD_RETENTION_PERIOD;
+        advance_to_era(start_era + eras_advanced);
+        assert_unregister(developer, &contract_id);
+
+        // Rewards shouldn't be burnable since retention period hasn't expired yet
+        assert_noop!(
+            DappsStaking::burn_stale_reward(RuntimeOrigin::root(), contract_id, start_era,),
+            Error::<TestRuntime>::EraOutOfBounds
+        );
+    })
+}
+
+#[test]
+fn burn_stale_reward_negative_checks() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let staker = 3;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+        // Cannot burn from non-existing contract
+        assert_noop!(
+            DappsStaking::burn_stale_reward(RuntimeOrigin::root(), contract_id, 1,),
+            Error::<TestRuntime>::NotOperatedContract
+        );
+
+        // Cannot burn unless called with root privileges
+        assert_noop!(
+            DappsStaking::burn_stale_reward(RuntimeOrigin::signed(developer), contract_id, 1,),
+            BadOrigin
+        );
+
+        // Register & stake on contract
+        assert_register(developer, &contract_id);
+        assert_bond_and_stake(staker, &contract_id, 100);
+
+        // Advance enough eras so stale rewards become burnable
+        let start_era = DappsStaking::current_era();
+        let eras_advanced = REWARD_RETENTION_PERIOD + 2;
+        advance_to_era(start_era + eras_advanced);
+        assert_unregister(developer, &contract_id);
+
+        // Claim them (before they are burned)
+        assert_claim_dapp(&contract_id, start_era);
+
+        // No longer possible to burn if reward was claimed
+        assert_noop!(
+            DappsStaking::burn_stale_reward(RuntimeOrigin::root(), contract_id, start_era,),
+            Error::<TestRuntime>::AlreadyClaimedInThisEra
+        );
+    })
+}
+
+#[test]
+fn prune_era_history_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 10;
+        let staker = 1;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
         let start_era = DappsStaking::current_era();
+
         assert_register(developer, &contract_id);
-        let stake_value = 100;
-        assert_bond_and_stake(staker, &contract_id, stake_value);
+        assert_bond_and_stake(staker, &contract_id, 100);
+        advance_to_era(start_era + 1);
+        assert_bond_and_stake(staker, &contract_id, 50);
 
-        // disable compounding mode, wait 3 eras
-        assert_set_reward_destination(staker, RewardDestination::FreeBalance);
+        // Claim all rewards
+        advance_to_era(start_era + 5);
+        for era in start_era..start_era + 5 {
+            assert_claim_staker(staker, &contract_id);
+            assert_claim_dapp(&contract_id, era);
+        }
+
+        // Eras within history depth aren't pruned
+        let prune_before = DappsStaking::current_era() - ERA_HISTORY_DEPTH;
+        assert_prune_era_history(prune_before);
+
+        // Latest stake change before the pruned era is kept
+        let mut stake_eras: Vec<_> =
+            ContractEraStake::<TestRuntime>::iter_key_prefix(&contract_id).collect();
+        stake_eras.sort();
+        assert_eq!(
+            stake_eras,
+            vec![start_era + 1, start_era + 3, start_era + 4]
+        );
+
+        // Rewards of the pruned eras cannot be claimed again
+        assert_noop!(
+            DappsStaking::claim_dapp(
+                RuntimeOrigin::signed(developer),
+                contract_id,
+                prune_before - 1
+            ),
+            Error::<TestRuntime>::UnknownEraReward
+        );
+
+        // New pass isn't started until the next era
+        let pruning_info = DappsStaking::era_pruning();
+        DappsStaking::on_idle(System::block_number(), Weight::MAX);
+        assert_eq!(DappsStaking::era_pruning(), pruning_info);
+
+        // Staking continues to work normally
+        assert_bond_and_stake(staker, &contract_id, 50);
+        advance_to_era(DappsStaking::current_era() + 1);
+        assert_claim_staker(staker, &contract_id);
+        assert_claim_dapp(&contract_id, DappsStaking::current_era() - 1);
+    })
+}
+
+#[test]
+fn prune_era_history_waits_for_unclaimed_rewards() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 10;
+        let staker = 1;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        let start_era = DappsStaking::current_era();
+
+        assert_register(developer, &contract_id);
+        assert_bond_and_stake(staker, &contract_id, 100);
+
+        // dApp reward of the second era is left unclaimed
+        advance_to_era(start_era + 5);
+        for era in start_era..start_era + 5 {
+            if era != start_era + 1 {
+                assert_claim_dapp(&contract_id, era);
+            }
+        }
+
+        // Unclaimed staker rewards prevent pruning
+        assert_prune_era_history(start_era);
+        assert!(DappsStaking::general_era_info(start_era).is_some());
+
+        // Unclaimed dApp reward prevents pruning
+        for _ in start_era..start_era + 5 {
+            assert_claim_staker(staker, &contract_id);
+        }
+        advance_to_era(start_era + 6);
+        assert_prune_era_history(start_era + 1);
+
+        // Everything outside of history depth is pruned once all rewards are claimed
+        assert_claim_dapp(&contract_id, start_era + 1);
+        advance_to_era(start_era + 7);
+        assert_prune_era_history(start_era + 5);
+    })
+}
+
+#[test]
+fn prune_era_history_with_unregistered_dapp_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 10;
+        let staker = 1;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        let start_era = DappsStaking::current_era();
+
+        assert_register(developer, &contract_id);
+        assert_bond_and_stake(staker, &contract_id, 100);
+
+        advance_to_era(start_era + 2);
+        assert_unregister(developer, &contract_id);
+        for era in start_era..start_era + 2 {
+            assert_claim_staker(staker, &contract_id);
+            assert_claim_dapp(&contract_id, era);
+        }
+
+        // Staker hasn't withdrawn the stake, but there are no more claimable rewards
+        advance_to_era(start_era + 7);
+        assert_prune_era_history(start_era + 7 - ERA_HISTORY_DEPTH);
+    })
+}
+
+#[test]
+fn claim_expired_staker_reward_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 10;
+        let staker = 1;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        let start_era = DappsStaking::current_era();
+
+        assert_register(developer, &contract_id);
+        assert_bond_and_stake(staker, &contract_id, 100);
+
+        // Rewards of the first two eras expire
+        advance_to_era(start_era + STAKER_CLAIM_WINDOW + 2);
+        let claimable_rewards = DappsStaking::staker_claimable_rewards(&staker, &contract_id);
+        assert_eq!(claimable_rewards.len() as u32, STAKER_CLAIM_WINDOW);
+        assert_eq!(claimable_rewards[0].0, start_era + 2);
+
+        assert_claim_expired_staker(staker, &contract_id);
+        assert_claim_expired_staker(staker, &contract_id);
+        assert_claim_staker(staker, &contract_id);
+
+        // dApp rewards don't expire
+        assert_claim_dapp(&contract_id, start_era);
+    })
+}
+
+#[test]
+fn claim_staker_sweeps_expired_rewards_without_claimable_eras() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 10;
+        let staker = 1;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        let start_era = DappsStaking::current_era();
+
+        assert_register(developer, &contract_id);
+        assert_bond_and_stake(staker, &contract_id, 100);
         advance_to_era(start_era + 1);
-        // ensure staker can claim rewards to wallet
+        assert_unbond_and_unstake(staker, &contract_id, 100);
+
+        // Reward of the only staked era expires, nothing remains to be claimed
+        advance_to_era(start_era + STAKER_CLAIM_WINDOW + 1);
+        assert!(DappsStaking::staker_claimable_rewards(&staker, &contract_id).is_empty());
+
+        // Expired reward is swept, without any reward paid out to the staker
+        let free_balance = Balances::free_balance(&staker);
+        System::reset_events();
+        assert_ok!(DappsStaking::claim_staker_batch(
+            RuntimeOrigin::signed(staker),
+            contract_id.clone(),
+            MAX_BATCH_CLAIM_ERAS
+        ));
+        let events = dapps_staking_events();
+        assert!(matches!(
+            events.as_slice(),
+            [Event::StakerRewardExpired(account, contract, era, _)]
+                if *account == staker && *contract == contract_id && *era == start_era
+        ));
+        assert_eq!(Balances::free_balance(&staker), free_balance);
+
+        // Once swept, there's nothing left to claim
+        assert_noop!(
+            DappsStaking::claim_staker(RuntimeOrigin::signed(staker), contract_id),
+            Error::<TestRuntime>::NotStakedContract
+        );
+    })
+}
+
+#[test]
+fn prune_era_history_sweeps_expired_staker_rewards() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 10;
+        let staker = 1;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        let start_era = DappsStaking::current_era();
+
+        assert_register(developer, &contract_id);
+        assert_bond_and_stake(staker, &contract_id, 100);
+
+        // More eras expire than can be swept in a single step
+        advance_to_era(start_era + STAKER_CLAIM_WINDOW + MAX_BATCH_CLAIM_ERAS + 2);
+        let first_claimable_era = DappsStaking::current_era() - STAKER_CLAIM_WINDOW;
+        let init_treasury_balance = Balances::free_balance(&TREASURY_ACCOUNT);
+        System::reset_events();
+
+        DappsStaking::on_idle(System::block_number(), Weight::MAX);
+
+        let expired_rewards: Vec<_> = dapps_staking_events()
+            .into_iter()
+            .filter_map(|event| match event {
+                Event::StakerRewardExpired(account, contract, era, amount) => {
+                    assert_eq!((account, contract), (staker, contract_id));
+                    Some((era, amount))
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            expired_rewards
+                .iter()
+                .map(|(era, _)| *era)
+                .collect::<Vec<_>>(),
+            (start_era..first_claimable_era).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            Balances::free_balance(&TREASURY_ACCOUNT),
+            init_treasury_balance
+                + expired_rewards
+                    .iter()
+                    .map(|(_, amount)| amount)
+                    .sum::<Balance>()
+        );
+        assert_eq!(
+            DappsStaking::staker_info(&staker, &contract_id).oldest_unclaimed_era(),
+            Some(first_claimable_era)
+        );
+
+        // Remaining rewards can still be claimed
+        assert_claim_staker(staker, &contract_id);
+    })
+}
+
+#[test]
+fn slash_dapp_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let staker = 3;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+        assert_register(developer, &contract_id);
+        assert_set_dapp_commission(developer, &contract_id, Perbill::from_percent(20));
+        let start_era = DappsStaking::current_era();
+        assert_bond_and_stake(staker, &contract_id, 100);
+        advance_to_era(start_era + 5);
+
+        // Already claimed eras are skipped
+        assert_claim_dapp(&contract_id, start_era);
+        assert_slash_dapp(
+            &contract_id,
+            start_era,
+            3,
+            Perbill::from_percent(60),
+            false,
+            false,
+        );
+        assert_noop!(
+            DappsStaking::claim_dapp(RuntimeOrigin::signed(developer), contract_id, start_era + 1),
+            Error::<TestRuntime>::AlreadyClaimedInThisEra
+        );
+
+        // Reward can be fully forfeited, together with the deposit
+        assert_slash_dapp(&contract_id, start_era + 3, 1, Perbill::one(), true, true);
+        assert_noop!(
+            DappsStaking::bond_and_stake(RuntimeOrigin::signed(staker), contract_id, 100),
+            Error::<TestRuntime>::DappFrozen
+        );
+
+        // Existing stake can still be unstaked, and rewards are no longer restaked
+        assert_unbond_and_unstake(staker, &contract_id, 10);
         assert_claim_staker(staker, &contract_id);
 
-        // enable compounding mode, wait 3 eras
-        assert_set_reward_destination(staker, RewardDestination::StakeBalance);
-        advance_to_era(start_era + 2);
-        // ensure staker can claim with compounding
-        assert_claim_staker(staker, &contract_id);
+        // Nothing remains to be slashed from the deposit, and nothing is returned on unregistration
+        assert_noop!(
+            DappsStaking::slash_dapp(
+                RuntimeOrigin::root(),
+                contract_id,
+                0,
+                0,
+                Perbill::zero(),
+                true,
+                false
+            ),
+            Error::<TestRuntime>::NothingToSlash
+        );
+        assert_unregister(developer, &contract_id);
+
+        // Unclaimed rewards of the unregistered dapp can still be slashed
+        assert_slash_dapp(
+            &contract_id,
+            start_era + 4,
+            1,
+            Perbill::from_percent(50),
+            false,
+            false,
+        );
+    })
+}
+
+#[test]
+fn slash_dapp_is_not_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let staker = 3;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        let slash = Perbill::from_percent(50);
+
+        // Contract must exist
+        assert_noop!(
+            DappsStaking::slash_dapp(RuntimeOrigin::root(), contract_id, 1, 1, slash, true, true),
+            Error::<TestRuntime>::NotOperatedContract
+        );
+
+        assert_register(developer, &contract_id);
+        let start_era = DappsStaking::current_era();
+        assert_bond_and_stake(staker, &contract_id, 100);
+        advance_to_era(start_era + 2);
+
+        // Only slash origin can slash
+        assert_noop!(
+            DappsStaking::slash_dapp(
+                RuntimeOrigin::signed(developer),
+                contract_id,
+                start_era,
+                1,
+                slash,
+                true,
+                true
+            ),
+            BadOrigin
+        );
+
+        // Some slash action is required
+        assert_noop!(
+            DappsStaking::slash_dapp(
+                RuntimeOrigin::root(),
+                contract_id,
+                start_era,
+                1,
+                Perbill::zero(),
+                false,
+                false
+            ),
+            Error::<TestRuntime>::NothingToSlash
+        );
+        assert_noop!(
+            DappsStaking::slash_dapp(
+                RuntimeOrigin::root(),
+                contract_id,
+                start_era,
+                0,
+                slash,
+                false,
+                false
+            ),
+            Error::<TestRuntime>::NothingToSlash
+        );
+
+        // Number of eras is limited
+        assert_noop!(
+            DappsStaking::slash_dapp(
+                RuntimeOrigin::root(),
+                contract_id,
+                start_era,
+                MAX_SLASH_ERAS + 1,
+                slash,
+                false,
+                false
+            ),
+            Error::<TestRuntime>::InvalidNumberOfSlashEras
+        );
+
+        // Current era cannot be slashed
+        assert_noop!(
+            DappsStaking::slash_dapp(
+                RuntimeOrigin::root(),
+                contract_id,
+                start_era,
+                3,
+                slash,
+                false,
+                false
+            ),
+            Error::<TestRuntime>::EraOutOfBounds
+        );
+
+        // Deposit of an unregistered contract is no longer reserved
+        assert_unregister(developer, &contract_id);
+        assert_noop!(
+            DappsStaking::slash_dapp(
+                RuntimeOrigin::root(),
+                contract_id,
+                start_era,
+                1,
+                slash,
+                true,
+                false
+            ),
+            Error::<TestRuntime>::NotOperatedContract
+        );
     })
 }
 
 #[test]
-fn claiming_when_stakes_full_without_compounding_is_ok() {
+fn frozen_dapp_cannot_be_staked_on() {
     ExternalityBuilder::build().execute_with(|| {
         initialize_first_block();
 
-        let staker_id = 1;
+        let staker = 3;
         let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
-        // Insert a contract under registered contracts.
-        assert_register(10, &contract_id);
-
-        // Stake with MAX_ERA_STAKE_VALUES - 1 on the same contract. It must work.
-        let start_era = DappsStaking::current_era();
-        for offset in 1..MAX_ERA_STAKE_VALUES {
-            assert_bond_and_stake(staker_id, &contract_id, 100);
-            advance_to_era(start_era + offset * 5);
-        }
+        let frozen_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x02));
 
-        // Make sure reward_destination is set to StakeBalance
-        assert_set_reward_destination(staker_id, RewardDestination::StakeBalance);
+        assert_register(1, &contract_id);
+        assert_register(10, &frozen_contract_id);
+        assert_bond_and_stake(staker, &contract_id, 100);
+        assert_bond_and_stake(staker, &frozen_contract_id, 100);
+        assert_unbond_and_unstake(staker, &frozen_contract_id, 20);
 
-        // claim and restake once, so there's a claim record for the for the current era in the stakes vec
-        assert_claim_staker(staker_id, &contract_id);
+        assert_slash_dapp(&frozen_contract_id, 0, 0, Perbill::zero(), false, true);
 
-        // making another gap in eras and trying to claim and restake would exceed MAX_ERA_STAKE_VALUES
-        advance_to_era(DappsStaking::current_era() + 1);
         assert_noop!(
-            DappsStaking::claim_staker(RuntimeOrigin::signed(staker_id), contract_id),
-            Error::<TestRuntime>::TooManyEraStakeValues
+            DappsStaking::bond_and_stake_with_lock(
+                RuntimeOrigin::signed(staker),
+                frozen_contract_id,
+                100,
+                SHORT_STAKE_LOCK.0
+            ),
+            Error::<TestRuntime>::DappFrozen
         );
-
-        // set reward_destination to FreeBalance (disable restaking)
-        assert_set_reward_destination(staker_id, RewardDestination::FreeBalance);
-
-        // claiming should work again
-        assert_claim_staker(staker_id, &contract_id);
-    })
-}
-
-#[test]
-fn changing_reward_destination_for_empty_ledger_is_not_ok() {
-    ExternalityBuilder::build().execute_with(|| {
-        initialize_first_block();
-        let staker = 1;
         assert_noop!(
-            DappsStaking::set_reward_destination(
+            DappsStaking::nomination_transfer(
                 RuntimeOrigin::signed(staker),
-                RewardDestination::FreeBalance
+                contract_id,
+                20,
+                frozen_contract_id
             ),
-            Error::<TestRuntime>::NotActiveStaker
+            Error::<TestRuntime>::DappFrozen
         );
-    });
+        assert_noop!(
+            DappsStaking::rebond(RuntimeOrigin::signed(staker), frozen_contract_id, 20),
+            Error::<TestRuntime>::DappFrozen
+        );
+
+        // Stake can be moved away from the frozen contract
+        assert_nomination_transfer(staker, &frozen_contract_id, 20, &contract_id);
+    })
 }
 
 #[test]
-fn claim_dapp_with_zero_stake_periods_is_ok() {
+fn reregister_is_ok() {
     ExternalityBuilder::build().execute_with(|| {
         initialize_first_block();
 
         let developer = 1;
-        let staker = 2;
+        let (staker_1, staker_2) = (3, 4);
         let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
 
-        // Prepare scenario: <staked eras><not staked eras><staked eras><not staked eras>
-
-        let start_era = DappsStaking::current_era();
         assert_register(developer, &contract_id);
-        let stake_value = 100;
-        assert_bond_and_stake(staker, &contract_id, stake_value);
+        let start_era = DappsStaking::current_era();
+        assert_bond_and_stake(staker_1, &contract_id, 100);
+        assert_bond_and_stake(staker_2, &contract_id, 200);
+        advance_to_era(start_era + 2);
 
-        advance_to_era(start_era + 5);
-        let first_full_unstake_era = DappsStaking::current_era();
-        assert_unbond_and_unstake(staker, &contract_id, stake_value);
+        // Unregister the contract, one of the stakers withdraws while the other one stays
+        assert_unregister(developer, &contract_id);
+        let unregistered_era = DappsStaking::current_era();
+        assert_withdraw_from_unregistered(staker_2, &contract_id);
+        advance_to_era(unregistered_era + 2);
 
-        advance_to_era(DappsStaking::current_era() + 7);
-        let restake_era = DappsStaking::current_era();
-        assert_bond_and_stake(staker, &contract_id, stake_value);
+        assert_reregister(&contract_id);
+        let reregistered_era = DappsStaking::current_era();
+        assert_eq!(
+            DappsStaking::contract_stake_info(&contract_id, reregistered_era)
+                .unwrap()
+                .total,
+            100
+        );
 
-        advance_to_era(DappsStaking::current_era() + 4);
-        let second_full_unstake_era = DappsStaking::current_era();
-        assert_unbond_and_unstake(staker, &contract_id, stake_value);
-        advance_to_era(DappsStaking::current_era() + 10);
+        // Contract can be staked on again
+        assert_bond_and_stake(staker_2, &contract_id, 50);
+        advance_to_era(reregistered_era + 1);
 
-        // Ensure that first interval can be claimed
-        for era in start_era..first_full_unstake_era {
+        // Developer can claim eras prior to unregistration and after reregistration, but not the ones in between
+        for era in start_era..unregistered_era {
             assert_claim_dapp(&contract_id, era);
         }
-
-        // Ensure that the empty interval cannot be claimed
-        for era in first_full_unstake_era..restake_era {
+        for era in unregistered_era..reregistered_era {
             assert_noop!(
-                DappsStaking::claim_dapp(
-                    RuntimeOrigin::signed(developer),
-                    contract_id.clone(),
-                    era
-                ),
-                Error::<TestRuntime>::NotStakedContract
+                DappsStaking::claim_dapp(RuntimeOrigin::signed(developer), contract_id, era),
+                Error::<TestRuntime>::NotOperatedContract
             );
         }
+        assert_claim_dapp(&contract_id, reregistered_era);
 
-        // Ensure that second interval can be claimed
-        for era in restake_era..second_full_unstake_era {
-            assert_claim_dapp(&contract_id, era);
+        // Staker claims skip the eras in which the contract was unregistered
+        for _ in start_era..unregistered_era {
+            assert_claim_staker(staker_1, &contract_id);
         }
-
-        // Ensure no more claims are possible since contract was fully unstaked
-        assert_noop!(
-            DappsStaking::claim_dapp(
-                RuntimeOrigin::signed(developer),
-                contract_id.clone(),
-                second_full_unstake_era
-            ),
-            Error::<TestRuntime>::NotStakedContract
-        );
-
-        // Now stake again and ensure contract can once again be claimed
-        let last_claim_era = DappsStaking::current_era();
-        assert_bond_and_stake(staker, &contract_id, stake_value);
-        advance_to_era(last_claim_era + 1);
-        assert_claim_dapp(&contract_id, last_claim_era);
+        assert_claim_staker(staker_1, &contract_id);
+        assert_claim_staker(staker_2, &contract_id);
+        let (next_claim_era, _) = DappsStaking::staker_info(&staker_1, &contract_id).claim();
+        assert!(next_claim_era > reregistered_era);
     })
 }
 
 #[test]
-fn maintenance_mode_is_ok() {
+fn reregister_in_unregistration_era_is_ok() {
     ExternalityBuilder::build().execute_with(|| {
         initialize_first_block();
 
-        assert_ok!(DappsStaking::ensure_pallet_enabled());
-        assert!(!PalletDisabled::<TestRuntime>::exists());
+        let developer = 1;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
 
-        assert_ok!(DappsStaking::maintenance_mode(RuntimeOrigin::root(), true));
-        assert!(PalletDisabled::<TestRuntime>::exists());
-        System::assert_last_event(mock::RuntimeEvent::DappsStaking(Event::MaintenanceMode(
-            true,
-        )));
+        assert_register(developer, &contract_id);
+        assert_bond_and_stake(3, &contract_id, 100);
+        advance_to_era(DappsStaking::current_era() + 1);
 
-        let account = 1;
+        // No era is skipped, hence nothing is recorded
+        assert_unregister(developer, &contract_id);
+        assert_reregister(&contract_id);
+        assert!(DappsStaking::unregistered_periods(&contract_id).is_empty());
+    })
+}
+
+#[test]
+fn reregister_repairs_missing_stake_history() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
         let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
 
-        //
-        // 1
-        assert_noop!(
-            DappsStaking::register(RuntimeOrigin::root(), account, contract_id),
-            Error::<TestRuntime>::Disabled
-        );
-        assert_noop!(
-            DappsStaking::unregister(RuntimeOrigin::root(), contract_id),
-            Error::<TestRuntime>::Disabled
-        );
-        assert_noop!(
-            DappsStaking::withdraw_from_unregistered(RuntimeOrigin::signed(account), contract_id),
-            Error::<TestRuntime>::Disabled
+        assert_register(developer, &contract_id);
+        assert_bond_and_stake(3, &contract_id, 100);
+        advance_to_era(DappsStaking::current_era() + 1);
+        assert_unregister(developer, &contract_id);
+        let unregistered_era = DappsStaking::current_era();
+        advance_to_era(unregistered_era + 2);
+
+        // Only the stake info of the unregistration era is available
+        let contract_info =
+            DappsStaking::contract_stake_info(&contract_id, unregistered_era).unwrap();
+        let _ = ContractEraStake::<TestRuntime>::clear_prefix(&contract_id, u32::MAX, None);
+        ContractEraStake::<TestRuntime>::insert(
+            &contract_id,
+            unregistered_era,
+            contract_info.clone(),
         );
+        ContractStakeHistory::<TestRuntime>::remove(&contract_id);
+        assert!(DappsStaking::contract_stake_info(&contract_id, unregistered_era + 2).is_none());
 
-        //
-        // 2
-        assert_noop!(
-            DappsStaking::bond_and_stake(RuntimeOrigin::signed(account), contract_id, 100),
-            Error::<TestRuntime>::Disabled
-        );
-        assert_noop!(
-            DappsStaking::unbond_and_unstake(RuntimeOrigin::signed(account), contract_id, 100),
-            Error::<TestRuntime>::Disabled
+        assert_ok!(DappsStaking::reregister(
+            RuntimeOrigin::root(),
+            contract_id.clone()
+        ));
+        let current_era = DappsStaking::current_era();
+        assert_eq!(
+            DappsStaking::contract_stake_info(&contract_id, current_era),
+            Some(contract_info)
         );
-        assert_noop!(
-            DappsStaking::claim_dapp(RuntimeOrigin::signed(account), contract_id, 5),
-            Error::<TestRuntime>::Disabled
+        assert_eq!(
+            DappsStaking::contract_stake_history(&contract_id).into_inner(),
+            vec![current_era]
         );
+    })
+}
+
+#[test]
+fn reregister_is_not_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+        // Contract must exist
         assert_noop!(
-            DappsStaking::claim_staker(RuntimeOrigin::signed(account), contract_id),
-            Error::<TestRuntime>::Disabled
+            DappsStaking::reregister(RuntimeOrigin::root(), contract_id),
+            Error::<TestRuntime>::NotOperatedContract
         );
+
+        // Contract must be unregistered
+        assert_register(developer, &contract_id);
         assert_noop!(
-            DappsStaking::withdraw_unbonded(RuntimeOrigin::signed(account)),
-            Error::<TestRuntime>::Disabled
+            DappsStaking::reregister(RuntimeOrigin::root(), contract_id),
+            Error::<TestRuntime>::NotUnregisteredContract
         );
+
+        // Only register origin can reregister
+        assert_unregister(developer, &contract_id);
         assert_noop!(
-            DappsStaking::nomination_transfer(
-                RuntimeOrigin::signed(account),
-                contract_id,
-                100,
-                contract_id,
-            ),
-            Error::<TestRuntime>::Disabled
+            DappsStaking::reregister(RuntimeOrigin::signed(developer), contract_id),
+            BadOrigin
         );
 
-        //
-        // 3
+        // Number of reregistrations with unregistered eras is limited
+        for _ in 0..MAX_REREGISTRATIONS {
+            advance_to_era(DappsStaking::current_era() + 1);
+            assert_reregister(&contract_id);
+            assert_unregister(developer, &contract_id);
+        }
+        advance_to_era(DappsStaking::current_era() + 1);
         assert_noop!(
-            DappsStaking::force_new_era(RuntimeOrigin::root()),
-            Error::<TestRuntime>::Disabled
+            DappsStaking::reregister(RuntimeOrigin::root(), contract_id),
+            Error::<TestRuntime>::TooManyReregistrations
         );
-        // shouldn't do anything since we're in maintenance mode
-        assert_eq!(DappsStaking::on_initialize(3), Weight::zero());
-
-        //
-        // 4
-        assert_ok!(DappsStaking::maintenance_mode(RuntimeOrigin::root(), false));
-        System::assert_last_event(mock::RuntimeEvent::DappsStaking(Event::MaintenanceMode(
-            false,
-        )));
-        assert_register(account, &contract_id);
     })
 }
 
 #[test]
-fn maintenance_mode_no_change() {
+fn set_dapp_metadata_is_ok() {
     ExternalityBuilder::build().execute_with(|| {
         initialize_first_block();
 
-        // Expect an error since maintenance mode is already disabled
-        assert_ok!(DappsStaking::ensure_pallet_enabled());
-        assert_noop!(
-            DappsStaking::maintenance_mode(RuntimeOrigin::root(), false),
-            Error::<TestRuntime>::NoMaintenanceModeChange
-        );
+        let developer = 1;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        assert_register(developer, &contract_id);
 
-        // Same for the case when maintenance mode is already enabled
-        assert_ok!(DappsStaking::maintenance_mode(RuntimeOrigin::root(), true));
-        assert_noop!(
-            DappsStaking::maintenance_mode(RuntimeOrigin::root(), true),
-            Error::<TestRuntime>::NoMaintenanceModeChange
+        // Deposit is adjusted to the metadata size, both up and down
+        assert_set_dapp_metadata(
+            &contract_id,
+            dapp_metadata([5, 12, 8, 3], DAppCategory::DeFi),
+        );
+        assert_set_dapp_metadata(
+            &contract_id,
+            dapp_metadata(
+                [MAX_METADATA_FIELD_LENGTH as usize; 4],
+                DAppCategory::Gaming,
+            ),
         );
+        assert_set_dapp_metadata(
+            &contract_id,
+            dapp_metadata([1, 0, 0, 0], DAppCategory::Others),
+        );
+
+        // Metadata and its deposit are moved together with the contract ownership
+        let new_developer = 2;
+        assert_propose_ownership_transfer(developer, &contract_id, new_developer);
+        assert_accept_ownership_transfer(new_developer, &contract_id);
+        assert_set_dapp_metadata(&contract_id, dapp_metadata([7, 7, 7, 7], DAppCategory::NFT));
+
+        // Metadata is removed once the contract is unregistered
+        assert_unregister(new_developer, &contract_id);
     })
 }
 
 #[test]
-fn dev_stakers_split_util() {
-    let base_stakers_reward = 7 * 11 * 13 * 17;
-    let base_dapps_reward = 19 * 23 * 31;
-    let staked_on_contract = 123456;
-    let total_staked = staked_on_contract * 3;
-
-    // Prepare structs
-    let staking_points = ContractStakeInfo::<Balance> {
-        total: staked_on_contract,
-        number_of_stakers: 10,
-        contract_reward_claimed: false,
-    };
-    let era_info = EraInfo::<Balance> {
-        rewards: RewardInfo {
-            dapps: base_dapps_reward,
-            stakers: base_stakers_reward,
-        },
-        staked: total_staked,
-        locked: total_staked,
-    };
+fn set_dapp_metadata_is_not_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
 
-    let (dev_reward, stakers_reward) = DappsStaking::dev_stakers_split(&staking_points, &era_info);
+        let developer = 1;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        let metadata = dapp_metadata([5, 5, 5, 5], DAppCategory::Tooling);
 
-    let contract_stake_ratio = Perbill::from_rational(staked_on_contract, total_staked);
-    let calculated_stakers_reward = contract_stake_ratio * base_stakers_reward;
-    let calculated_dev_reward = contract_stake_ratio * base_dapps_reward;
-    assert_eq!(calculated_dev_reward, dev_reward);
-    assert_eq!(calculated_stakers_reward, stakers_reward);
+        // Contract must exist
+        assert_noop!(
+            DappsStaking::set_dapp_metadata(
+                RuntimeOrigin::signed(developer),
+                contract_id,
+                metadata.clone()
+            ),
+            Error::<TestRuntime>::NotOperatedContract
+        );
 
-    assert_eq!(
-        calculated_stakers_reward + calculated_dev_reward,
-        dev_reward + stakers_reward
-    );
+        // Only developer can set the metadata
+        assert_register(developer, &contract_id);
+        assert_noop!(
+            DappsStaking::set_dapp_metadata(
+                RuntimeOrigin::signed(2),
+                contract_id,
+                metadata.clone()
+            ),
+            Error::<TestRuntime>::NotOwnedContract
+        );
+
+        // Contract must be registered
+        assert_unregister(developer, &contract_id);
+        assert_noop!(
+            DappsStaking::set_dapp_metadata(
+                RuntimeOrigin::signed(developer),
+                contract_id,
+                metadata
+            ),
+            Error::<TestRuntime>::NotOperatedContract
+        );
+    })
 }
 
 #[test]
-pub fn tvl_util_test() {
+fn clear_dapp_metadata_is_ok() {
     ExternalityBuilder::build().execute_with(|| {
-        // Ensure TVL is zero before first block and also after first block
-        assert!(DappsStaking::tvl().is_zero());
         initialize_first_block();
-        assert!(DappsStaking::tvl().is_zero());
 
         let developer = 1;
         let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
         assert_register(developer, &contract_id);
 
-        // Expect TVL to change as we bond&stake more
-        let iterations = 10;
-        let stake_value = 100;
-        for x in 1..=iterations {
-            assert_bond_and_stake(developer, &contract_id, stake_value);
-            assert_eq!(DappsStaking::tvl(), stake_value * x);
-        }
+        // Only metadata origin can clear the metadata
+        assert_set_dapp_metadata(
+            &contract_id,
+            dapp_metadata([5, 5, 5, 5], DAppCategory::DeFi),
+        );
+        assert_noop!(
+            DappsStaking::clear_dapp_metadata(RuntimeOrigin::signed(developer), contract_id),
+            BadOrigin
+        );
 
-        // Era advancement should have no effect on TVL
-        advance_to_era(5);
-        assert_eq!(DappsStaking::tvl(), stake_value * iterations);
+        assert_clear_dapp_metadata(&contract_id);
+
+        // Metadata must exist
+        assert_noop!(
+            DappsStaking::clear_dapp_metadata(RuntimeOrigin::root(), contract_id),
+            Error::<TestRuntime>::NoDappMetadata
+        );
+
+        // Metadata can be set again afterwards
+        assert_set_dapp_metadata(
+            &contract_id,
+            dapp_metadata([3, 0, 3, 0], DAppCategory::Utility),
+        );
     })
 }
 
 #[test]
-pub fn set_contract_stake_info() {
+fn try_state_detects_inconsistent_storage() {
     ExternalityBuilder::build().execute_with(|| {
         initialize_first_block();
 
+        let staker = 1;
         let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
         assert_register(10, &contract_id);
+        assert_bond_and_stake(staker, &contract_id, 100);
+        assert_unbond_and_unstake(staker, &contract_id, 30);
+        advance_to_era(DappsStaking::current_era() + 1);
+        assert_ok!(DappsStaking::do_try_state());
 
-        let staker_id = 1;
-        assert_bond_and_stake(staker_id, &contract_id, 1000);
+        // Contract stake no longer matches its stakers
+        let current_era = DappsStaking::current_era();
+        let contract_info = DappsStaking::contract_stake_info(&contract_id, current_era).unwrap();
+        let mut modified_info = contract_info.clone();
+        modified_info.total += 1;
+        ContractEraStake::<TestRuntime>::insert(&contract_id, current_era, modified_info);
+        assert!(DappsStaking::do_try_state().is_err());
+        ContractEraStake::<TestRuntime>::insert(&contract_id, current_era, contract_info);
+        assert_ok!(DappsStaking::do_try_state());
+
+        // Ledger locked amount no longer covers staked and unbonding funds
+        let ledger = DappsStaking::ledger(&staker);
+        let mut modified_ledger = ledger.clone();
+        modified_ledger.locked -= 1;
+        Ledger::<TestRuntime>::insert(&staker, modified_ledger);
+        assert!(DappsStaking::do_try_state().is_err());
+        Ledger::<TestRuntime>::insert(&staker, ledger);
+        assert_ok!(DappsStaking::do_try_state());
+
+        // Pallet account can't pay out the outstanding rewards
+        let _ = Balances::slash(
+            &DappsStaking::account_id(),
+            Balances::free_balance(&DappsStaking::account_id()),
+        );
+        assert!(DappsStaking::do_try_state().is_err());
+    })
+}
 
-        // Read current contract stake info, then overwrite it with different value
-        let original_contract_stake_info =
-            DappsStaking::contract_stake_info(&contract_id, 1).unwrap();
-        let mut modified_info = original_contract_stake_info.clone();
-        modified_info.total = modified_info.total + 17;
-        ContractEraStake::<TestRuntime>::insert(&contract_id, 1, modified_info);
+#[test]
+fn genesis_config_is_ok() {
+    let first_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+    let second_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x02));
+
+    ExternalityBuilder::build_with_genesis(Some(crate::GenesisConfig::<TestRuntime> {
+        current_era: 0,
+        dapps: vec![(10, first_contract_id), (11, second_contract_id)],
+        stakes: vec![
+            (1, first_contract_id, 100),
+            (2, first_contract_id, 50),
+            (1, second_contract_id, 200),
+        ],
+        reward_destinations: vec![(2, RewardDestination::FreeBalance)],
+    }))
+    .execute_with(|| {
+        // dApps are registered and their deposit is reserved
+        let dapp_info = DappsStaking::dapp_info(&first_contract_id).unwrap();
+        assert_eq!(dapp_info.developer, 10);
+        assert_eq!(dapp_info.state, DAppState::Registered);
+        assert!(DappsStaking::dapp_info(&second_contract_id).is_some());
+        assert_eq!(
+            <TestRuntime as Config>::Currency::reserved_balance(&10),
+            REGISTER_DEPOSIT
+        );
 
-        // Ensure only root can call it
-        assert_noop!(
-            DappsStaking::set_contract_stake_info(
-                RuntimeOrigin::signed(1),
-                contract_id.clone(),
-                1,
-                original_contract_stake_info.clone()
-            ),
-            BadOrigin
+        // Stakes are registered for the genesis era and locked
+        assert_eq!(DappsStaking::tvl(), 350);
+        let contract_info = DappsStaking::contract_stake_info(&first_contract_id, 0).unwrap();
+        assert_eq!(contract_info.total, 150);
+        assert_eq!(contract_info.number_of_stakers, 2);
+        assert_eq!(DappsStaking::ledger(&1).locked, 300);
+        assert_eq!(Balances::usable_balance(&1), 9000 - 300);
+        assert_eq!(
+            DappsStaking::staker_info(&2, &first_contract_id).latest_staked_value(),
+            50
         );
 
-        // Verify we can fix the corrupted stroage
-        assert_ne!(
-            ContractEraStake::<TestRuntime>::get(&contract_id, 1).unwrap(),
-            original_contract_stake_info
+        // Reward destination defaults to restaking unless specified
+        assert_eq!(
+            DappsStaking::ledger(&1).reward_destination(),
+            RewardDestination::StakeBalance
         );
-        assert_ok!(DappsStaking::set_contract_stake_info(
-            RuntimeOrigin::root(),
-            contract_id.clone(),
-            1,
-            original_contract_stake_info.clone()
-        ));
         assert_eq!(
-            ContractEraStake::<TestRuntime>::get(&contract_id, 1).unwrap(),
-            original_contract_stake_info
+            DappsStaking::ledger(&2).reward_destination(),
+            RewardDestination::FreeBalance
         );
+        assert_ok!(DappsStaking::do_try_state());
+
+        // Genesis stakes earn rewards like any other stakes
+        initialize_first_block();
+        advance_to_era(DappsStaking::current_era() + 1);
+        assert_claim_staker(1, &first_contract_id);
+        assert_claim_staker(2, &first_contract_id);
+        assert_claim_dapp(&first_contract_id, 0);
     })
 }
 
 #[test]
-fn custom_max_encoded_len() {
-    let max_unbonding_info_len = 10 * (4 + 16) + 1;
-    assert_eq!(
-        UnbondingInfo::<u128>::max_encoded_len(),
-        max_unbonding_info_len as usize
-    );
+fn set_and_remove_operator_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
 
-    let max_staker_info_len = 10 * (4 + 16) + 1;
-    assert_eq!(
-        StakerInfo::<u128>::max_encoded_len(),
-        max_staker_info_len as usize
-    );
+        let staker = 1;
+        assert_set_operator(staker, 2);
+
+        // Existing operator is replaced
+        assert_set_operator(staker, 3);
+
+        assert_remove_operator(staker);
+        assert_noop!(
+            DappsStaking::remove_operator(RuntimeOrigin::signed(staker)),
+            Error::<TestRuntime>::NoOperator
+        );
+
+        // Staker can't be its own operator
+        assert_noop!(
+            DappsStaking::set_operator(RuntimeOrigin::signed(staker), staker),
+            Error::<TestRuntime>::OperatorIsStaker
+        );
+    })
 }
 
 #[test]
-fn burn_stale_reward_is_ok() {
+fn operator_actions_are_ok() {
     ExternalityBuilder::build().execute_with(|| {
         initialize_first_block();
 
-        let developer = 1;
-        let staker = 3;
-        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
-
-        let start_era = DappsStaking::current_era();
+        let staker = 1;
+        let operator = 2;
+        let origin_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        let target_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x02));
+        assert_register(10, &origin_contract_id);
+        assert_register(11, &target_contract_id);
+        assert_bond_and_stake(staker, &origin_contract_id, 100);
 
-        // Register & stake on contract
-        assert_register(developer, &contract_id);
-        assert_bond_and_stake(staker, &contract_id, 100);
+        assert_set_operator(staker, operator);
+        assert_operator_nomination_transfer(
+            operator,
+            staker,
+            &origin_contract_id,
+            40,
+            &target_contract_id,
+        );
+        assert_operator_set_reward_destination(operator, staker, RewardDestination::FreeBalance);
 
-        // Advance enough eras so stale rewards become burnable
-        let eras_advanced = REWARD_RETENTION_PERIOD + 1;
-        advance_to_era(start_era + eras_advanced);
-        assert_unregister(developer, &contract_id);
+        // Operator can claim rewards on behalf of the staker, like any other account
+        advance_to_era(DappsStaking::current_era() + 1);
+        assert_claim_staker_for(operator, staker, &target_contract_id, 1);
 
-        assert_burn_stale_reward(&contract_id, start_era);
+        // Operator's own calls don't touch the staker's funds
+        assert_noop!(
+            DappsStaking::unbond_and_unstake(
+                RuntimeOrigin::signed(operator),
+                origin_contract_id,
+                10
+            ),
+            Error::<TestRuntime>::NotStakedContract
+        );
+        assert_eq!(DappsStaking::ledger(&staker).locked, 100);
     })
 }
 
 #[test]
-fn burn_stale_reward_from_registered_dapp_fails() {
+fn operator_actions_are_not_ok() {
     ExternalityBuilder::build().execute_with(|| {
         initialize_first_block();
 
-        let developer = 1;
-        let staker = 3;
-        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
-
-        let start_era = DappsStaking::current_era();
+        let staker = 1;
+        let operator = 2;
+        let origin_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        let target_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x02));
+        assert_register(10, &origin_contract_id);
+        assert_register(11, &target_contract_id);
+        assert_bond_and_stake(staker, &origin_contract_id, 100);
 
-        // Register & stake on contract
-        assert_register(developer, &contract_id);
-        assert_bond_and_stake(staker, &contract_id, 100);
+        // Caller must be the operator of the staker
+        assert_noop!(
+            DappsStaking::operator_nomination_transfer(
+                RuntimeOrigin::signed(operator),
+                staker,
+                origin_contract_id,
+                40,
+                target_contract_id,
+            ),
+            Error::<TestRuntime>::NotOperator
+        );
+        assert_set_operator(staker, 3);
+        assert_noop!(
+            DappsStaking::operator_set_reward_destination(
+                RuntimeOrigin::signed(operator),
+                staker,
+                RewardDestination::FreeBalance
+            ),
+            Error::<TestRuntime>::NotOperator
+        );
 
-        // Advance enough eras so stale rewards would become burnable, in case dapp was unregistered
-        let eras_advanced = REWARD_RETENTION_PERIOD;
-        advance_to_era(start_era + eras_advanced);
+        // Revoked operator can no longer act on behalf of the staker
+        assert_remove_operator(staker);
+        assert_noop!(
+            DappsStaking::operator_set_reward_destination(
+                RuntimeOrigin::signed(3),
+                staker,
+                RewardDestination::FreeBalance
+            ),
+            Error::<TestRuntime>::NotOperator
+        );
 
-        // Rewards shouldn't be burnable since retention period hasn't expired yet
+        // Same rules as for the staker's own calls apply
+        assert_set_operator(operator, staker);
         assert_noop!(
-            DappsStaking::burn_stale_reward(RuntimeOrigin::root(), contract_id, start_era,),
-            Error::<TestRuntime>::NotUnregisteredContract
+            DappsStaking::operator_set_reward_destination(
+                RuntimeOrigin::signed(staker),
+                operator,
+                RewardDestination::FreeBalance
+            ),
+            Error::<TestRuntime>::NotActiveStaker
         );
     })
 }
 
 #[test]
-fn burn_stale_reward_before_retention_period_finished_fails() {
+fn batch_nomination_transfer_is_ok() {
     ExternalityBuilder::build().execute_with(|| {
         initialize_first_block();
 
-        let developer = 1;
-        let staker = 3;
-        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
-
-        let start_era = DappsStaking::current_era();
-
-        // Register & stake on contract
-        assert_register(developer, &contract_id);
-        assert_bond_and_stake(staker, &contract_id, 100);
+        let staker = 1;
+        let first_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        let second_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x02));
+        let third_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x03));
+        assert_register(10, &first_contract_id);
+        assert_register(11, &second_contract_id);
+        assert_register(20, &third_contract_id);
+        assert_bond_and_stake(staker, &first_contract_id, 300);
+        assert_bond_and_stake(staker, &second_contract_id, 100);
 
-        // Advance enough eras so stale rewards become burnable
-        let eras_advanced = REWARD_RETENTION_PERIOD;
-        advance_to_era(start_era + eras_advanced);
-        assert_unregister(developer, &contract_id);
+        assert_batch_nomination_transfer(staker, vec![(first_contract_id, second_contract_id, 50)]);
 
-        // Rewards shouldn't be burnable since retention period hasn't expired yet
-        assert_noop!(
-            DappsStaking::burn_stale_reward(RuntimeOrigin::root(), contract_id, start_era,),
-            Error::<TestRuntime>::EraOutOfBounds
+        // Value received by an earlier move can be moved on by a later one
+        assert_batch_nomination_transfer(
+            staker,
+            vec![
+                (first_contract_id, third_contract_id, 100),
+                (second_contract_id, first_contract_id, 30),
+                (third_contract_id, second_contract_id, 60),
+                (first_contract_id, second_contract_id, 20),
+            ],
+        );
+        assert_eq!(
+            DappsStaking::contract_stake_info(&third_contract_id, DappsStaking::current_era())
+                .unwrap()
+                .number_of_stakers,
+            1
         );
     })
 }
 
 #[test]
-fn burn_stale_reward_negative_checks() {
+fn batch_nomination_transfer_is_not_ok() {
     ExternalityBuilder::build().execute_with(|| {
         initialize_first_block();
 
-        let developer = 1;
-        let staker = 3;
-        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        let staker = 1;
+        let first_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        let second_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x02));
+        let unregistered_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x03));
+        assert_register(10, &first_contract_id);
+        assert_register(11, &second_contract_id);
+        assert_bond_and_stake(staker, &first_contract_id, 300);
 
-        // Cannot burn from non-existing contract
+        // Number of transfers must be greater than zero
         assert_noop!(
-            DappsStaking::burn_stale_reward(RuntimeOrigin::root(), contract_id, 1,),
-            Error::<TestRuntime>::NotOperatedContract
+            DappsStaking::batch_nomination_transfer(
+                RuntimeOrigin::signed(staker),
+                Default::default()
+            ),
+            Error::<TestRuntime>::InvalidNumberOfNominationTransfers
         );
 
-        // Cannot burn unless called with root privileges
+        // In case any move fails, none are applied
         assert_noop!(
-            DappsStaking::burn_stale_reward(RuntimeOrigin::signed(developer), contract_id, 1,),
-            BadOrigin
+            DappsStaking::batch_nomination_transfer(
+                RuntimeOrigin::signed(staker),
+                BoundedVec::truncate_from(vec![
+                    (first_contract_id, second_contract_id, 100),
+                    (first_contract_id, first_contract_id, 100),
+                ])
+            ),
+            Error::<TestRuntime>::NominationTransferToSameContract
+        );
+        assert_noop!(
+            DappsStaking::batch_nomination_transfer(
+                RuntimeOrigin::signed(staker),
+                BoundedVec::truncate_from(vec![
+                    (first_contract_id, second_contract_id, 100),
+                    (second_contract_id, first_contract_id, 50),
+                    (first_contract_id, unregistered_contract_id, 50),
+                ])
+            ),
+            Error::<TestRuntime>::NotOperatedContract
         );
 
-        // Register & stake on contract
-        assert_register(developer, &contract_id);
-        assert_bond_and_stake(staker, &contract_id, 100);
-
-        // Advance enough eras so stale rewards become burnable
-        let start_era = DappsStaking::current_era();
-        let eras_advanced = REWARD_RETENTION_PERIOD + 2;
-        advance_to_era(start_era + eras_advanced);
-        assert_unregister(developer, &contract_id);
-
-        // Claim them (before they are burned)
-        assert_claim_dapp(&contract_id, start_era);
-
-        // No longer possible to burn if reward was claimed
+        // Max number of stakers is enforced on the target contracts
+        for temp_staker in 100..(MAX_NUMBER_OF_STAKERS as u64 + 100) {
+            Balances::resolve_creating(&temp_staker, Balances::issue(100));
+            assert_bond_and_stake(temp_staker, &second_contract_id, 13);
+        }
         assert_noop!(
-            DappsStaking::burn_stale_reward(RuntimeOrigin::root(), contract_id, start_era,),
-            Error::<TestRuntime>::AlreadyClaimedInThisEra
+            DappsStaking::batch_nomination_transfer(
+                RuntimeOrigin::signed(staker),
+                BoundedVec::truncate_from(vec![(first_contract_id, second_contract_id, 100)])
+            ),
+            Error::<TestRuntime>::MaxNumberOfStakersExceeded
         );
     })
 }
@@ -7,12 +7,12 @@
 // If not, see <https://polyformproject.org/licenses/noncommercial/1.0.0//>.
 
 use super::*;
-use frame_support::assert_ok;
-use mock::Balance;
+use frame_support::{assert_ok, traits::ConstU32};
+use mock::{Balance, MaxEraStakeValues, MAX_ERA_STAKE_VALUES};
 
 #[test]
 fn unbonding_info_test() {
-    let mut unbonding_info = UnbondingInfo::<Balance>::default();
+    let mut unbonding_info = UnbondingInfo::<Balance, ConstU32<5>>::default();
 
     // assert basic ops on empty info
     assert!(unbonding_info.is_empty());
@@ -34,7 +34,7 @@ fn unbonding_info_test() {
     }
 
     // Add one unlocking chunk and verify basic ops.
-    unbonding_info.add(chunks[0 as usize]);
+    assert_ok!(unbonding_info.add(chunks[0 as usize]));
 
     assert!(!unbonding_info.is_empty());
     assert_eq!(1, unbonding_info.len());
@@ -47,7 +47,7 @@ fn unbonding_info_test() {
 
     // Add remainder and verify basic ops
     for x in unbonding_info.len() as usize..chunks.len() {
-        unbonding_info.add(chunks[x]);
+        assert_ok!(unbonding_info.add(chunks[x]));
         // Ensure internal vec is sorted
         assert!(unbonding_info
             .vec()
@@ -65,9 +65,75 @@ fn unbonding_info_test() {
     assert_eq!(unbonding_info.sum(), first_info.sum() + second_info.sum());
 }
 
+#[test]
+fn unbonding_info_add_when_full() {
+    let mut unbonding_info = UnbondingInfo::<Balance, ConstU32<2>>::default();
+    let first_chunk = UnlockingChunk {
+        amount: 100,
+        unlock_era: 3,
+    };
+    let second_chunk = UnlockingChunk {
+        amount: 200,
+        unlock_era: 5,
+    };
+    assert_ok!(unbonding_info.add(first_chunk));
+    assert_ok!(unbonding_info.add(second_chunk));
+
+    // Chunk with an existing unlock era is merged so it doesn't require additional space
+    assert_ok!(unbonding_info.add(first_chunk));
+    assert_eq!(2, unbonding_info.len());
+    assert_eq!(
+        first_chunk.amount * 2 + second_chunk.amount,
+        unbonding_info.sum()
+    );
+
+    // New chunk cannot be added
+    let third_chunk = UnlockingChunk {
+        amount: 300,
+        unlock_era: 4,
+    };
+    assert_eq!(unbonding_info.add(third_chunk), Err(third_chunk));
+    assert_eq!(2, unbonding_info.len());
+}
+
+#[test]
+fn unbonding_info_rebond() {
+    let mut unbonding_info = UnbondingInfo::<Balance, ConstU32<5>>::default();
+    assert!(unbonding_info.rebond(100).is_zero());
+
+    for (amount, unlock_era) in [(100, 3), (200, 4), (300, 5)] {
+        assert_ok!(unbonding_info.add(UnlockingChunk { amount, unlock_era }));
+    }
+
+    // Newest chunk is reduced first
+    assert_eq!(unbonding_info.rebond(50), 50);
+    assert_eq!(unbonding_info.len(), 3);
+    assert_eq!(unbonding_info.sum(), 550);
+
+    // Fully rebonded chunks are removed
+    assert_eq!(unbonding_info.rebond(300), 300);
+    assert_eq!(
+        unbonding_info.vec(),
+        vec![
+            UnlockingChunk {
+                amount: 100,
+                unlock_era: 3
+            },
+            UnlockingChunk {
+                amount: 150,
+                unlock_era: 4
+            }
+        ]
+    );
+
+    // Rebonding more than available rebonds everything
+    assert_eq!(unbonding_info.rebond(1000), 250);
+    assert!(unbonding_info.is_empty());
+}
+
 #[test]
 fn staker_info_basic() {
-    let staker_info = StakerInfo::<Balance>::default();
+    let staker_info = StakerInfo::<Balance, MaxEraStakeValues>::default();
 
     assert!(staker_info.is_empty());
     assert_eq!(staker_info.len(), 0);
@@ -76,7 +142,7 @@ fn staker_info_basic() {
 
 #[test]
 fn staker_info_stake_ops() {
-    let mut staker_info = StakerInfo::<Balance>::default();
+    let mut staker_info = StakerInfo::<Balance, MaxEraStakeValues>::default();
 
     // Do first stake and verify it
     let first_era = 1;
@@ -119,7 +185,7 @@ fn staker_info_stake_ops() {
 
 #[test]
 fn staker_info_stake_error() {
-    let mut staker_info = StakerInfo::<Balance>::default();
+    let mut staker_info = StakerInfo::<Balance, MaxEraStakeValues>::default();
     assert_ok!(staker_info.stake(5, 100));
     if let Err(_) = staker_info.stake(4, 100) {
     } else {
@@ -129,7 +195,7 @@ fn staker_info_stake_error() {
 
 #[test]
 fn staker_info_unstake_ops() {
-    let mut staker_info = StakerInfo::<Balance>::default();
+    let mut staker_info = StakerInfo::<Balance, MaxEraStakeValues>::default();
 
     // Unstake on empty staker_info
     assert!(staker_info.is_empty());
@@ -182,7 +248,7 @@ fn staker_info_unstake_ops() {
 
 #[test]
 fn stake_after_full_unstake() {
-    let mut staker_info = StakerInfo::<Balance>::default();
+    let mut staker_info = StakerInfo::<Balance, MaxEraStakeValues>::default();
 
     // Stake some amount
     let first_era = 1;
@@ -206,7 +272,7 @@ fn stake_after_full_unstake() {
 
 #[test]
 fn staker_info_unstake_error() {
-    let mut staker_info = StakerInfo::<Balance>::default();
+    let mut staker_info = StakerInfo::<Balance, MaxEraStakeValues>::default();
     assert_ok!(staker_info.stake(5, 100));
     if let Err(_) = staker_info.unstake(4, 100) {
     } else {
@@ -214,9 +280,34 @@ fn staker_info_unstake_error() {
     }
 }
 
+#[test]
+fn staker_info_stake_and_unstake_when_full() {
+    let mut staker_info = StakerInfo::<Balance, MaxEraStakeValues>::default();
+    for era in 1..=MAX_ERA_STAKE_VALUES {
+        assert_ok!(staker_info.stake(era, 100));
+    }
+    assert_eq!(MAX_ERA_STAKE_VALUES, staker_info.len());
+
+    // Modifying the latest era doesn't require additional space
+    let latest_era = MAX_ERA_STAKE_VALUES;
+    assert_ok!(staker_info.stake(latest_era, 100));
+    assert_ok!(staker_info.unstake(latest_era, 50));
+
+    // New era cannot be added
+    assert_eq!(
+        staker_info.stake(latest_era + 1, 100),
+        Err(StakerInfoError::TooManyEraStakeValues)
+    );
+    assert_eq!(
+        staker_info.unstake(latest_era + 1, 100),
+        Err(StakerInfoError::TooManyEraStakeValues)
+    );
+    assert_eq!(MAX_ERA_STAKE_VALUES, staker_info.len());
+}
+
 #[test]
 fn staker_info_claim_ops_basic() {
-    let mut staker_info = StakerInfo::<Balance>::default();
+    let mut staker_info = StakerInfo::<Balance, MaxEraStakeValues>::default();
 
     // Empty staker info
     assert!(staker_info.is_empty());
@@ -231,7 +322,7 @@ fn staker_info_claim_ops_basic() {
     assert!(staker_info.is_empty());
 
     // Only one staked exists
-    staker_info = StakerInfo::<Balance>::default();
+    staker_info = StakerInfo::<Balance, MaxEraStakeValues>::default();
     let stake_era = 1;
     let stake_value = 123;
     assert_ok!(staker_info.stake(stake_era, stake_value));
@@ -242,7 +333,7 @@ fn staker_info_claim_ops_basic() {
 
 #[test]
 fn staker_info_claim_ops_advanced() {
-    let mut staker_info = StakerInfo::<Balance>::default();
+    let mut staker_info = StakerInfo::<Balance, MaxEraStakeValues>::default();
 
     // Two consecutive eras staked, third era contains a gap with the second one
     let (first_stake_era, second_stake_era, third_stake_era) = (1, 2, 4);
@@ -298,3 +389,77 @@ fn staker_info_claim_ops_advanced() {
         assert_eq!(staker_info.latest_staked_value(), fourth_stake_value);
     }
 }
+
+#[test]
+fn staker_info_skip_to() {
+    let mut staker_info = StakerInfo::<Balance, MaxEraStakeValues>::default();
+
+    // Nothing to skip
+    staker_info.skip_to(5);
+    assert!(staker_info.is_empty());
+
+    assert_ok!(staker_info.stake(5, 1000));
+    assert_ok!(staker_info.stake(7, 300));
+    assert_ok!(staker_info.unstake(8, 1300));
+    assert_ok!(staker_info.stake(15, 3000));
+    assert_eq!(staker_info.len(), 4);
+
+    // Skipping to an already claimable era doesn't change anything
+    staker_info.skip_to(4);
+    assert_eq!(staker_info.oldest_unclaimed_era(), Some(5));
+    assert_eq!(staker_info.len(), 4);
+
+    // Skip within the first stake
+    staker_info.skip_to(6);
+    assert_eq!(staker_info.len(), 4);
+    assert_eq!(staker_info.clone().claim(), (6, 1000));
+
+    // Unstaked period is skipped as well
+    staker_info.skip_to(10);
+    assert_eq!(staker_info.len(), 1);
+    assert_eq!(staker_info.clone().claim(), (15, 3000));
+
+    // Skip past the latest stake
+    staker_info.skip_to(20);
+    assert_eq!(staker_info.len(), 1);
+    assert_eq!(staker_info.claim(), (20, 3000));
+    assert_eq!(staker_info.latest_staked_value(), 3000);
+}
+
+#[test]
+fn reward_destination_restake_part() {
+    let reward: Balance = 1000;
+
+    assert!(RewardDestination::FreeBalance
+        .restake_part(reward)
+        .is_zero());
+    assert_eq!(RewardDestination::StakeBalance.restake_part(reward), reward);
+    assert_eq!(
+        RewardDestination::Split(Perbill::from_percent(35)).restake_part(reward),
+        350
+    );
+    assert!(RewardDestination::Split(Perbill::zero())
+        .restake_part(reward)
+        .is_zero());
+    assert_eq!(
+        RewardDestination::Split(Perbill::one()).restake_part(reward),
+        reward
+    );
+}
+
+#[test]
+fn dapp_category_try_from_u8() {
+    for category in [
+        DAppCategory::Others,
+        DAppCategory::DeFi,
+        DAppCategory::NFT,
+        DAppCategory::Gaming,
+        DAppCategory::Tooling,
+        DAppCategory::Utility,
+    ] {
+        // Raw value must match the encoded enum index
+        assert_eq!(DAppCategory::try_from(category as u8), Ok(category));
+        assert_eq!(category.encode(), vec![category as u8]);
+    }
+    assert!(DAppCategory::try_from(DAppCategory::Utility as u8 + 1).is_err());
+}
@@ -59,6 +59,22 @@ pub trait WeightInfo {
 	fn maintenance_mode() -> Weight;
 	fn set_reward_destination() -> Weight;
 	fn nomination_transfer() -> Weight;
+	fn claim_staker_batch(n: u32, ) -> Weight;
+	fn propose_ownership_transfer() -> Weight;
+	fn accept_ownership_transfer() -> Weight;
+	fn rebond() -> Weight;
+	fn set_dapp_commission() -> Weight;
+	fn set_staking_parameters() -> Weight;
+	fn instant_unbond_and_unstake() -> Weight;
+	fn instant_withdraw_unbonded() -> Weight;
+	fn bond_and_stake_with_lock() -> Weight;
+	fn slash_dapp(n: u32, ) -> Weight;
+	fn reregister() -> Weight;
+	fn set_dapp_metadata(n: u32, ) -> Weight;
+	fn clear_dapp_metadata() -> Weight;
+	fn set_operator() -> Weight;
+	fn remove_operator() -> Weight;
+	fn batch_nomination_transfer(n: u32, ) -> Weight;
 }
 
 /// Weights for pallet_staking using the Substrate node and recommended hardware.
@@ -72,11 +88,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: DappsStaking RegisteredDapps (r:1 w:1)
+	// Storage: DappsStaking PendingOwnershipTransfer (r:0 w:1)
 	// Storage: System Account (r:1 w:1)
+	// Storage: DappsStaking SlashedDeposits (r:1 w:1)
+	// Storage: DappsStaking DappMetadata (r:1 w:1)
 	fn unregister() -> Weight {
-		Weight::from_ref_time(37_244_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().writes(2 as u64))
+		Weight::from_ref_time(41_307_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
 	}
 	// Storage: DappsStaking RegisteredDapps (r:1 w:0)
 	// Storage: DappsStaking GeneralStakerInfo (r:1 w:1)
@@ -84,32 +103,39 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
 	// Storage: DappsStaking GeneralEraInfo (r:1 w:1)
+	// Storage: DappsStaking StakeLocks (r:1 w:1)
+	// Storage: DappsStaking ContractEraStake (r:1 w:1)
+	// Storage: DappsStaking ContractStakeHistory (r:1 w:1)
 	fn withdraw_from_unregistered() -> Weight {
 		Weight::from_ref_time(53_956_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(6 as u64))
-			.saturating_add(T::DbWeight::get().writes(5 as u64))
+			.saturating_add(T::DbWeight::get().reads(9 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
 	}
 	// Storage: DappsStaking RegisteredDapps (r:1 w:0)
 	// Storage: DappsStaking Ledger (r:1 w:1)
 	// Storage: DappsStaking ContractEraStake (r:1 w:1)
+	// Storage: DappsStaking ContractStakeHistory (r:1 w:1)
 	// Storage: DappsStaking GeneralStakerInfo (r:1 w:1)
 	// Storage: DappsStaking GeneralEraInfo (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
+	// Storage: DappsStaking FrozenDapps (r:1 w:0)
 	fn bond_and_stake() -> Weight {
-		Weight::from_ref_time(140_275_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(6 as u64))
-			.saturating_add(T::DbWeight::get().writes(5 as u64))
+		Weight::from_ref_time(146_863_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
 	}
 	// Storage: DappsStaking RegisteredDapps (r:1 w:0)
 	// Storage: DappsStaking GeneralStakerInfo (r:1 w:1)
 	// Storage: DappsStaking ContractEraStake (r:1 w:1)
+	// Storage: DappsStaking ContractStakeHistory (r:1 w:1)
 	// Storage: DappsStaking Ledger (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: DappsStaking GeneralEraInfo (r:1 w:1)
+	// Storage: DappsStaking StakeLocks (r:1 w:0)
 	fn unbond_and_unstake() -> Weight {
-		Weight::from_ref_time(141_641_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(6 as u64))
-			.saturating_add(T::DbWeight::get().writes(5 as u64))
+		Weight::from_ref_time(148_102_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
 	}
 	// Storage: DappsStaking Ledger (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
@@ -119,42 +145,47 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3 as u64))
 			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
-	// Storage: DappsStaking RegisteredDapps (r:2 w:0)
-	// Storage: DappsStaking GeneralStakerInfo (r:2 w:2)
-	// Storage: DappsStaking ContractEraStake (r:2 w:2)
-	fn nomination_transfer() -> Weight {
-		Weight::from_ref_time(43_519_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(6 as u64))
-			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	// Storage: DappsStaking GeneralStakerInfo (r:1 w:1)
+	// Storage: DappsStaking RegisteredDapps (r:1 w:0)
+	// Storage: DappsStaking ContractEraStake (r:1 w:0)
+	// Storage: DappsStaking ContractStakeHistory (r:1 w:0)
+	// Storage: DappsStaking GeneralEraInfo (r:1 w:0)
+	// Storage: DappsStaking Ledger (r:1 w:0)
+	// Storage: DappsStaking DappCommission (r:1 w:0)
+	// Storage: DappsStaking StakeLocks (r:1 w:1)
+	// Storage: DappsStaking FrozenDapps (r:1 w:0)
+	// Storage: DappsStaking UnregisteredPeriods (r:1 w:0)
+	fn claim_staker_without_restake() -> Weight {
+		Weight::from_ref_time(51_842_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: DappsStaking GeneralStakerInfo (r:1 w:1)
 	// Storage: DappsStaking RegisteredDapps (r:1 w:0)
 	// Storage: DappsStaking ContractEraStake (r:2 w:1)
+	// Storage: DappsStaking ContractStakeHistory (r:2 w:1)
 	// Storage: DappsStaking GeneralEraInfo (r:2 w:1)
 	// Storage: DappsStaking Ledger (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
+	// Storage: DappsStaking DappCommission (r:1 w:0)
+	// Storage: DappsStaking StakeLocks (r:1 w:1)
+	// Storage: DappsStaking FrozenDapps (r:1 w:0)
+	// Storage: DappsStaking UnregisteredPeriods (r:1 w:0)
 	fn claim_staker_with_restake() -> Weight {
-		Weight::from_ref_time(72_500_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(9 as u64))
-			.saturating_add(T::DbWeight::get().writes(6 as u64))
-	}
-	// Storage: DappsStaking GeneralStakerInfo (r:1 w:1)
-	// Storage: DappsStaking RegisteredDapps (r:1 w:0)
-	// Storage: DappsStaking ContractEraStake (r:1 w:0)
-	// Storage: DappsStaking GeneralEraInfo (r:1 w:0)
-	// Storage: DappsStaking Ledger (r:1 w:0)
-	fn claim_staker_without_restake() -> Weight {
-		Weight::from_ref_time(45_414_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(5 as u64))
-			.saturating_add(T::DbWeight::get().writes(1 as u64))
+		Weight::from_ref_time(82_517_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(15 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
 	}
 	// Storage: DappsStaking RegisteredDapps (r:1 w:0)
 	// Storage: DappsStaking ContractEraStake (r:1 w:1)
+	// Storage: DappsStaking ContractStakeHistory (r:1 w:0)
 	// Storage: DappsStaking GeneralEraInfo (r:1 w:0)
+	// Storage: DappsStaking DappCommission (r:1 w:0)
+	// Storage: DappsStaking UnregisteredPeriods (r:1 w:0)
 	fn claim_dapp() -> Weight {
-		Weight::from_ref_time(35_939_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(3 as u64))
+		Weight::from_ref_time(42_106_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
 	fn force_new_era() -> Weight {
@@ -169,4 +200,187 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+	// Storage: DappsStaking RegisteredDapps (r:2 w:0)
+	// Storage: DappsStaking GeneralStakerInfo (r:2 w:2)
+	// Storage: DappsStaking ContractEraStake (r:2 w:2)
+	// Storage: DappsStaking ContractStakeHistory (r:2 w:2)
+	// Storage: DappsStaking StakeLocks (r:1 w:0)
+	// Storage: DappsStaking FrozenDapps (r:1 w:0)
+	fn nomination_transfer() -> Weight {
+		Weight::from_ref_time(52_337_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	// Storage: DappsStaking GeneralStakerInfo (r:1 w:1)
+	// Storage: DappsStaking RegisteredDapps (r:1 w:0)
+	// Storage: DappsStaking ContractEraStake (r:2 w:1)
+	// Storage: DappsStaking ContractStakeHistory (r:2 w:1)
+	// Storage: DappsStaking GeneralEraInfo (r:2 w:1)
+	// Storage: DappsStaking Ledger (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: DappsStaking DappCommission (r:1 w:0)
+	// Storage: DappsStaking StakeLocks (r:1 w:1)
+	// Storage: DappsStaking FrozenDapps (r:1 w:0)
+	// Storage: DappsStaking UnregisteredPeriods (r:1 w:0)
+	/// The range of component `n` is `[1, 16]`.
+	fn claim_staker_batch(n: u32, ) -> Weight {
+		Weight::from_ref_time(72_381_000 as u64)
+			.saturating_add(Weight::from_ref_time(13_604_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().reads((5 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
+	}
+	// Storage: DappsStaking RegisteredDapps (r:1 w:0)
+	// Storage: DappsStaking RegisteredDevelopers (r:1 w:0)
+	// Storage: DappsStaking PendingOwnershipTransfer (r:0 w:1)
+	fn propose_ownership_transfer() -> Weight {
+		Weight::from_ref_time(24_135_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: DappsStaking PendingOwnershipTransfer (r:1 w:1)
+	// Storage: DappsStaking RegisteredDapps (r:1 w:1)
+	// Storage: DappsStaking RegisteredDevelopers (r:1 w:2)
+	// Storage: System Account (r:2 w:2)
+	// Storage: DappsStaking SlashedDeposits (r:1 w:0)
+	// Storage: DappsStaking DappMetadata (r:1 w:0)
+	fn accept_ownership_transfer() -> Weight {
+		Weight::from_ref_time(54_618_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	// Storage: DappsStaking RegisteredDapps (r:1 w:0)
+	// Storage: DappsStaking Ledger (r:1 w:1)
+	// Storage: DappsStaking ContractEraStake (r:1 w:1)
+	// Storage: DappsStaking ContractStakeHistory (r:1 w:1)
+	// Storage: DappsStaking GeneralStakerInfo (r:1 w:1)
+	// Storage: DappsStaking GeneralEraInfo (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: DappsStaking FrozenDapps (r:1 w:0)
+	fn rebond() -> Weight {
+		Weight::from_ref_time(131_205_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	// Storage: DappsStaking RegisteredDapps (r:1 w:0)
+	// Storage: DappsStaking DappCommission (r:1 w:1)
+	// Storage: DappsStaking DappCommissionHistory (r:0 w:1)
+	fn set_dapp_commission() -> Weight {
+		Weight::from_ref_time(21_774_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: DappsStaking PendingStakingParameters (r:0 w:1)
+	fn set_staking_parameters() -> Weight {
+		Weight::from_ref_time(12_486_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: DappsStaking RegisteredDapps (r:1 w:0)
+	// Storage: DappsStaking GeneralStakerInfo (r:1 w:1)
+	// Storage: DappsStaking ContractEraStake (r:1 w:1)
+	// Storage: DappsStaking ContractStakeHistory (r:1 w:1)
+	// Storage: DappsStaking Ledger (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: DappsStaking GeneralEraInfo (r:1 w:1)
+	// Storage: DappsStaking StakeLocks (r:1 w:0)
+	fn instant_unbond_and_unstake() -> Weight {
+		Weight::from_ref_time(167_318_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(9 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+	}
+	// Storage: DappsStaking Ledger (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: DappsStaking GeneralEraInfo (r:1 w:1)
+	fn instant_withdraw_unbonded() -> Weight {
+		Weight::from_ref_time(131_942_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// Storage: DappsStaking RegisteredDapps (r:1 w:0)
+	// Storage: DappsStaking Ledger (r:1 w:1)
+	// Storage: DappsStaking ContractEraStake (r:1 w:1)
+	// Storage: DappsStaking ContractStakeHistory (r:1 w:1)
+	// Storage: DappsStaking GeneralStakerInfo (r:1 w:1)
+	// Storage: DappsStaking StakeLocks (r:1 w:1)
+	// Storage: DappsStaking BoostExpiries (r:1 w:1)
+	// Storage: DappsStaking GeneralEraInfo (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: DappsStaking FrozenDapps (r:1 w:0)
+	fn bond_and_stake_with_lock() -> Weight {
+		Weight::from_ref_time(168_407_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
+	}
+	// Storage: DappsStaking RegisteredDapps (r:1 w:0)
+	// Storage: DappsStaking ContractEraStake (r:1 w:1)
+	// Storage: DappsStaking ContractStakeHistory (r:1 w:0)
+	// Storage: DappsStaking GeneralEraInfo (r:1 w:0)
+	// Storage: DappsStaking DappCommission (r:1 w:0)
+	// Storage: DappsStaking SlashedDeposits (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: DappsStaking FrozenDapps (r:1 w:1)
+	// Storage: DappsStaking UnregisteredPeriods (r:1 w:0)
+	/// The range of component `n` is `[0, 16]`.
+	fn slash_dapp(n: u32, ) -> Weight {
+		Weight::from_ref_time(61_254_000 as u64)
+			.saturating_add(Weight::from_ref_time(33_918_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().reads((7 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: DappsStaking RegisteredDapps (r:1 w:1)
+	// Storage: DappsStaking UnregisteredPeriods (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: DappsStaking ContractEraStake (r:2 w:1)
+	// Storage: DappsStaking ContractStakeHistory (r:1 w:1)
+	fn reregister() -> Weight {
+		Weight::from_ref_time(44_815_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	// Storage: DappsStaking RegisteredDapps (r:1 w:0)
+	// Storage: DappsStaking DappMetadata (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn set_dapp_metadata(n: u32, ) -> Weight {
+		Weight::from_ref_time(31_540_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: DappsStaking DappMetadata (r:1 w:1)
+	// Storage: DappsStaking RegisteredDapps (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	fn clear_dapp_metadata() -> Weight {
+		Weight::from_ref_time(29_812_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: DappsStaking Operators (r:0 w:1)
+	fn set_operator() -> Weight {
+		Weight::from_ref_time(17_325_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: DappsStaking Operators (r:1 w:1)
+	fn remove_operator() -> Weight {
+		Weight::from_ref_time(19_871_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: DappsStaking RegisteredDapps (r:2 w:0)
+	// Storage: DappsStaking GeneralStakerInfo (r:2 w:2)
+	// Storage: DappsStaking ContractEraStake (r:2 w:2)
+	// Storage: DappsStaking ContractStakeHistory (r:2 w:2)
+	// Storage: DappsStaking StakeLocks (r:1 w:0)
+	// Storage: DappsStaking FrozenDapps (r:1 w:0)
+	/// The range of component `n` is `[1, 8]`.
+	fn batch_nomination_transfer(n: u32, ) -> Weight {
+		Weight::from_ref_time(8_412_000 as u64)
+			.saturating_add(Weight::from_ref_time(41_583_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads((10 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes((6 as u64).saturating_mul(n as u64)))
+	}
 }
@@ -42,6 +42,18 @@ interface DappsStaking {
     /// @return total: The most recent total staked amount on contract
     function read_contract_stake(address contract_id) external view returns (uint128);
 
+    /// Category under which the dApp is listed.
+    enum DAppCategory {Others, DeFi, NFT, Gaming, Tooling, Utility}
+
+    /// @notice Read the metadata of the dApp. Text fields are empty if no metadata was set.
+    /// @param contract_id: The smart contract address used for staking
+    /// @return name: The dApp name
+    /// @return description: Short description of the dApp
+    /// @return website: The dApp website URL
+    /// @return logo_uri: URI of the dApp logo
+    /// @return category: Category under which the dApp is listed
+    function read_dapp_metadata(address contract_id) external view returns (bytes memory, bytes memory, bytes memory, bytes memory, DAppCategory);
+
 
     // Extrinsic calls
 
@@ -77,6 +89,11 @@ interface DappsStaking {
     /// @notice Set reward destination for staker rewards
     /// @param reward_destination: The instruction on how the reward payout should be handled
     function set_reward_destination(RewardDestination reward_destination) external;
+
+    /// @notice Set reward destination for staker rewards, so that only part of the reward is restaked
+    ///         and the rest is paid out to the staker (free balance).
+    /// @param restake_ratio: Part of the reward to restake, expressed in parts per billion (at most 1_000_000_000)
+    function set_reward_destination_split(uint32 restake_ratio) external;
     
     /// @notice Withdraw staked funds from an unregistered contract.
     /// @param smart_contract: The smart contract address used for staking
@@ -87,4 +104,15 @@ interface DappsStaking {
     /// @param amount: The amount to transfer from origin to target
     /// @param target_smart_contract: The target smart contract address
     function nomination_transfer(address origin_smart_contract, uint128 amount, address target_smart_contract) external;
+
+    /// @notice Set the metadata of the dApp, overwriting the previous one.
+    ///         Deposit proportional to the metadata size is reserved from the developer account.
+    ///         Developer account is derived from the caller address.
+    /// @param smart_contract: The smart contract address used for staking
+    /// @param name: The dApp name
+    /// @param description: Short description of the dApp
+    /// @param website: The dApp website URL
+    /// @param logo_uri: URI of the dApp logo
+    /// @param category: Category under which the dApp is listed
+    function set_dapp_metadata(address smart_contract, bytes calldata name, bytes calldata description, bytes calldata website, bytes calldata logo_uri, DAppCategory category) external;
 }
@@ -27,15 +27,19 @@ use fp_evm::{PrecompileHandle, PrecompileOutput};
 use frame_support::{
     dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
     traits::{Currency, Get},
+    BoundedVec,
 };
-use pallet_dapps_staking::RewardDestination;
+use pallet_dapps_staking::{DAppCategory, DAppMetadata, RewardDestination};
 use pallet_evm::{AddressMapping, Precompile};
 use precompile_utils::{
-    error, revert, succeed, Address, Bytes, EvmData, EvmDataWriter, EvmResult, FunctionModifier,
-    PrecompileHandleExt, RuntimeHelper,
+    error, revert, succeed, Address, Bytes, EvmData, EvmDataReader, EvmDataWriter, EvmResult,
+    FunctionModifier, PrecompileHandleExt, RuntimeHelper,
 };
 use sp_core::H160;
-use sp_runtime::traits::{Saturating, Zero};
+use sp_runtime::{
+    traits::{Saturating, Zero},
+    PerThing, Perbill,
+};
 use sp_std::marker::PhantomData;
 use sp_std::prelude::*;
 extern crate alloc;
@@ -82,7 +86,8 @@ where
     fn read_unbonding_period(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
         handle.record_cost(RuntimeHelper::<R>::db_read_gas_cost())?;
 
-        let unbonding_period = R::UnbondingPeriod::get();
+        let unbonding_period =
+            pallet_dapps_staking::Pallet::<R>::staking_parameters().unbonding_period;
 
         Ok(succeed(
             EvmDataWriter::new().write(unbonding_period).build(),
@@ -192,6 +197,32 @@ where
         Ok(succeed(EvmDataWriter::new().write(total).build()))
     }
 
+    /// Read the metadata of the dApp, text fields are empty if no metadata was set
+    fn read_dapp_metadata(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        handle.record_cost(RuntimeHelper::<R>::db_read_gas_cost())?;
+
+        let mut input = handle.read_input()?;
+        input.expect_arguments(1)?;
+
+        // parse contract's address
+        let contract_h160 = input.read::<Address>()?.0;
+        let contract_id = Self::decode_smart_contract(contract_h160)?;
+
+        // call pallet-dapps-staking
+        let (metadata, _) =
+            pallet_dapps_staking::DappMetadata::<R>::get(&contract_id).unwrap_or_default();
+
+        Ok(succeed(
+            EvmDataWriter::new()
+                .write(Bytes::from(&metadata.name[..]))
+                .write(Bytes::from(&metadata.description[..]))
+                .write(Bytes::from(&metadata.website[..]))
+                .write(Bytes::from(&metadata.logo_uri[..]))
+                .write(metadata.category as u8)
+                .build(),
+        ))
+    }
+
     /// Register contract with the dapp-staking pallet
     /// Register is root origin only. This should always fail when called via evm precompile.
     fn register(_: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
@@ -323,6 +354,32 @@ where
 
         Ok(succeed(EvmDataWriter::new().write(true).build()))
     }
+
+    /// Set claim reward destination for the caller, restaking only the specified part of the reward
+    fn set_reward_destination_split(
+        handle: &mut impl PrecompileHandle,
+    ) -> EvmResult<PrecompileOutput> {
+        let mut input = handle.read_input()?;
+        input.expect_arguments(1)?;
+
+        // restake ratio, expressed in parts per billion
+        let restake_ratio_raw = input.read::<u32>()?;
+        if restake_ratio_raw > Perbill::ACCURACY {
+            return Err(error("Reward destination split ratio out of bounds."));
+        }
+        let reward_destination = RewardDestination::Split(Perbill::from_parts(restake_ratio_raw));
+
+        // Build call with origin.
+        let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        log::trace!(target: "ds-precompile", "set_reward_destination_split {:?} {:?}", origin, reward_destination);
+
+        let call = pallet_dapps_staking::Call::<R>::set_reward_destination { reward_destination };
+
+        RuntimeHelper::<R>::try_dispatch(handle, Some(origin).into(), call)?;
+
+        Ok(succeed(EvmDataWriter::new().write(true).build()))
+    }
+
     /// Withdraw staked funds from the unregistered contract
     fn withdraw_from_unregistered(
         handle: &mut impl PrecompileHandle,
@@ -344,6 +401,46 @@ where
         Ok(succeed(EvmDataWriter::new().write(true).build()))
     }
 
+    /// Set the metadata of the caller's dApp
+    fn set_dapp_metadata(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        let mut input = handle.read_input()?;
+        input.expect_arguments(6)?;
+
+        // parse contract's address
+        let contract_h160 = input.read::<Address>()?.0;
+        let contract_id = Self::decode_smart_contract(contract_h160)?;
+
+        // parse metadata text fields
+        let name = Self::read_metadata_field(&mut input)?;
+        let description = Self::read_metadata_field(&mut input)?;
+        let website = Self::read_metadata_field(&mut input)?;
+        let logo_uri = Self::read_metadata_field(&mut input)?;
+
+        // raw solidity representation of enum
+        let category = DAppCategory::try_from(input.read::<u8>()?)
+            .map_err(|_| error("Unexpected dApp category value."))?;
+
+        let metadata = DAppMetadata {
+            name,
+            description,
+            website,
+            logo_uri,
+            category,
+        };
+        log::trace!(target: "ds-precompile", "set_dapp_metadata {:?} {:?}", contract_id, metadata);
+
+        // Build call with origin.
+        let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        let call = pallet_dapps_staking::Call::<R>::set_dapp_metadata {
+            contract_id,
+            metadata,
+        };
+
+        RuntimeHelper::<R>::try_dispatch(handle, Some(origin).into(), call)?;
+
+        Ok(succeed(EvmDataWriter::new().write(true).build()))
+    }
+
     /// Claim rewards for the contract in the dapps-staking pallet
     fn nomination_transfer(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
         let mut input = handle.read_input()?;
@@ -394,6 +491,18 @@ where
         Ok(smart_contract)
     }
 
+    /// Helper method to parse a text field of the dApp metadata, bounded by `MaxMetadataFieldLength`
+    fn read_metadata_field(
+        input: &mut EvmDataReader,
+    ) -> EvmResult<BoundedVec<u8, <R as pallet_dapps_staking::Config>::MaxMetadataFieldLength>>
+    {
+        let field: Vec<u8> = input.read::<Bytes>()?.into();
+
+        field
+            .try_into()
+            .map_err(|_| error("dApp metadata field is too long."))
+    }
+
     /// Helper method to parse H160 or SS58 address
     fn parse_input_address(staker_vec: Vec<u8>) -> EvmResult<R::AccountId> {
         let staker: R::AccountId = match staker_vec.len() {
@@ -438,8 +547,11 @@ pub enum Action {
     ClaimDapp = "claim_dapp(address,uint128)",
     ClaimStaker = "claim_staker(address)",
     SetRewardDestination = "set_reward_destination(uint8)",
+    SetRewardDestinationSplit = "set_reward_destination_split(uint32)",
     WithdrawFromUnregistered = "withdraw_from_unregistered(address)",
     NominationTransfer = "nomination_transfer(address,uint128,address)",
+    ReadDappMetadata = "read_dapp_metadata(address)",
+    SetDappMetadata = "set_dapp_metadata(address,bytes,bytes,bytes,bytes,uint8)",
 }
 
 impl<R> Precompile for DappsStakingWrapper<R>
@@ -464,7 +576,8 @@ where
             | Action::ReadEraStaked
             | Action::ReadStakedAmount
             | Action::ReadStakedAmountOnContract
-            | Action::ReadContractStake => FunctionModifier::View,
+            | Action::ReadContractStake
+            | Action::ReadDappMetadata => FunctionModifier::View,
             _ => FunctionModifier::NonPayable,
         })?;
 
@@ -477,6 +590,7 @@ where
             Action::ReadStakedAmount => Self::read_staked_amount(handle),
             Action::ReadStakedAmountOnContract => Self::read_staked_amount_on_contract(handle),
             Action::ReadContractStake => Self::read_contract_stake(handle),
+            Action::ReadDappMetadata => Self::read_dapp_metadata(handle),
             // Dispatchables
             Action::Register => Self::register(handle),
             Action::BondAndStake => Self::bond_and_stake(handle),
@@ -485,8 +599,10 @@ where
             Action::ClaimDapp => Self::claim_dapp(handle),
             Action::ClaimStaker => Self::claim_staker(handle),
             Action::SetRewardDestination => Self::set_reward_destination(handle),
+            Action::SetRewardDestinationSplit => Self::set_reward_destination_split(handle),
             Action::WithdrawFromUnregistered => Self::withdraw_from_unregistered(handle),
             Action::NominationTransfer => Self::nomination_transfer(handle),
+            Action::SetDappMetadata => Self::set_dapp_metadata(handle),
         }
     }
 }
@@ -63,6 +63,8 @@ pub(crate) const MAX_ERA_STAKE_VALUES: u32 = 10;
 pub(crate) const BLOCKS_PER_ERA: BlockNumber = 3;
 
 pub(crate) const REGISTER_DEPOSIT: Balance = 10 * AST;
+pub(crate) const METADATA_DEPOSIT_PER_BYTE: Balance = MILLIAST;
+pub(crate) const MAX_METADATA_FIELD_LENGTH: u32 = 64;
 
 pub(crate) const STAKER_BLOCK_REWARD: Balance = 531911;
 pub(crate) const DAPP_BLOCK_REWARD: Balance = 773333;
@@ -255,7 +257,17 @@ impl pallet_timestamp::Config for TestRuntime {
 }
 
 #[derive(
-    PartialEq, Eq, Copy, Clone, Encode, Decode, Debug, scale_info::TypeInfo, MaxEncodedLen,
+    PartialEq,
+    Eq,
+    Copy,
+    Clone,
+    Encode,
+    Decode,
+    Debug,
+    scale_info::TypeInfo,
+    MaxEncodedLen,
+    Serialize,
+    Deserialize,
 )]
 pub enum MockSmartContract<AccountId32> {
     Evm(sp_core::H160),
@@ -278,6 +290,7 @@ parameter_types! {
     pub const MaxUnlockingChunks: u32 = MAX_UNLOCKING_CHUNKS;
     pub const UnbondingPeriod: EraIndex = UNBONDING_PERIOD;
     pub const MaxEraStakeValues: u32 = MAX_ERA_STAKE_VALUES;
+    pub const MetadataDepositPerByte: Balance = METADATA_DEPOSIT_PER_BYTE;
 }
 
 impl pallet_dapps_staking::Config for TestRuntime {
@@ -295,6 +308,26 @@ impl pallet_dapps_staking::Config for TestRuntime {
     type UnbondingPeriod = UnbondingPeriod;
     type MaxEraStakeValues = MaxEraStakeValues;
     type UnregisteredDappRewardRetention = ConstU32<2>;
+    type MaxBatchClaimEras = ConstU32<5>;
+    type MaxSlashEras = ConstU32<8>;
+    type MaxBatchNominationTransfers = ConstU32<8>;
+    type MaxContractStakeHistory = ConstU32<72>;
+    type InstantUnbondPenalty = ();
+    type InstantUnbondPenaltyHandler = ();
+    type StakerClaimWindow = ConstU32<64>;
+    type ExpiredStakerRewards = ();
+    type EraHistoryDepth = ConstU32<16>;
+    type StakeLockOptions = ();
+    type MaxStakeLocks = ConstU32<4>;
+    type MaxBoostExpiriesPerEra = ConstU32<16>;
+    type MaxReregistrations = ConstU32<4>;
+    type MetadataDepositPerByte = MetadataDepositPerByte;
+    type MaxMetadataFieldLength = ConstU32<MAX_METADATA_FIELD_LENGTH>;
+    type RegisterOrigin = frame_system::EnsureRoot<AccountId32>;
+    type UnregisterOrigin = frame_system::EnsureRoot<AccountId32>;
+    type SlashOrigin = frame_system::EnsureRoot<AccountId32>;
+    type SlashHandler = ();
+    type MetadataOrigin = frame_system::EnsureRoot<AccountId32>;
 }
 
 pub struct ExternalityBuilder {
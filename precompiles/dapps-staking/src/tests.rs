@@ -26,10 +26,10 @@ use crate::{
 };
 use fp_evm::ExitError;
 use frame_support::assert_ok;
-use pallet_dapps_staking::RewardDestination;
+use pallet_dapps_staking::{DAppCategory, RewardDestination};
 use precompile_utils::testing::*;
 use sp_core::H160;
-use sp_runtime::{traits::Zero, AccountId32, Perbill};
+use sp_runtime::{traits::Zero, AccountId32, PerThing, Perbill};
 
 fn precompiles() -> DappPrecompile<TestRuntime> {
     PrecompilesValue::get()
@@ -331,10 +331,42 @@ fn set_reward_destination() {
                 TestAccount::Bobo.into(),
                 RewardDestination::StakeBalance,
             );
+            set_reward_destination_verify(
+                TestAccount::Bobo.into(),
+                RewardDestination::Split(Perbill::from_percent(30)),
+            );
             set_reward_destination_verify(TestAccount::Bobo.into(), RewardDestination::FreeBalance);
         });
 }
 
+#[test]
+fn set_reward_destination_split_out_of_bounds() {
+    ExternalityBuilder::default()
+        .with_balances(vec![
+            (TestAccount::Alex.into(), 200 * AST),
+            (TestAccount::Bobo.into(), 200 * AST),
+        ])
+        .build()
+        .execute_with(|| {
+            initialize_first_block();
+            register_and_verify(TestAccount::Alex.into(), TEST_CONTRACT);
+            bond_stake_and_verify(TestAccount::Bobo, TEST_CONTRACT, 100 * AST);
+
+            precompiles()
+                .prepare_test(
+                    TestAccount::Bobo,
+                    precompile_address(),
+                    EvmDataWriter::new_with_selector(Action::SetRewardDestinationSplit)
+                        .write(Perbill::ACCURACY + 1)
+                        .build(),
+                )
+                .expect_no_logs()
+                .execute_error(ExitError::Other(alloc::borrow::Cow::Borrowed(
+                    "Reward destination split ratio out of bounds.",
+                )));
+        });
+}
+
 #[test]
 fn withdraw_from_unregistered() {
     ExternalityBuilder::default()
@@ -393,6 +425,99 @@ fn nomination_transfer() {
         });
 }
 
+#[test]
+fn dapp_metadata_is_ok() {
+    ExternalityBuilder::default()
+        .with_balances(vec![(TestAccount::Alex.into(), 200 * AST)])
+        .build()
+        .execute_with(|| {
+            initialize_first_block();
+            register_and_verify(TestAccount::Alex.into(), TEST_CONTRACT);
+
+            // contract without metadata has empty fields
+            read_dapp_metadata_verify(TEST_CONTRACT, [&[][..]; 4], DAppCategory::Others);
+
+            let fields = [
+                &b"Astar dApp"[..],
+                &b"dApp used for testing"[..],
+                &b"https://astar.network"[..],
+                &b"ipfs://logo"[..],
+            ];
+            precompiles()
+                .prepare_test(
+                    TestAccount::Alex,
+                    precompile_address(),
+                    EvmDataWriter::new_with_selector(Action::SetDappMetadata)
+                        .write(Address(TEST_CONTRACT))
+                        .write(Bytes::from(fields[0]))
+                        .write(Bytes::from(fields[1]))
+                        .write(Bytes::from(fields[2]))
+                        .write(Bytes::from(fields[3]))
+                        .write(DAppCategory::Tooling as u8)
+                        .build(),
+                )
+                .expect_no_logs()
+                .execute_returns(EvmDataWriter::new().write(true).build());
+
+            let byte_size: usize = fields.iter().map(|field| field.len()).sum();
+            assert_eq!(
+                <TestRuntime as pallet_evm::Config>::Currency::reserved_balance(
+                    &TestAccount::Alex.into()
+                ),
+                REGISTER_DEPOSIT + METADATA_DEPOSIT_PER_BYTE * byte_size as Balance
+            );
+            read_dapp_metadata_verify(TEST_CONTRACT, fields, DAppCategory::Tooling);
+        });
+}
+
+#[test]
+fn set_dapp_metadata_invalid_input() {
+    ExternalityBuilder::default()
+        .with_balances(vec![(TestAccount::Alex.into(), 200 * AST)])
+        .build()
+        .execute_with(|| {
+            initialize_first_block();
+            register_and_verify(TestAccount::Alex.into(), TEST_CONTRACT);
+
+            let too_long_field = vec![b'x'; MAX_METADATA_FIELD_LENGTH as usize + 1];
+            precompiles()
+                .prepare_test(
+                    TestAccount::Alex,
+                    precompile_address(),
+                    EvmDataWriter::new_with_selector(Action::SetDappMetadata)
+                        .write(Address(TEST_CONTRACT))
+                        .write(Bytes(too_long_field))
+                        .write(Bytes::from(&b""[..]))
+                        .write(Bytes::from(&b""[..]))
+                        .write(Bytes::from(&b""[..]))
+                        .write(0u8)
+                        .build(),
+                )
+                .expect_no_logs()
+                .execute_error(ExitError::Other(alloc::borrow::Cow::Borrowed(
+                    "dApp metadata field is too long.",
+                )));
+
+            precompiles()
+                .prepare_test(
+                    TestAccount::Alex,
+                    precompile_address(),
+                    EvmDataWriter::new_with_selector(Action::SetDappMetadata)
+                        .write(Address(TEST_CONTRACT))
+                        .write(Bytes::from(&b"Astar dApp"[..]))
+                        .write(Bytes::from(&b""[..]))
+                        .write(Bytes::from(&b""[..]))
+                        .write(Bytes::from(&b""[..]))
+                        .write(DAppCategory::Utility as u8 + 1)
+                        .build(),
+                )
+                .expect_no_logs()
+                .execute_error(ExitError::Other(alloc::borrow::Cow::Borrowed(
+                    "Unexpected dApp category value.",
+                )));
+        });
+}
+
 // ****************************************************************************************************
 // Helper functions
 // ****************************************************************************************************
@@ -518,18 +643,21 @@ fn set_reward_destination_verify(staker: TestAccount, reward_destination: Reward
     // Ensure that something is staked or being unbonded
     assert!(!init_ledger.is_empty());
 
-    let reward_destination_raw: u8 = match reward_destination {
-        RewardDestination::FreeBalance => 0,
-        RewardDestination::StakeBalance => 1,
-    };
+    let input_data = match reward_destination {
+        RewardDestination::FreeBalance => {
+            EvmDataWriter::new_with_selector(Action::SetRewardDestination).write(0u8)
+        }
+        RewardDestination::StakeBalance => {
+            EvmDataWriter::new_with_selector(Action::SetRewardDestination).write(1u8)
+        }
+        RewardDestination::Split(restake_ratio) => {
+            EvmDataWriter::new_with_selector(Action::SetRewardDestinationSplit)
+                .write(restake_ratio.deconstruct())
+        }
+    }
+    .build();
     precompiles()
-        .prepare_test(
-            staker.clone(),
-            precompile_address(),
-            EvmDataWriter::new_with_selector(Action::SetRewardDestination)
-                .write(reward_destination_raw)
-                .build(),
-        )
+        .prepare_test(staker.clone(), precompile_address(), input_data)
         .expect_no_logs()
         .execute_returns(EvmDataWriter::new().write(true).build());
 
@@ -677,6 +805,29 @@ fn verify_staked_amount(contract: H160, staker: TestAccount, amount: Balance) {
         .execute_returns(EvmDataWriter::new().write(amount).build());
 }
 
+/// helper function to read dApp metadata and verify it matches the expected values
+fn read_dapp_metadata_verify(contract: H160, fields: [&[u8]; 4], category: DAppCategory) {
+    precompiles()
+        .prepare_test(
+            TestAccount::Alex,
+            precompile_address(),
+            EvmDataWriter::new_with_selector(Action::ReadDappMetadata)
+                .write(Address(contract))
+                .build(),
+        )
+        .expect_cost(READ_WEIGHT)
+        .expect_no_logs()
+        .execute_returns(
+            EvmDataWriter::new()
+                .write(Bytes::from(fields[0]))
+                .write(Bytes::from(fields[1]))
+                .write(Bytes::from(fields[2]))
+                .write(Bytes::from(fields[3]))
+                .write(category as u8)
+                .build(),
+        );
+}
+
 /// Helper method to decode type SmartContract enum from [u8; 20]
 fn decode_smart_contract_from_array(
     contract_array: [u8; 20],